@@ -0,0 +1,334 @@
+//! Arbitrary precision unsigned integers, just enough for exact conversions between decimal and
+//! binary.
+
+use std::{cmp::Ordering, fmt, iter};
+
+/// A non-negative integer of any size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without trailing zeros.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn from_u64(n: u64) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut big = Self {
+            limbs: vec![n as u32, (n >> 32) as u32],
+        };
+        big.normalize();
+        big
+    }
+
+    /// The number with the given binary digits, most significant first.
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> Self {
+        let mut big = Self { limbs: Vec::new() };
+        for bit in bits {
+            big = big.shl(1);
+            if bit {
+                big.add_small(1);
+            }
+        }
+        big
+    }
+
+    /// `10^exponent`.
+    pub fn pow10(exponent: u32) -> Self {
+        let mut big = Self::from_u64(1);
+        big.mul_pow10(exponent);
+        big
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Number of bits needed to write the number, which is 0 for zero.
+    pub fn bit_len(&self) -> usize {
+        self.limbs.last().map_or(0, |&top| {
+            32 * self.limbs.len() - top.leading_zeros() as usize
+        })
+    }
+
+    /// Number of zero bits below the lowest one, which is 0 for zero.
+    pub fn trailing_zeros(&self) -> usize {
+        self.limbs
+            .iter()
+            .position(|&limb| limb != 0)
+            .map_or(0, |i| 32 * i + self.limbs[i].trailing_zeros() as usize)
+    }
+
+    /// The bit worth `2^i`.
+    pub fn bit(&self, i: usize) -> bool {
+        self.limbs
+            .get(i / 32)
+            .is_some_and(|&limb| (limb >> (i % 32)) & 1 == 1)
+    }
+
+    fn set_bit(&mut self, i: usize) {
+        if self.limbs.len() <= i / 32 {
+            self.limbs.resize(i / 32 + 1, 0);
+        }
+        self.limbs[i / 32] |= 1 << (i % 32);
+    }
+
+    pub fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            #[allow(clippy::cast_possible_truncation)]
+            let low = product as u32;
+            *limb = low;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            #[allow(clippy::cast_possible_truncation)]
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    pub fn add_small(&mut self, addend: u32) {
+        let mut carry = addend;
+        for limb in &mut self.limbs {
+            let (sum, overflowed) = limb.overflowing_add(carry);
+            *limb = sum;
+            carry = u32::from(overflowed);
+            if carry == 0 {
+                return;
+            }
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+
+    /// `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = u64::from(self.limbs.get(i).copied().unwrap_or(0))
+                + u64::from(other.limbs.get(i).copied().unwrap_or(0))
+                + carry;
+            #[allow(clippy::cast_possible_truncation)]
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        limbs.push(carry as u32);
+        let mut big = Self { limbs };
+        big.normalize();
+        big
+    }
+
+    /// `self - other`, where `other` is no larger than `self`.
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        difference.sub_assign(other);
+        difference
+    }
+
+    /// `self * other`, by schoolbook multiplication.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut limbs = vec![0; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = u64::from(a) * u64::from(b) + u64::from(limbs[i + j]) + carry;
+                #[allow(clippy::cast_possible_truncation)]
+                let low = product as u32;
+                limbs[i + j] = low;
+                carry = product >> 32;
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            let carry = carry as u32;
+            limbs[i + other.limbs.len()] = carry;
+        }
+        let mut big = Self { limbs };
+        big.normalize();
+        big
+    }
+
+    /// Multiplies by `10^exponent`.
+    pub fn mul_pow10(&mut self, exponent: u32) {
+        for _ in 0..exponent / 9 {
+            self.mul_small(1_000_000_000);
+        }
+        self.mul_small(10_u32.pow(exponent % 9));
+    }
+
+    /// `self * 2^bits`.
+    pub fn shl(&self, bits: usize) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let (words, bits) = (bits / 32, bits % 32);
+        let mut limbs = vec![0; words];
+        let mut carry = 0;
+        for &limb in &self.limbs {
+            limbs.push(if bits == 0 {
+                limb
+            } else {
+                (limb << bits) | carry
+            });
+            carry = if bits == 0 { 0 } else { limb >> (32 - bits) };
+        }
+        limbs.push(carry);
+        let mut big = Self { limbs };
+        big.normalize();
+        big
+    }
+
+    /// `self / 2^bits`, rounded down.
+    pub fn shr(&self, bits: usize) -> Self {
+        let (words, bits) = (bits / 32, bits % 32);
+        let limbs = self.limbs.get(words..).unwrap_or(&[]);
+        let mut big = Self {
+            limbs: limbs
+                .iter()
+                .enumerate()
+                .map(|(i, &limb)| {
+                    let next = limbs.get(i + 1).copied().unwrap_or(0);
+                    if bits == 0 {
+                        limb
+                    } else {
+                        (limb >> bits) | (next << (32 - bits))
+                    }
+                })
+                .collect(),
+        };
+        big.normalize();
+        big
+    }
+
+    /// Divides by `divisor` in place, returning the remainder.
+    ///
+    /// # Panics
+    ///
+    /// If `divisor` is zero.
+    pub fn div_small(&mut self, divisor: u32) -> u32 {
+        assert!(divisor != 0, "division by zero");
+        let mut remainder = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let dividend = remainder << 32 | u64::from(*limb);
+            #[allow(clippy::cast_possible_truncation)]
+            let quotient = (dividend / u64::from(divisor)) as u32;
+            *limb = quotient;
+            remainder = dividend % u64::from(divisor);
+        }
+        self.normalize();
+        #[allow(clippy::cast_possible_truncation)]
+        let remainder = remainder as u32;
+        remainder
+    }
+
+    /// Subtracts `other`, which must be no larger than `self`.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let subtrahend = other.limbs.get(i).copied().unwrap_or(0);
+            let (difference, borrowed) = limb.overflowing_sub(subtrahend);
+            let (difference, borrowed_again) = difference.overflowing_sub(u32::from(borrow));
+            *limb = difference;
+            borrow = borrowed || borrowed_again;
+        }
+        debug_assert!(!borrow, "subtracted a larger number");
+        self.normalize();
+    }
+
+    /// Quotient and remainder, by long division a bit at a time. This takes time in proportion
+    /// to the length of the quotient, which is short in every use here, rather than that of
+    /// the dividend.
+    ///
+    /// # Panics
+    ///
+    /// If `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let quotient_len = (self.bit_len() + 1).saturating_sub(divisor.bit_len());
+        let mut quotient = Self { limbs: Vec::new() };
+        // The bits above those of the quotient are less than the divisor.
+        let mut remainder = self.shr(quotient_len);
+        for i in (0..quotient_len).rev() {
+            remainder = remainder.shl(1);
+            if self.bit(i) {
+                remainder.set_bit(0);
+            }
+            if remainder >= *divisor {
+                remainder.sub_assign(divisor);
+                quotient.set_bit(i);
+            }
+        }
+        quotient.normalize();
+        (quotient, remainder)
+    }
+
+    /// The number as a `u64`, or `None` if it doesn't fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.as_slice() {
+            [] => Some(0),
+            [low] => Some(u64::from(*low)),
+            [low, high] => Some(u64::from(*high) << 32 | u64::from(*low)),
+            _ => None,
+        }
+    }
+
+    /// The nearest `f64`, give or take an ulp, or infinity if it is out of range.
+    pub fn to_f64(&self) -> f64 {
+        self.limbs.iter().rev().fold(0.0, |acc, &limb| {
+            acc.mul_add(2_f64.powi(32), f64::from(limb))
+        })
+    }
+}
+
+impl fmt::Display for BigUint {
+    /// Decimal digits, nine at a time.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            chunks.push(rest.div_small(1_000_000_000));
+        }
+        let leading = chunks.pop().unwrap_or(0).to_string();
+        let digits: String = iter::once(leading)
+            .chain(chunks.iter().rev().map(|chunk| format!("{:09}", chunk)))
+            .collect();
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::UpperHex for BigUint {
+    /// Hex digits, a limb at a time.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = self.limbs.iter().rev();
+        let leading = limbs
+            .next()
+            .map_or_else(|| "0".to_owned(), |top| format!("{:X}", top));
+        let digits: String = iter::once(leading)
+            .chain(limbs.map(|limb| format!("{:08X}", limb)))
+            .collect();
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,1579 @@
+//! The bits of a number in an IEEE 754 style format and the values they encode.
+
+use crate::bignum::BigUint;
+use std::{
+    cmp::Ordering,
+    fmt::Write,
+    iter,
+    ops::{BitOrAssign, Range},
+    slice,
+};
+
+/// Number of **explicitly stored** significand bits for IEEE754 binary64.
+pub const BINARY_64_SIGNIFICAND_BITS: usize = 52;
+
+pub const BINARY_64_EXPONENT_BITS: usize = 11;
+
+/// The widest fields supported, those of IEEE 754 binary256.
+pub const MAX_EXPONENT_BITS: usize = 19;
+pub const MAX_SIGNIFICAND_BITS: usize = 236;
+/// The largest exponent bias which can be set, twice the standard bias of the widest exponent.
+pub const MAX_BIAS: u64 = 1 << MAX_EXPONENT_BITS;
+
+/// Exponent of the smallest power of two an `f64` can hold, that of its smallest subnormal.
+const MIN_F64_SCALE: i32 = -1074;
+
+/// Common formats, which all follow IEEE 754 apart from their widths, except for the OCP
+/// FP8 E4M3 format, which has no infinities, and x87 extended precision, which stores the
+/// leading significand bit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FormatPreset {
+    E4M3,
+    E5M2,
+    Binary16,
+    Bfloat16,
+    Binary32,
+    Binary64,
+    X87Extended,
+}
+
+impl FormatPreset {
+    pub const ALL: [Self; 7] = [
+        Self::E4M3,
+        Self::E5M2,
+        Self::Binary16,
+        Self::Bfloat16,
+        Self::Binary32,
+        Self::Binary64,
+        Self::X87Extended,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::E4M3 => "FP8 E4M3",
+            Self::E5M2 => "FP8 E5M2",
+            Self::Binary16 => "binary16",
+            Self::Bfloat16 => "bfloat16",
+            Self::Binary32 => "binary32",
+            Self::Binary64 => "binary64",
+            Self::X87Extended => "x87 extended",
+        }
+    }
+
+    /// Exponent and explicitly stored significand widths.
+    pub const fn layout(self) -> (usize, usize) {
+        match self {
+            Self::E4M3 => (4, 3),
+            Self::E5M2 => (5, 2),
+            Self::Binary16 => (5, 10),
+            Self::Bfloat16 => (8, 7),
+            Self::Binary32 => (8, 23),
+            Self::Binary64 => (11, 52),
+            Self::X87Extended => (15, 64),
+        }
+    }
+
+    /// E4M3 gives up infinities so that only the all ones encodings are NaNs, and the rest of
+    /// the top binade extends the range to 448. x87 extended precision stores its leading bit.
+    pub fn options(self) -> Options {
+        match self {
+            Self::E4M3 => Options {
+                infinities: false,
+                ..Options::default()
+            },
+            Self::X87Extended => Options {
+                implicit_bit: false,
+                ..Options::default()
+            },
+            _ => Options::default(),
+        }
+    }
+
+    /// Zero in this format.
+    pub fn zero(self) -> Encoding {
+        let (exponent_len, significand_len) = self.layout();
+        let mut zero = Encoding::from_bits(exponent_len, significand_len, 0);
+        zero.options = self.options();
+        zero
+    }
+}
+
+/// Values at the edges of every format, worked out for its widths and options.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpecialValue {
+    PositiveZero,
+    NegativeZero,
+    PositiveInfinity,
+    NegativeInfinity,
+    QuietNan,
+    MinSubnormal,
+    MinNormal,
+    MaxFinite,
+    One,
+}
+
+impl SpecialValue {
+    pub const ALL: [Self; 9] = [
+        Self::PositiveZero,
+        Self::NegativeZero,
+        Self::PositiveInfinity,
+        Self::NegativeInfinity,
+        Self::QuietNan,
+        Self::MinSubnormal,
+        Self::MinNormal,
+        Self::MaxFinite,
+        Self::One,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PositiveZero => "+0",
+            Self::NegativeZero => "\u{2212}0",
+            Self::PositiveInfinity => "+\u{221E}",
+            Self::NegativeInfinity => "\u{2212}\u{221E}",
+            Self::QuietNan => "Quiet NaN",
+            Self::MinSubnormal => "Smallest subnormal",
+            Self::MinNormal => "Smallest normal",
+            Self::MaxFinite => "Largest finite",
+            Self::One => "1.0",
+        }
+    }
+
+    /// The value in the format of `format`, or `None` if it has no such value.
+    pub fn in_format(self, format: &Encoding) -> Option<Encoding> {
+        match self {
+            Self::PositiveZero => Some(format.zero(false)),
+            Self::NegativeZero => Some(format.zero(true)),
+            Self::PositiveInfinity => format.infinity(false),
+            Self::NegativeInfinity => format.infinity(true),
+            Self::QuietNan => format.default_nan(),
+            Self::MinSubnormal => Some(format.min_subnormal()),
+            Self::MinNormal => format.min_normal(),
+            Self::MaxFinite => Some(format.max_finite(false)),
+            Self::One => format.power_of_two(0),
+        }
+    }
+}
+
+/// The classes of IEEE 754's `class` operation, which tell apart every kind of encoding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Class {
+    SignalingNan,
+    QuietNan,
+    NegativeInfinity,
+    NegativeNormal,
+    NegativeSubnormal,
+    NegativeZero,
+    PositiveZero,
+    PositiveSubnormal,
+    PositiveNormal,
+    PositiveInfinity,
+}
+
+impl Class {
+    /// The name IEEE 754 gives the class.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SignalingNan => "signalingNaN",
+            Self::QuietNan => "quietNaN",
+            Self::NegativeInfinity => "negativeInfinity",
+            Self::NegativeNormal => "negativeNormal",
+            Self::NegativeSubnormal => "negativeSubnormal",
+            Self::NegativeZero => "negativeZero",
+            Self::PositiveZero => "positiveZero",
+            Self::PositiveSubnormal => "positiveSubnormal",
+            Self::PositiveNormal => "positiveNormal",
+            Self::PositiveInfinity => "positiveInfinity",
+        }
+    }
+
+    /// The C `fpclassify` macro for the class, which doesn't tell signs or kinds of NaN apart.
+    pub const fn fpclassify(self) -> &'static str {
+        match self {
+            Self::SignalingNan | Self::QuietNan => "FP_NAN",
+            Self::NegativeInfinity | Self::PositiveInfinity => "FP_INFINITE",
+            Self::NegativeNormal | Self::PositiveNormal => "FP_NORMAL",
+            Self::NegativeSubnormal | Self::PositiveSubnormal => "FP_SUBNORMAL",
+            Self::NegativeZero | Self::PositiveZero => "FP_ZERO",
+        }
+    }
+}
+
+/// Encodings which only formats with an explicit leading bit have, where the stored leading
+/// bit disagrees with the exponent.
+///
+/// The 8087 and 80287 gave them all meanings, but the 80387
+/// and later treat the unnormals and the pseudo-infinities and pseudo-NaNs as invalid
+/// operands, and only read pseudo-denormals, as subnormals with the smallest normal exponent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NonCanonical {
+    PseudoDenormal,
+    Unnormal,
+    PseudoInfinity,
+    PseudoNan,
+}
+
+impl NonCanonical {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PseudoDenormal => "pseudo-denormal",
+            Self::Unnormal => "unnormal",
+            Self::PseudoInfinity => "pseudo-infinity",
+            Self::PseudoNan => "pseudo-NaN",
+        }
+    }
+
+    /// What makes the encoding non-canonical.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::PseudoDenormal => "The exponent field is zero but the leading bit is set",
+            Self::Unnormal => "The exponent field is nonzero but the leading bit is clear",
+            Self::PseudoInfinity => {
+                "The exponent field is all ones and the significand is all zeros, leading bit \
+                 included"
+            }
+            Self::PseudoNan => {
+                "The exponent field is all ones and the leading bit is clear, but the rest of \
+                 the significand is not"
+            }
+        }
+    }
+}
+
+/// The IEEE 754 exception flags.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Flags {
+    pub invalid: bool,
+    pub divide_by_zero: bool,
+    pub overflow: bool,
+    pub underflow: bool,
+    pub inexact: bool,
+}
+
+impl Flags {
+    /// Whether each flag is raised, with its name.
+    pub const fn named(self) -> [(bool, &'static str); 5] {
+        [
+            (self.invalid, "invalid"),
+            (self.divide_by_zero, "divideByZero"),
+            (self.overflow, "overflow"),
+            (self.underflow, "underflow"),
+            (self.inexact, "inexact"),
+        ]
+    }
+
+    /// Names of the raised flags.
+    pub fn raised(self) -> Vec<&'static str> {
+        self.named()
+            .iter()
+            .filter(|(raised, _)| *raised)
+            .map(|&(_, name)| name)
+            .collect()
+    }
+
+    /// The flags as a bit set in the order used by Berkeley `TestFloat`, from inexact in bit 0
+    /// up to invalid in bit 4.
+    pub fn to_bits(self) -> u8 {
+        [
+            self.inexact,
+            self.underflow,
+            self.overflow,
+            self.divide_by_zero,
+            self.invalid,
+        ]
+        .iter()
+        .rev()
+        .fold(0, |acc, &flag| (acc << 1) | u8::from(flag))
+    }
+
+    /// Inverse of `to_bits`.
+    pub fn from_bits(bits: u8) -> Self {
+        let bit = |i: u8| (bits >> i) & 1 == 1;
+        Self {
+            inexact: bit(0),
+            underflow: bit(1),
+            overflow: bit(2),
+            divide_by_zero: bit(3),
+            invalid: bit(4),
+        }
+    }
+}
+
+impl BitOrAssign for Flags {
+    /// Raises every flag raised in `other`, as IEEE 754's sticky flags accumulate.
+    fn bitor_assign(&mut self, other: Self) {
+        self.invalid |= other.invalid;
+        self.divide_by_zero |= other.divide_by_zero;
+        self.overflow |= other.overflow;
+        self.underflow |= other.underflow;
+        self.inexact |= other.inexact;
+    }
+}
+
+/// Computes `x / ±0` in the format of `x`, or `None` for `0 / 0` in a format without NaNs.
+///
+/// Division by zero never rounds, so the result and flags follow directly from the
+/// classification of `x` (IEEE 754-2019 sections 7.2 and 7.3).
+pub fn divide_by_zero(x: &Encoding, negative_zero: bool) -> Option<(Encoding, Flags)> {
+    let mut flags = Flags::default();
+    let result = if x.is_nan() {
+        // NaNs propagate, with signaling NaNs quieted.
+        flags.invalid = x.is_signaling_nan();
+        x.quieted()
+    } else if x.is_zero() {
+        flags.invalid = true;
+        x.default_nan()?
+    } else {
+        flags.divide_by_zero = !x.is_infinite();
+        x.infinite_result(x.sign_bit != negative_zero)
+    };
+    Some((result, flags))
+}
+
+/// What a format does with finite results too large for its largest finite value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Overflow {
+    /// Round to infinity, as IEEE 754 requires when rounding to nearest.
+    Infinity,
+    /// Clamp to the largest finite value, as FP8 E4M3 and many DSP formats do.
+    Saturate,
+}
+
+impl Overflow {
+    pub const ALL: [Self; 2] = [Self::Infinity, Self::Saturate];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Infinity => "Overflow to infinity",
+            Self::Saturate => "Saturate to the largest finite value",
+        }
+    }
+}
+
+/// The IEEE 754 rounding-direction attributes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RoundingMode {
+    TiesToEven,
+    TiesToAway,
+    TowardZero,
+    TowardPositive,
+    TowardNegative,
+}
+
+impl RoundingMode {
+    pub const ALL: [Self; 5] = [
+        Self::TiesToEven,
+        Self::TiesToAway,
+        Self::TowardZero,
+        Self::TowardPositive,
+        Self::TowardNegative,
+    ];
+
+    /// The name IEEE 754 gives the attribute.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::TiesToEven => "roundTiesToEven",
+            Self::TiesToAway => "roundTiesToAway",
+            Self::TowardZero => "roundTowardZero",
+            Self::TowardPositive => "roundTowardPositive",
+            Self::TowardNegative => "roundTowardNegative",
+        }
+    }
+
+    /// Whether an inexact magnitude of the given sign rounds away from zero, given how the
+    /// discarded part compares with half an ulp and whether the kept part is odd.
+    fn rounds_up(self, sign_bit: bool, half: Ordering, odd: bool) -> bool {
+        match self {
+            Self::TiesToEven => half == Ordering::Greater || (half == Ordering::Equal && odd),
+            Self::TiesToAway => half != Ordering::Less,
+            Self::TowardZero => false,
+            Self::TowardPositive => !sign_bit,
+            Self::TowardNegative => sign_bit,
+        }
+    }
+}
+
+/// Choices about how a format treats its encodings, beyond the widths of its fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub overflow: Overflow,
+    /// Whether the format has encodings for infinity.
+    pub infinities: bool,
+    pub nans: bool,
+    /// Whether the leading bit of the significand is implied by the exponent rather than
+    /// stored as the first significand bit, as it is in x87 extended precision.
+    pub implicit_bit: bool,
+    /// An exponent bias other than the standard `2^(k - 1) - 1`, as some accelerator formats
+    /// and older machines use.
+    pub bias: Option<u64>,
+}
+
+impl Default for Options {
+    /// IEEE 754 behaviour.
+    fn default() -> Self {
+        Self {
+            overflow: Overflow::Infinity,
+            infinities: true,
+            nans: true,
+            implicit_bit: true,
+            bias: None,
+        }
+    }
+}
+
+/// The bits of a number in a format with arbitrary exponent and significand widths.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Encoding {
+    pub sign_bit: bool,
+    pub exponent_bits: Vec<bool>,
+    pub significand_bits: Vec<bool>,
+    /// Part of the format rather than the value, but carried along so that anything built
+    /// from this encoding honours them.
+    pub options: Options,
+}
+
+impl Encoding {
+    /// All bits of the encoding, from the sign bit down to the last significand bit.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        iter::once(self.sign_bit)
+            .chain(self.exponent_bits.iter().copied())
+            .chain(self.significand_bits.iter().copied())
+    }
+
+    pub fn bits_mut(&mut self) -> impl Iterator<Item = &mut bool> + '_ {
+        iter::once(&mut self.sign_bit)
+            .chain(&mut self.exponent_bits)
+            .chain(&mut self.significand_bits)
+    }
+
+    /// Bit `i` and where it sits in the encoding, or `None` if it is out of range.
+    pub fn bit(&self, i: usize) -> Option<Bit> {
+        let exponent_len = self.exponent_bits.len();
+        let (field, weight, value) = if i == 0 {
+            (BitType::Sign, 0, self.sign_bit)
+        } else if i <= exponent_len {
+            (
+                BitType::Exponent,
+                exponent_len - i,
+                self.exponent_bits[i - 1],
+            )
+        } else {
+            let j = i - exponent_len - 1;
+            let value = *self.significand_bits.get(j)?;
+            (
+                BitType::Significand,
+                self.significand_bits.len() - 1 - j,
+                value,
+            )
+        };
+        Some(Bit {
+            index: i,
+            field,
+            weight,
+            value,
+        })
+    }
+
+    /// Positions in `bits()` of the bits of field `t`.
+    pub const fn field_range(&self, t: BitType) -> Range<usize> {
+        let significand_start = 1 + self.exponent_bits.len();
+        match t {
+            BitType::Sign => 0..1,
+            BitType::Exponent => 1..significand_start,
+            BitType::Significand => significand_start..self.len(),
+        }
+    }
+
+    /// The bits of field `t`, most significant first.
+    pub fn field_bits(&self, t: BitType) -> &[bool] {
+        match t {
+            BitType::Sign => slice::from_ref(&self.sign_bit),
+            BitType::Exponent => &self.exponent_bits,
+            BitType::Significand => &self.significand_bits,
+        }
+    }
+
+    fn field_bits_mut(&mut self, t: BitType) -> &mut [bool] {
+        match t {
+            BitType::Sign => slice::from_mut(&mut self.sign_bit),
+            BitType::Exponent => &mut self.exponent_bits,
+            BitType::Significand => &mut self.significand_bits,
+        }
+    }
+
+    /// Field `t` as an unsigned integer, keeping only the low 64 bits of wider significands.
+    pub fn field(&self, t: BitType) -> u64 {
+        self.field_bits(t)
+            .iter()
+            .fold(0, |acc, &b| (acc << 1) | u64::from(b))
+    }
+
+    /// Sets field `t` to the low bits of `value`, ignoring any which don't fit and clearing any
+    /// beyond the 64 of `value`.
+    pub fn set_field(&mut self, t: BitType, value: u64) {
+        for (i, bit) in self.field_bits_mut(t).iter_mut().rev().enumerate() {
+            *bit = i < 64 && (value >> i) & 1 == 1;
+        }
+    }
+
+    /// Sets every bit of field `t` to `value`.
+    pub fn fill_field(&mut self, t: BitType, value: bool) {
+        for bit in self.field_bits_mut(t) {
+            *bit = value;
+        }
+    }
+
+    /// Sets the bit of field `t` worth `2^weight` within the field. Does nothing if the field
+    /// is narrower than that.
+    pub fn set_bit(&mut self, t: BitType, weight: usize, value: bool) {
+        let field = self.field_bits_mut(t);
+        if let Some(i) = field.len().checked_sub(weight + 1) {
+            field[i] = value;
+        }
+    }
+
+    /// A copy of the encoding with bit `i` flipped.
+    pub fn toggled(&self, i: usize) -> Self {
+        let mut toggled = self.clone();
+        if let Some(bit) = toggled.bits_mut().nth(i) {
+            *bit = !*bit;
+        }
+        toggled
+    }
+
+    pub fn is_zero(&self) -> bool {
+        !self.exponent_bits.iter().any(|&b| b) && !self.significand_bits.iter().any(|&b| b)
+    }
+
+    /// Whether the whole all ones exponent is given over to infinities and NaNs, as in
+    /// IEEE 754. A format with only one of the two reserves just the all ones encoding for it,
+    /// and one with neither reserves nothing.
+    pub const fn reserves_top_binade(&self) -> bool {
+        self.options.infinities && self.options.nans
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.options.infinities
+            && self.exponent_bits.iter().all(|&b| b)
+            && if self.options.nans {
+                self.significand_bits == self.infinity_significand()
+            } else {
+                self.significand_bits.iter().all(|&b| b)
+            }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.options.nans
+            && self.exponent_bits.iter().all(|&b| b)
+            && if self.options.infinities {
+                self.significand_bits != self.infinity_significand()
+            } else {
+                self.significand_bits.iter().all(|&b| b)
+            }
+    }
+
+    /// A NaN with the quiet bit clear. Formats with a single NaN encoding have no signaling
+    /// NaNs.
+    pub fn is_signaling_nan(&self) -> bool {
+        self.is_nan()
+            && self.reserves_top_binade()
+            && self.significand_bits.get(self.quiet_bit()) == Some(&false)
+    }
+
+    /// A copy with the quiet bit set, which turns a signaling NaN into a quiet one with the
+    /// same payload.
+    pub fn quieted(&self) -> Self {
+        let mut quiet = self.clone();
+        if let Some(bit) = quiet.significand_bits.get_mut(self.quiet_bit()) {
+            *bit = true;
+        }
+        quiet
+    }
+
+    /// The payload of a NaN, the significand bits after the quiet bit, or `None` if `self`
+    /// isn't a NaN or is the only NaN of its format.
+    pub fn nan_payload(&self) -> Option<BigUint> {
+        if !self.is_nan() || !self.reserves_top_binade() {
+            return None;
+        }
+        let payload = self.significand_bits.iter().skip(self.quiet_bit() + 1);
+        Some(BigUint::from_bits(payload.copied()))
+    }
+
+    /// Which of IEEE 754's classes the encoding is in. Only a zero exponent field makes a
+    /// nonzero value subnormal, so explicit leading bits are classed by the exponent alone.
+    pub fn class(&self) -> Class {
+        let negative = self.sign_bit;
+        if self.is_signaling_nan() {
+            Class::SignalingNan
+        } else if self.is_nan() {
+            Class::QuietNan
+        } else if self.is_infinite() {
+            if negative {
+                Class::NegativeInfinity
+            } else {
+                Class::PositiveInfinity
+            }
+        } else if self.is_zero() {
+            if negative {
+                Class::NegativeZero
+            } else {
+                Class::PositiveZero
+            }
+        } else if !self.exponent_bits.iter().any(|&b| b) {
+            if negative {
+                Class::NegativeSubnormal
+            } else {
+                Class::PositiveSubnormal
+            }
+        } else if negative {
+            Class::NegativeNormal
+        } else {
+            Class::PositiveNormal
+        }
+    }
+
+    /// Index in the significand of the bit which distinguishes quiet NaNs from signaling ones:
+    /// the first one after the leading bit.
+    fn quiet_bit(&self) -> usize {
+        usize::from(!self.options.implicit_bit)
+    }
+
+    /// Which kind of non-canonical encoding `self` is, if any. Only formats with an explicit
+    /// leading bit have them.
+    pub fn non_canonical(&self) -> Option<NonCanonical> {
+        if self.options.implicit_bit || self.significand_bits[0] == self.normal_leading_bit() {
+            return None;
+        }
+        let top = self.exponent_bits.iter().all(|&b| b);
+        if self.significand_bits[0] {
+            Some(NonCanonical::PseudoDenormal)
+        } else if top && self.reserves_top_binade() {
+            if self.significand_bits.iter().any(|&b| b) {
+                Some(NonCanonical::PseudoNan)
+            } else {
+                Some(NonCanonical::PseudoInfinity)
+            }
+        } else {
+            Some(NonCanonical::Unnormal)
+        }
+    }
+
+    /// The leading bit a canonical encoding with this exponent would have: set unless the
+    /// exponent field is zero.
+    fn normal_leading_bit(&self) -> bool {
+        self.exponent_bits.iter().any(|&b| b)
+    }
+
+    /// The leading bit of the significand, whether stored or implied by the exponent.
+    pub fn leading_bit(&self) -> bool {
+        if self.options.implicit_bit {
+            self.normal_leading_bit()
+        } else {
+            self.significand_bits[0]
+        }
+    }
+
+    /// The significand of infinity in formats which give it the whole all ones exponent: zero
+    /// apart from any explicit leading bit.
+    fn infinity_significand(&self) -> Vec<bool> {
+        let mut significand = vec![false; self.significand_bits.len()];
+        significand[0] = !self.options.implicit_bit;
+        significand
+    }
+
+    /// Zero of the given sign with the same layout as `self`.
+    pub fn zero(&self, sign_bit: bool) -> Self {
+        Self {
+            sign_bit,
+            exponent_bits: vec![false; self.exponent_bits.len()],
+            significand_bits: vec![false; self.significand_bits.len()],
+            options: self.options,
+        }
+    }
+
+    /// The encoding of the given sign with every other bit set.
+    fn all_ones(&self, sign_bit: bool) -> Self {
+        Self {
+            sign_bit,
+            exponent_bits: vec![true; self.exponent_bits.len()],
+            significand_bits: vec![true; self.significand_bits.len()],
+            options: self.options,
+        }
+    }
+
+    /// Infinity of the given sign with the same layout as `self`, or `None` if the format
+    /// has no infinities.
+    pub fn infinity(&self, sign_bit: bool) -> Option<Self> {
+        if !self.options.infinities {
+            return None;
+        }
+        let mut infinity = self.all_ones(sign_bit);
+        if self.options.nans {
+            infinity.significand_bits = self.infinity_significand();
+        }
+        Some(infinity)
+    }
+
+    /// The result of an operation whose exact result is infinite: infinity, or failing that
+    /// NaN, or in a format with neither the largest finite value.
+    pub fn infinite_result(&self, sign_bit: bool) -> Self {
+        self.infinity(sign_bit)
+            .or_else(|| self.default_nan())
+            .unwrap_or_else(|| self.max_finite(sign_bit))
+    }
+
+    /// What a value of the given sign too large for the format becomes when rounding to
+    /// nearest: infinity (or its stand-in), or the largest finite value if the format
+    /// saturates.
+    pub fn overflowed(&self, sign_bit: bool) -> Self {
+        match self.options.overflow {
+            Overflow::Infinity => self.infinite_result(sign_bit),
+            Overflow::Saturate => self.max_finite(sign_bit),
+        }
+    }
+
+    /// The largest finite value of the given sign with the same layout as `self`.
+    pub fn max_finite(&self, sign_bit: bool) -> Self {
+        let mut max = self.all_ones(sign_bit);
+        if self.reserves_top_binade() {
+            *max.exponent_bits
+                .last_mut()
+                .expect("exponent is never empty") = false;
+        } else if self.options.infinities || self.options.nans {
+            *max.significand_bits
+                .last_mut()
+                .expect("significand is never empty") = false;
+        }
+        max
+    }
+
+    /// The smallest positive normal value with the same layout as `self`, or `None` if a
+    /// single exponent bit leaves no room for normal values.
+    pub fn min_normal(&self) -> Option<Self> {
+        if self.exponent_bits.len() == 1 && self.reserves_top_binade() {
+            return None;
+        }
+        let mut min = self.zero(false);
+        *min.exponent_bits
+            .last_mut()
+            .expect("exponent is never empty") = true;
+        min.significand_bits[0] = !self.options.implicit_bit;
+        Some(min)
+    }
+
+    /// The smallest positive subnormal value with the same layout as `self`.
+    pub fn min_subnormal(&self) -> Self {
+        let mut min = self.zero(false);
+        *min.significand_bits
+            .last_mut()
+            .expect("significand is never empty") = true;
+        min
+    }
+
+    /// How many encodings of the format are finite, zeros of both signs included. Finite
+    /// magnitudes run from zero up to the largest finite value with nothing reserved between.
+    pub fn finite_count(&self) -> BigUint {
+        let mut magnitudes = self.max_finite(false).to_big_bits();
+        magnitudes.add_small(1);
+        magnitudes.shl(1)
+    }
+
+    /// Every finite encoding with the sign bit clear, in order of their bits. Only formats
+    /// which fit in a `u64` are enumerated fully.
+    pub fn nonnegative_finite(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..=self.max_finite(false).to_bits()).map(move |bits| self.with_bits(bits))
+    }
+
+    /// `2^e` with the same layout as `self`, or `None` if it isn't representable.
+    pub fn power_of_two(&self, e: i32) -> Option<Self> {
+        let significand_len = self.significand_bits.len();
+        let significand_len_i32 = self.precision() - 1;
+        let mut power = self.zero(false);
+        if (self.emin()..=self.emax()).contains(&e) {
+            // The bias is at most `MAX_BIAS`, so fits in an `i32`, and `e` is at least `emin`, so
+            // the biased exponent is positive.
+            #[allow(
+                clippy::cast_sign_loss,
+                clippy::cast_possible_truncation,
+                clippy::cast_possible_wrap
+            )]
+            let exponent = (e + self.bias() as i32) as u64;
+            power.set_field(BitType::Exponent, exponent);
+            if !self.options.implicit_bit {
+                power.set_bit(BitType::Significand, significand_len - 1, true);
+            }
+            Some(power)
+        } else if (self.emin() - significand_len_i32..self.emin()).contains(&e) {
+            #[allow(clippy::cast_sign_loss)]
+            let weight = (e - self.emin() + significand_len_i32) as usize;
+            power.set_bit(BitType::Significand, weight, true);
+            Some(power)
+        } else {
+            None
+        }
+    }
+
+    /// The least representable value greater than `self`, as IEEE 754 `nextUp`, or `None` for
+    /// NaNs and for the largest value of the format.
+    pub fn next_up(&self) -> Option<Self> {
+        if self.is_nan() {
+            return None;
+        }
+        if self.is_infinite() {
+            return if self.sign_bit {
+                Some(self.max_finite(true))
+            } else {
+                None
+            };
+        }
+        // Encodings with an explicit leading bit can be unnormalized, and stepping from those
+        // would skip values, so start from the normalized encoding of the same value.
+        let mut next = if self.options.implicit_bit {
+            self.clone()
+        } else {
+            self.convert(self, RoundingMode::TiesToEven)
+                .map_or_else(|| self.clone(), |(normal, _)| normal)
+        };
+        if next.is_zero() {
+            next.sign_bit = false;
+        }
+        // Check before stepping, as in a format without infinities or NaNs the largest value
+        // is all ones and stepping would carry out of the top and wrap around to zero.
+        let max = self.max_finite(false);
+        if !next.sign_bit
+            && (&next.exponent_bits, &next.significand_bits)
+                >= (&max.exponent_bits, &max.significand_bits)
+        {
+            return self.infinity(false);
+        }
+        next.step_magnitude(!next.sign_bit);
+        Some(next)
+    }
+
+    /// The greatest representable value less than `self`, as IEEE 754 `nextDown`.
+    pub fn next_down(&self) -> Option<Self> {
+        let mut negated = self.clone();
+        negated.sign_bit = !negated.sign_bit;
+        let mut next = negated.next_up()?;
+        next.sign_bit = !next.sign_bit;
+        Some(next)
+    }
+
+    /// The next bit pattern, adding one to the raw encoding as an unsigned integer and wrapping
+    /// from all ones back to zero. For positive values this is the next value up until the
+    /// patterns run into infinity and the NaNs, and for negative ones the next value down.
+    pub fn incremented(&self) -> Self {
+        let mut next = self.clone();
+        for bit in next.bits_mut().collect::<Vec<_>>().into_iter().rev() {
+            *bit = !*bit;
+            if *bit {
+                break;
+            }
+        }
+        next
+    }
+
+    /// Adds or subtracts one in the last place of the magnitude, carrying from the significand
+    /// into the exponent, and renormalizes any explicit leading bit.
+    fn step_magnitude(&mut self, up: bool) {
+        for bit in self
+            .exponent_bits
+            .iter_mut()
+            .chain(self.significand_bits.iter_mut())
+            .rev()
+        {
+            let carry = *bit == up;
+            *bit = !*bit;
+            if !carry {
+                break;
+            }
+        }
+        if self.options.implicit_bit {
+            return;
+        }
+        let exponent = self.field(BitType::Exponent);
+        match (up, exponent, self.significand_bits[0]) {
+            // Carrying out of a subnormal significand reaches the smallest normal exponent.
+            (true, 0, true) => self.set_field(BitType::Exponent, 1),
+            // Carrying out of a normal significand leaves it all zeros.
+            (true, e, false) if e != 0 => self.significand_bits[0] = true,
+            // Borrowing into the leading bit of the smallest normal values makes a subnormal.
+            (false, 1, false) => self.set_field(BitType::Exponent, 0),
+            // Otherwise it belongs to the binade below.
+            (false, e, false) if e != 0 => {
+                self.set_field(BitType::Exponent, e - 1);
+                self.significand_bits[0] = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Precision in bits, including the leading bit whether or not it is stored.
+    // Widths are at most 256 bits, so none of these casts can truncate.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn precision(&self) -> i32 {
+        self.significand_bits.len() as i32 + i32::from(self.options.implicit_bit)
+    }
+
+    /// The largest unbiased exponent of a finite value.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn emax(&self) -> i32 {
+        let reserved = i32::from(self.reserves_top_binade());
+        (1 << self.exponent_bits.len()) - 1 - reserved - self.bias() as i32
+    }
+
+    /// The unbiased exponent of the smallest normal value.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn emin(&self) -> i32 {
+        1 - self.bias() as i32
+    }
+
+    /// The default quiet NaN with the same layout as `self`, or `None` if the format has no
+    /// NaNs.
+    pub fn default_nan(&self) -> Option<Self> {
+        if !self.options.nans {
+            return None;
+        }
+        let mut nan = self.all_ones(false);
+        if self.options.infinities {
+            nan.significand_bits = self.infinity_significand();
+            match nan.significand_bits.get_mut(self.quiet_bit()) {
+                Some(bit) => *bit = true,
+                // A lone explicit leading bit leaves no room for a quiet bit, so the only NaN
+                // is the one with the leading bit clear.
+                None => nan.significand_bits[0] = false,
+            }
+        }
+        Some(nan)
+    }
+
+    /// The exponent bias, which is the standard one unless the options override it.
+    pub fn bias(&self) -> u64 {
+        self.options.bias.unwrap_or_else(|| self.standard_bias())
+    }
+
+    /// `2^(k - 1) - 1` for an exponent of `k` bits, as IEEE 754 has it.
+    pub const fn standard_bias(&self) -> u64 {
+        (1 << (self.exponent_bits.len() - 1)) - 1
+    }
+
+    pub const fn same_layout(&self, other: &Self) -> bool {
+        self.exponent_bits.len() == other.exponent_bits.len()
+            && self.significand_bits.len() == other.significand_bits.len()
+    }
+
+    /// Number of bits, which is never zero as there is always a sign bit.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        1 + self.exponent_bits.len() + self.significand_bits.len()
+    }
+
+    /// Builds an encoding from the low `1 + exponent_len + significand_len` bits of `bits`.
+    pub fn from_bits(exponent_len: usize, significand_len: usize, bits: u64) -> Self {
+        Self {
+            sign_bit: false,
+            exponent_bits: vec![false; exponent_len],
+            significand_bits: vec![false; significand_len],
+            options: Options::default(),
+        }
+        .with_bits(bits)
+    }
+
+    /// Builds an encoding with the same layout and options as `self` from the low bits of
+    /// `bits`. Any bits beyond the 64th are clear.
+    pub fn with_bits(&self, bits: u64) -> Self {
+        let bit = |i: usize| i < 64 && (bits >> i) & 1 == 1;
+        let exponent_len = self.exponent_bits.len();
+        let significand_len = self.significand_bits.len();
+        Self {
+            sign_bit: bit(exponent_len + significand_len),
+            exponent_bits: (0..exponent_len)
+                .rev()
+                .map(|i| bit(significand_len + i))
+                .collect(),
+            significand_bits: (0..significand_len).rev().map(bit).collect(),
+            options: self.options,
+        }
+    }
+
+    /// Builds an encoding with the same layout and options as `self` from hex digits, or
+    /// `None` if they aren't hex or set bits beyond the width of the encoding.
+    pub fn with_hex(&self, digits: &str) -> Option<Self> {
+        if digits.is_empty() {
+            return None;
+        }
+        let mut bits = Vec::new();
+        for c in digits.chars() {
+            let digit = c.to_digit(16)?;
+            bits.extend((0..4).rev().map(|i| (digit >> i) & 1 == 1));
+        }
+        let excess = bits.len().saturating_sub(self.len());
+        if bits[..excess].iter().any(|&b| b) {
+            return None;
+        }
+        let mut encoding = self.zero(false);
+        let padding = iter::repeat_n(false, self.len().saturating_sub(bits.len()));
+        for (bit, value) in encoding
+            .bits_mut()
+            .zip(padding.chain(bits[excess..].iter().copied()))
+        {
+            *bit = value;
+        }
+        Some(encoding)
+    }
+
+    /// Rounds `numerator / denominator`, negated if `sign_bit` is set, in the format of `self`
+    /// and in the direction of `rounding`, raising flags as IEEE 754 does with tininess
+    /// detected before rounding.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_sign_loss
+    )]
+    pub fn round_ratio(
+        &self,
+        sign_bit: bool,
+        numerator: &BigUint,
+        denominator: &BigUint,
+        rounding: RoundingMode,
+    ) -> (Self, Flags) {
+        let mut flags = Flags::default();
+        if numerator.is_zero() {
+            return (self.zero(sign_bit), flags);
+        }
+        let p = self.precision();
+        let significand_len = self.significand_bits.len();
+
+        // The exponent of the leading bit of the exact result.
+        let shifted_cmp = |t: i32| {
+            if t >= 0 {
+                numerator.cmp(&denominator.shl(t as usize))
+            } else {
+                numerator.shl(-t as usize).cmp(denominator)
+            }
+        };
+        let mut t = numerator.bit_len() as i32 - denominator.bit_len() as i32;
+        if shifted_cmp(t) == Ordering::Less {
+            t -= 1;
+        }
+        flags.underflow = t < self.emin();
+
+        // Divide by the weight of the last significand bit, which can't go below that of the
+        // subnormals, and round the quotient to an integer.
+        let mut last = t.max(self.emin()) - (p - 1);
+        let (dividend, divisor) = if last >= 0 {
+            (numerator.clone(), denominator.shl(last as usize))
+        } else {
+            (numerator.shl(-last as usize), denominator.clone())
+        };
+        let (mut significand, remainder) = dividend.div_rem(&divisor);
+        flags.inexact = !remainder.is_zero();
+        flags.underflow &= flags.inexact;
+        let round_up = flags.inexact
+            && rounding.rounds_up(sign_bit, remainder.shl(1).cmp(&divisor), significand.bit(0));
+        if round_up {
+            significand.add_small(1);
+            if significand.bit_len() > p as usize {
+                significand = significand.shr(1);
+                last += 1;
+            }
+        }
+
+        let normal = significand.bit_len() == p as usize;
+        let exponent = if normal {
+            i64::from(last + p - 1) + self.bias() as i64
+        } else {
+            0
+        };
+        // Any implicit leading bit is above the stored ones, so it is dropped here.
+        let mut rounded = self.zero(sign_bit);
+        rounded.set_field(BitType::Exponent, exponent as u64);
+        for weight in 0..significand_len {
+            rounded.set_bit(BitType::Significand, weight, significand.bit(weight));
+        }
+        let max = self.max_finite(sign_bit);
+        if exponent >= 1 << self.exponent_bits.len()
+            || (&rounded.exponent_bits, &rounded.significand_bits)
+                > (&max.exponent_bits, &max.significand_bits)
+        {
+            flags.overflow = true;
+            flags.inexact = true;
+            // Rounding toward zero stops at the largest finite value.
+            let away = rounding.rounds_up(sign_bit, Ordering::Greater, false);
+            return (if away { self.overflowed(sign_bit) } else { max }, flags);
+        }
+        (rounded, flags)
+    }
+
+    /// `self` rounded into the format of `format` in the direction of `rounding`, with the
+    /// flags the conversion raises. NaNs become the default NaN, or `None` if the format has
+    /// no NaNs.
+    pub fn convert(&self, format: &Self, rounding: RoundingMode) -> Option<(Self, Flags)> {
+        let signaling = Flags {
+            invalid: self.is_signaling_nan(),
+            ..Flags::default()
+        };
+        if self.is_nan() {
+            return format.default_nan().map(|nan| (nan, signaling));
+        }
+        if self.is_infinite() {
+            let flags = Flags {
+                invalid: !format.options.infinities,
+                ..Flags::default()
+            };
+            return Some((format.infinite_result(self.sign_bit), flags));
+        }
+        let (numerator, denominator) = self.exact_ratio();
+        Some(format.round_ratio(self.sign_bit, &numerator, &denominator, rounding))
+    }
+
+    /// The integer significand, including any implicit leading bit, and the power of two it
+    /// is scaled by.
+    pub fn scaled_significand(&self) -> (BigUint, i32) {
+        let exponent = self.field(BitType::Exponent);
+        let leading = self.options.implicit_bit && exponent != 0;
+        let significand =
+            BigUint::from_bits(iter::once(leading).chain(self.significand_bits.iter().copied()));
+        // Subnormals share the exponent of the smallest normal values, and the significand is
+        // an integer, so scale by the weight of its last bit.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let scale = exponent.max(1) as i32 - self.bias() as i32 - (self.precision() - 1);
+        (significand, scale)
+    }
+
+    /// Compares the values as IEEE 754 does, with `None` if either is a NaN and the two zeros
+    /// equal. The encodings may be in different formats.
+    pub fn compare(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // Each value as a sign and a class: zero, finite or infinite.
+        let key = |x: &Self| {
+            let class = if x.is_zero() {
+                0
+            } else if x.is_infinite() {
+                2
+            } else {
+                1
+            };
+            (x.sign_bit && class != 0, class)
+        };
+        let ((sign, class), (other_sign, other_class)) = (key(self), key(other));
+        let magnitude_order = if class != other_class || class != 1 {
+            class.cmp(&other_class)
+        } else {
+            // Both ratios have power of two denominators, so cross multiplying is exact.
+            let (n, d) = self.exact_ratio();
+            let (other_n, other_d) = other.exact_ratio();
+            n.mul(&other_d).cmp(&other_n.mul(&d))
+        };
+        Some(match (sign, other_sign) {
+            (false, false) => magnitude_order,
+            (true, true) => magnitude_order.reverse(),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        })
+    }
+
+    /// The base 2 logarithm of one ulp here: the weight of the last significand bit, which is
+    /// the spacing of the values in this binade, or of the largest finite values for
+    /// infinity.
+    pub fn ulp_exponent(&self) -> i32 {
+        let (_, scale) = if self.is_infinite() {
+            self.max_finite(false).scaled_significand()
+        } else {
+            self.scaled_significand()
+        };
+        scale
+    }
+
+    /// The base 2 logarithm of the number of values from one power of two up to the next, which
+    /// is the same in every binade, and also the number of subnormals.
+    pub fn binade_size_exponent(&self) -> i32 {
+        self.precision() - 1
+    }
+
+    /// The base 10 logarithm of the magnitude of a finite value, even beyond the range of
+    /// `f64`.
+    pub fn log10(&self) -> f64 {
+        let (significand, scale) = self.scaled_significand();
+        f64::from(scale).mul_add(2_f64.log10(), significand.to_f64().log10())
+    }
+
+    /// The base 2 logarithm of the magnitude of a finite value, even beyond the range of
+    /// `f64`.
+    pub fn log2(&self) -> f64 {
+        let (significand, scale) = self.scaled_significand();
+        f64::from(scale) + significand.to_f64().log2()
+    }
+
+    /// The base 2 logarithm of the magnitude if it is an integer, as it is exactly when the
+    /// significand has a single one bit: when a normal value's fraction is all zeros.
+    pub fn exact_log2(&self) -> Option<i32> {
+        let (significand, scale) = self.scaled_significand();
+        if self.is_nan() || self.is_infinite() || significand.is_zero() {
+            return None;
+        }
+        let len = significand.bit_len();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let log = scale + len as i32 - 1;
+        Some(log).filter(|_| significand.trailing_zeros() == len - 1)
+    }
+
+    /// The base 10 logarithm of the magnitude if it is an integer. That needs a power of ten,
+    /// and only the nonnegative ones are representable, as the others have factors of five in
+    /// their denominators.
+    pub fn exact_log10(&self) -> Option<u32> {
+        if self.is_nan() || self.is_infinite() || self.is_zero() {
+            return None;
+        }
+        let (mut numerator, power) = self.reduced_ratio();
+        if power != 0 {
+            return None;
+        }
+        let one = BigUint::from_u64(1);
+        let mut log = 0;
+        while numerator != one {
+            if numerator.div_small(10) != 0 {
+                return None;
+            }
+            log += 1;
+        }
+        Some(log)
+    }
+
+    /// The magnitude of a finite value as a numerator and a power of two denominator.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn exact_ratio(&self) -> (BigUint, BigUint) {
+        let (significand, scale) = self.scaled_significand();
+        let one = BigUint::from_u64(1);
+        if scale >= 0 {
+            (significand.shl(scale as usize), one)
+        } else {
+            (significand, one.shl(-scale as usize))
+        }
+    }
+
+    /// The magnitude of a finite value as a fraction in lowest terms: the numerator and the
+    /// power of two which is the denominator, e.g. `(3602879701896397, 55)` for 0.1 in
+    /// binary64.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn reduced_ratio(&self) -> (BigUint, usize) {
+        let (significand, scale) = self.scaled_significand();
+        if scale >= 0 || significand.is_zero() {
+            return (significand.shl(scale.max(0) as usize), 0);
+        }
+        let shift = significand.trailing_zeros().min(-scale as usize);
+        (significand.shr(shift), -scale as usize - shift)
+    }
+
+    /// The bits of each field separated by bars, e.g. `0 | 01111111111 | 0000...` for 1.0.
+    pub fn field_string(&self) -> String {
+        let field =
+            |bits: &[bool]| -> String { bits.iter().map(|&b| if b { '1' } else { '0' }).collect() };
+        [
+            field(&[self.sign_bit]),
+            field(&self.exponent_bits),
+            field(&self.significand_bits),
+        ]
+        .join(" | ")
+    }
+
+    /// Encodes the field widths and bits for use in a URL fragment, e.g. `11-52-3FF0000000000000`,
+    /// followed by letters for any options which differ from IEEE 754: `S` for saturating,
+    /// `I` for no infinities, `N` for no NaNs and `E` for an explicit leading bit, then any
+    /// overridden bias, e.g. `5-2-3C--B16` or `5-2-3C-I-B16`.
+    pub fn to_fragment(&self) -> String {
+        let mut fragment = format!(
+            "{}-{}-{}",
+            self.exponent_bits.len(),
+            self.significand_bits.len(),
+            &self.hex()[2..]
+        );
+        let letters: String = [
+            (self.options.overflow == Overflow::Saturate, 'S'),
+            (!self.options.infinities, 'I'),
+            (!self.options.nans, 'N'),
+            (!self.options.implicit_bit, 'E'),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|&(_, letter)| letter)
+        .collect();
+        if !letters.is_empty() || self.options.bias.is_some() {
+            fragment.push('-');
+            fragment.push_str(&letters);
+        }
+        if let Some(bias) = self.options.bias {
+            write!(fragment, "-B{}", bias).expect("writing to a String never fails");
+        }
+        fragment
+    }
+
+    /// Inverse of `to_fragment`, returning `None` for malformed fragments or unsupported widths.
+    pub fn from_fragment(fragment: &str) -> Option<Self> {
+        let mut parts = fragment.split('-');
+        let exponent_len: usize = parts.next()?.parse().ok()?;
+        let significand_len: usize = parts.next()?.parse().ok()?;
+        let digits = parts.next()?;
+        let letters = parts.next().unwrap_or("");
+        if !letters.chars().all(|c| "SINE".contains(c)) {
+            return None;
+        }
+        let bias = match parts.next() {
+            Some(part) => Some(part.strip_prefix('B')?.parse().ok()?),
+            None => None,
+        };
+        let options = Options {
+            overflow: if letters.contains('S') {
+                Overflow::Saturate
+            } else {
+                Overflow::Infinity
+            },
+            infinities: !letters.contains('I'),
+            nans: !letters.contains('N'),
+            implicit_bit: !letters.contains('E'),
+            bias,
+        };
+        if parts.next().is_some()
+            || bias.is_some_and(|bias| bias > MAX_BIAS)
+            || !(1..=MAX_EXPONENT_BITS).contains(&exponent_len)
+            || !(1..=MAX_SIGNIFICAND_BITS).contains(&significand_len)
+        {
+            return None;
+        }
+        Self {
+            options,
+            ..Self::from_bits(exponent_len, significand_len, 0)
+        }
+        .with_hex(digits)
+    }
+
+    /// Whether the raw encoding fits in the integers of `to_bits` and the like, which only
+    /// hold its low 64 bits.
+    pub const fn fits_u64(&self) -> bool {
+        self.len() <= 64
+    }
+
+    /// The raw encoding as an integer, with the last significand bit least significant.
+    pub fn to_bits(&self) -> u64 {
+        self.bits().fold(0, |acc, b| (acc << 1) | u64::from(b))
+    }
+
+    /// The raw encoding as a two's complement integer of the encoding's width.
+    pub fn to_signed_bits(&self) -> i64 {
+        let unused = 64 - self.len();
+        #[allow(clippy::cast_possible_wrap)]
+        let bits = (self.to_bits() << unused) as i64;
+        bits >> unused
+    }
+
+    /// The raw encoding as an integer of any width, for encodings too wide for `to_bits`.
+    pub fn to_big_bits(&self) -> BigUint {
+        BigUint::from_bits(self.bits())
+    }
+
+    /// The raw encoding as a two's complement integer of any width: whether it is negative,
+    /// and its magnitude.
+    pub fn to_big_signed_bits(&self) -> (bool, BigUint) {
+        let bits = self.to_big_bits();
+        if self.sign_bit {
+            (true, BigUint::from_u64(1).shl(self.len()).sub(&bits))
+        } else {
+            (false, bits)
+        }
+    }
+
+    /// The bits without the sign, negated for negative values, so that integer order matches
+    /// numeric order (apart from NaNs and the two zeros comparing unequal).
+    pub fn to_ordinal(&self) -> i64 {
+        let magnitude = self.to_bits() & !(1 << (self.len() - 1));
+        #[allow(clippy::cast_possible_wrap)]
+        let magnitude = magnitude as i64;
+        if self.sign_bit {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// The bits mapped so that unsigned integer order matches numeric order: the sign bit is
+    /// set for positive values and every bit is inverted for negative ones.
+    pub fn to_sortable_bits(&self) -> u64 {
+        let width = self.len();
+        let mask = u64::MAX >> (64 - width);
+        let bits = self.to_bits();
+        if self.sign_bit {
+            !bits & mask
+        } else {
+            bits | 1 << (width - 1)
+        }
+    }
+
+    /// Inverse of `to_ordinal` for an encoding with the same layout as `self`.
+    pub fn with_ordinal(&self, ordinal: i64) -> Self {
+        let mut encoding = self.with_bits(ordinal.unsigned_abs());
+        encoding.sign_bit = ordinal < 0;
+        encoding
+    }
+
+    /// The raw encoding in hexadecimal, e.g. `0x3FF0000000000000` for binary64 1.0.
+    pub fn hex(&self) -> String {
+        let mut hex = "0x".to_owned();
+        for i in 0..self.nibble_count() {
+            write!(hex, "{:X}", self.nibble(i)).expect("writing to a String never fails");
+        }
+        hex
+    }
+
+    /// The encoding as bytes, most significant first, with zeros above the sign bit to make up
+    /// whole bytes.
+    pub fn bytes(&self) -> Vec<u8> {
+        let padding = (8 - self.len() % 8) % 8;
+        let bits: Vec<bool> = iter::repeat_n(false, padding).chain(self.bits()).collect();
+        bits.chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, &b| (acc << 1) | u8::from(b)))
+            .collect()
+    }
+
+    /// Number of digits in `hex()`.
+    pub const fn nibble_count(&self) -> usize {
+        self.len().div_ceil(4)
+    }
+
+    /// Hex digit `i` of `hex()`, counting from the most significant.
+    pub fn nibble(&self, i: usize) -> u64 {
+        self.nibble_bits(i).fold(0, |acc, j| {
+            (acc << 1) | j.map_or(0, |j| u64::from(self.bits().nth(j) == Some(true)))
+        })
+    }
+
+    /// Overwrites hex digit `i`, dropping any bits of `digit` beyond the width of the encoding.
+    pub fn set_nibble(&mut self, i: usize, digit: u64) {
+        for (k, j) in self.nibble_bits(i).enumerate() {
+            if let Some(bit) = j.and_then(|j| self.bits_mut().nth(j)) {
+                *bit = (digit >> (3 - k)) & 1 == 1;
+            }
+        }
+    }
+
+    /// Positions in `bits()` of the four bits of hex digit `i`, most significant first, with
+    /// `None` for the padding in front of the sign bit.
+    fn nibble_bits(&self, i: usize) -> impl Iterator<Item = Option<usize>> {
+        let padding = 4 * self.nibble_count() - self.len();
+        (4 * i..4 * i + 4).map(move |j| j.checked_sub(padding))
+    }
+
+    /// Which bits differ from `other`, or `None` if the field widths differ.
+    pub fn changes_from(&self, other: &Self) -> Option<Vec<bool>> {
+        if self.same_layout(other) {
+            Some(self.bits().zip(other.bits()).map(|(a, b)| a != b).collect())
+        } else {
+            None
+        }
+    }
+
+    /// The value, rounded to the nearest `f64` if the format is wider. NaNs keep their sign,
+    /// whether they are quiet and as much of their payload as fits.
+    pub fn value(&self) -> f64 {
+        if let Some(payload) = self.nan_payload() {
+            // Align the payload with the top of the 51 bits binary64 has for it, as widening
+            // conversions do, but without quieting signaling NaNs.
+            // A lone explicit leading bit leaves no room for a quiet bit, nor for a payload.
+            let payload_len = self
+                .significand_bits
+                .len()
+                .saturating_sub(self.quiet_bit() + 1);
+            let payload = if payload_len > 51 {
+                payload.shr(payload_len - 51)
+            } else {
+                payload.shl(51 - payload_len)
+            };
+            let payload = payload.to_u64().expect("at most 51 bits");
+            let quiet = u64::from(!self.is_signaling_nan()) << 51;
+            // A signaling NaN needs a payload to be told apart from infinity.
+            let fraction = if quiet | payload == 0 {
+                1
+            } else {
+                quiet | payload
+            };
+            return f64::from_bits(u64::from(self.sign_bit) << 63 | 0x7FF << 52 | fraction);
+        }
+        if self.is_nan() {
+            return f64::NAN;
+        }
+        let (significand, scale) = self.scaled_significand();
+        // A bias override can scale by less than the smallest power of two f64 has, even when
+        // the value itself is in range.
+        if self.precision() > 53
+            || self.exponent_bits.len() > BINARY_64_EXPONENT_BITS
+            || scale < MIN_F64_SCALE
+        {
+            let (nearest, _) = self
+                .convert(&FormatPreset::Binary64.zero(), RoundingMode::TiesToEven)
+                .expect("binary64 has NaNs");
+            return f64::from_bits(nearest.to_bits());
+        }
+        let significand = significand.to_u64().expect("at most 53 bits");
+        // The significand has at most 53 bits, so both factors and their product are exact
+        // unless the product is beyond the range of f64, as the top binade of an 11 bit
+        // exponent without reserved encodings is.
+        #[allow(clippy::cast_precision_loss)]
+        let magnitude = if self.is_infinite() {
+            f64::INFINITY
+        } else if significand == 0 {
+            0.0
+        } else {
+            significand as f64 * f64::from(scale).exp2()
+        };
+        if self.sign_bit {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// A single bit of an encoding.
+#[derive(Debug, Copy, Clone)]
+pub struct Bit {
+    /// Position counting from the sign bit, as in `Encoding::bits()`.
+    pub index: usize,
+    pub field: BitType,
+    /// Position within the field counting from its least significant bit.
+    pub weight: usize,
+    pub value: bool,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum BitType {
+    Sign,
+    Exponent,
+    Significand,
+}
+
+impl BitType {
+    pub const ALL: [Self; 3] = [Self::Sign, Self::Exponent, Self::Significand];
+
+    pub const fn default_color(self) -> &'static str {
+        match self {
+            Self::Sign => "#D72638",
+            Self::Exponent => "#00916E",
+            Self::Significand => "#F49D37",
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Sign => "Sign",
+            Self::Exponent => "Exponent",
+            Self::Significand => "Significand",
+        }
+    }
+}
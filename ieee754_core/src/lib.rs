@@ -0,0 +1,51 @@
+//! Encoding and decoding numbers in IEEE 754 style formats of any width, without any of the
+//! user interface, so that it can be tested natively with `cargo test`.
+#![warn(rust_2018_idioms)]
+// Only the app uses the crate, which checks results where it needs to.
+#![allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+// The only panics are `expect`s on invariants of `Encoding`, such as its fields never being
+// empty, which no input can break.
+#![allow(clippy::missing_panics_doc)]
+// Format strings name their arguments after the string, as in the app.
+#![allow(clippy::uninlined_format_args)]
+
+pub mod bignum;
+pub mod encoding;
+#[cfg(test)]
+mod tests;
+
+use encoding::{BitType, Class, Encoding, Flags, FormatPreset, RoundingMode};
+
+/// The fields of an encoding and the value they hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoded {
+    pub sign: bool,
+    /// The stored exponent field, still biased.
+    pub exponent: u64,
+    /// The stored significand field.
+    pub significand: u64,
+    pub class: Class,
+    /// The value, rounded to the nearest `f64` if the format is wider.
+    pub value: f64,
+}
+
+/// Decodes the low bits of `bits` in the IEEE 754 format with the given field widths.
+pub fn decode(bits: u64, exponent_width: usize, significand_width: usize) -> Decoded {
+    let encoding = Encoding::from_bits(exponent_width, significand_width, bits);
+    Decoded {
+        sign: encoding.sign_bit,
+        exponent: encoding.field(BitType::Exponent),
+        significand: encoding.field(BitType::Significand),
+        class: encoding.class(),
+        value: encoding.value(),
+    }
+}
+
+/// `value` rounded into the format of `format` in the direction of `rounding`, with the flags
+/// raised on the way. `None` for a NaN when the format has none.
+pub fn encode(value: f64, format: &Encoding, rounding: RoundingMode) -> Option<(Encoding, Flags)> {
+    FormatPreset::Binary64
+        .zero()
+        .with_bits(value.to_bits())
+        .convert(format, rounding)
+}
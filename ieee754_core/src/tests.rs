@@ -0,0 +1,140 @@
+//! Unit tests of decoding and encoding, run natively with `cargo test`.
+// Every expected value is exactly representable, so exact comparisons are what's meant.
+#![allow(clippy::float_cmp)]
+
+use super::*;
+use bignum::BigUint;
+
+#[test]
+fn binary16_fields_are_decoded() {
+    let one = decode(0x3C00, 5, 10);
+    assert_eq!(
+        one,
+        Decoded {
+            sign: false,
+            exponent: 15,
+            significand: 0,
+            class: Class::PositiveNormal,
+            value: 1.0,
+        }
+    );
+    let max = decode(0x7BFF, 5, 10);
+    assert_eq!(max.value, 65504.0);
+    let subnormal = decode(0x8001, 5, 10);
+    assert_eq!(subnormal.class, Class::NegativeSubnormal);
+    assert_eq!(subnormal.value, -(2_f64.powi(-24)));
+    assert_eq!(decode(0xFC00, 5, 10).class, Class::NegativeInfinity);
+    assert_eq!(decode(0x7D00, 5, 10).class, Class::SignalingNan);
+    assert_eq!(decode(0x7E00, 5, 10).class, Class::QuietNan);
+}
+
+#[test]
+fn decoding_matches_the_native_formats() {
+    for &bits in &[0, 1, 0x3F80_0000, 0x7F7F_FFFF, 0x8080_0000, 0x4049_0FDB] {
+        assert_eq!(
+            decode(u64::from(bits), 8, 23).value,
+            f64::from(f32::from_bits(bits))
+        );
+    }
+    for &x in &[0.1, -2.5e-310, f64::MAX, f64::MIN_POSITIVE, 1.0 / 3.0] {
+        assert_eq!(decode(x.to_bits(), 11, 52).value, x);
+    }
+}
+
+#[test]
+fn tiny_formats_are_decoded() {
+    // Three exponent bits with a bias of 3 and two significand bits.
+    let values: Vec<f64> = (0..8).map(|bits| decode(bits, 3, 2).value).collect();
+    assert_eq!(
+        values,
+        [0.0, 0.0625, 0.125, 0.1875, 0.25, 0.3125, 0.375, 0.4375]
+    );
+    assert_eq!(decode(0x1B, 3, 2).value, 14.0);
+    assert!(decode(0x1C, 3, 2).value.is_infinite());
+}
+
+#[test]
+fn encoding_rounds_and_raises_flags() {
+    let binary32 = FormatPreset::Binary32.zero();
+    let (tenth, flags) = encode(0.1, &binary32, RoundingMode::TiesToEven).unwrap();
+    assert_eq!(tenth.to_bits(), 0x3DCC_CCCD);
+    assert!(flags.inexact && !flags.overflow);
+    let (tenth, _) = encode(0.1, &binary32, RoundingMode::TowardZero).unwrap();
+    assert_eq!(tenth.to_bits(), 0x3DCC_CCCC);
+
+    let binary16 = FormatPreset::Binary16.zero();
+    let (huge, flags) = encode(1e10, &binary16, RoundingMode::TiesToEven).unwrap();
+    assert!(huge.is_infinite());
+    assert!(flags.overflow && flags.inexact);
+    let (huge, _) = encode(1e10, &binary16, RoundingMode::TowardZero).unwrap();
+    assert_eq!(huge.to_bits(), 0x7BFF);
+    let (tiny, flags) = encode(1e-10, &binary16, RoundingMode::TiesToEven).unwrap();
+    assert!(tiny.is_zero());
+    assert!(flags.underflow && flags.inexact);
+    let (one, flags) = encode(1.0, &binary16, RoundingMode::TiesToEven).unwrap();
+    assert_eq!(one.to_bits(), 0x3C00);
+    assert_eq!(flags, Flags::default());
+}
+
+#[test]
+fn formats_without_nans_have_no_encoding_for_nan() {
+    let e4m3 = FormatPreset::E4M3.zero();
+    assert!(encode(f64::NAN, &e4m3, RoundingMode::TiesToEven).is_some());
+    let mut neither = Encoding::from_bits(3, 2, 0);
+    neither.options.infinities = false;
+    neither.options.nans = false;
+    assert!(encode(f64::NAN, &neither, RoundingMode::TiesToEven).is_none());
+}
+
+#[test]
+fn every_binary16_value_survives_a_round_trip() {
+    let binary16 = FormatPreset::Binary16.zero();
+    for bits in 0..=0xFFFF {
+        let decoded = decode(bits, 5, 10);
+        if decoded.value.is_nan() {
+            continue;
+        }
+        let (encoded, flags) = encode(decoded.value, &binary16, RoundingMode::TiesToEven).unwrap();
+        assert_eq!(encoded.to_bits(), bits);
+        assert!(!flags.inexact);
+    }
+}
+
+#[test]
+fn stepping_stops_at_the_largest_value() {
+    let binary16 = FormatPreset::Binary16.zero();
+    let max = binary16.max_finite(false);
+    assert!(max.next_up().unwrap().is_infinite());
+    // Without infinities or NaNs the largest value is all ones, and nothing lies beyond it.
+    let mut neither = Encoding::from_bits(3, 2, 0x1F);
+    neither.options.infinities = false;
+    neither.options.nans = false;
+    assert!(neither.next_up().is_none());
+    assert!(neither.with_bits(0x3F).next_down().is_none());
+}
+
+#[test]
+fn overridden_biases_reach_beyond_the_range_of_f64() {
+    let mut encoding = FormatPreset::Binary64.zero();
+    encoding.options.bias = Some(1100);
+    encoding.set_field(BitType::Exponent, 30);
+    assert_eq!(encoding.value().to_bits(), 1 << 4);
+}
+
+#[test]
+fn fields_wider_than_64_bits_are_set_from_their_low_bits() {
+    let mut binary128 = Encoding::from_bits(15, 112, 0);
+    binary128.fill_field(BitType::Significand, true);
+    binary128.set_field(BitType::Significand, 1);
+    let set: Vec<bool> = binary128.significand_bits.iter().rev().copied().collect();
+    assert!(set[0] && !set[1..].contains(&true));
+}
+
+#[test]
+fn big_integers_print_in_decimal() {
+    let mut n = BigUint::from_u64(u64::MAX);
+    n.mul_small(10);
+    n.add_small(7);
+    assert_eq!(n.to_string(), "184467440737095516157");
+    assert_eq!(n.shl(3).shr(3), n);
+}
@@ -0,0 +1,161 @@
+//! The basic arithmetic operations, correctly rounded in any format.
+
+use crate::encoding::{divide_by_zero, Encoding, Flags, RoundingMode};
+
+/// The binary operations which can be applied to two values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    pub const ALL: [Self; 4] = [Self::Add, Self::Subtract, Self::Multiply, Self::Divide];
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Subtract => "\u{2212}",
+            Self::Multiply => "\u{D7}",
+            Self::Divide => "\u{F7}",
+        }
+    }
+}
+
+/// Computes `a ∘ b` in the format of `a` as IEEE 754 does, rounding the exact result once in
+/// the direction of `rounding`. `b` must have the same format. NaN operands propagate, with
+/// the first one taking precedence. `None` for invalid operations in formats without NaNs.
+pub fn calculate(
+    operator: Operator,
+    a: &Encoding,
+    b: &Encoding,
+    rounding: RoundingMode,
+) -> Option<(Encoding, Flags)> {
+    let invalid = || {
+        a.default_nan().map(|nan| {
+            (
+                nan,
+                Flags {
+                    invalid: true,
+                    ..Flags::default()
+                },
+            )
+        })
+    };
+    if a.is_nan() || b.is_nan() {
+        let nan = if a.is_nan() { a } else { b };
+        let flags = Flags {
+            invalid: a.is_signaling_nan() || b.is_signaling_nan(),
+            ..Flags::default()
+        };
+        return Some((nan.quieted(), flags));
+    }
+    // Subtraction is addition of the negated operand.
+    let mut b = b.clone();
+    b.sign_bit ^= operator == Operator::Subtract;
+    let sign_bit = a.sign_bit != b.sign_bit;
+    let (a_n, a_d) = a.exact_ratio();
+    let (b_n, b_d) = b.exact_ratio();
+    match operator {
+        Operator::Add | Operator::Subtract
+            if a.is_infinite() && b.is_infinite() && a.sign_bit != b.sign_bit =>
+        {
+            invalid()
+        }
+        Operator::Add | Operator::Subtract if a.is_infinite() => {
+            Some((a.clone(), Flags::default()))
+        }
+        Operator::Add | Operator::Subtract if b.is_infinite() => Some((b, Flags::default())),
+        Operator::Add | Operator::Subtract => {
+            // Both denominators are powers of two, so cross multiplying keeps the sum exact.
+            let (a_n, b_n) = (a_n.mul(&b_d), b_n.mul(&a_d));
+            let denominator = a_d.mul(&b_d);
+            let (sign_bit, numerator) = if a.sign_bit == b.sign_bit {
+                (a.sign_bit, a_n.add(&b_n))
+            } else if a_n >= b_n {
+                (a.sign_bit, a_n.sub(&b_n))
+            } else {
+                (b.sign_bit, b_n.sub(&a_n))
+            };
+            // An exact zero sum of opposite signs is +0, except when rounding down.
+            let sign_bit = if numerator.is_zero() && a.sign_bit != b.sign_bit {
+                rounding == RoundingMode::TowardNegative
+            } else {
+                sign_bit
+            };
+            Some(a.round_ratio(sign_bit, &numerator, &denominator, rounding))
+        }
+        Operator::Multiply
+            if (a.is_zero() && b.is_infinite()) || (a.is_infinite() && b.is_zero()) =>
+        {
+            invalid()
+        }
+        Operator::Multiply if a.is_infinite() || b.is_infinite() => {
+            Some((a.infinite_result(sign_bit), Flags::default()))
+        }
+        Operator::Multiply => {
+            Some(a.round_ratio(sign_bit, &a_n.mul(&b_n), &a_d.mul(&b_d), rounding))
+        }
+        Operator::Divide if a.is_infinite() && b.is_infinite() => invalid(),
+        Operator::Divide if b.is_zero() => divide_by_zero(a, b.sign_bit),
+        Operator::Divide if a.is_infinite() => {
+            Some((a.infinite_result(sign_bit), Flags::default()))
+        }
+        Operator::Divide if b.is_infinite() => Some((a.zero(sign_bit), Flags::default())),
+        Operator::Divide => Some(a.round_ratio(sign_bit, &a_n.mul(&b_d), &a_d.mul(&b_n), rounding)),
+    }
+}
+
+/// The operations on a single value which the operation buttons apply.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum UnaryOperator {
+    Negate,
+    Abs,
+    Double,
+    Halve,
+}
+
+impl UnaryOperator {
+    pub const ALL: [Self; 4] = [Self::Negate, Self::Abs, Self::Double, Self::Halve];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Negate => "Negate",
+            Self::Abs => "Absolute value",
+            Self::Double => "Double",
+            Self::Halve => "Halve",
+        }
+    }
+}
+
+/// Applies `operator` to `a`. Negation and absolute value only touch the sign bit, so they are
+/// exact even for NaNs. Doubling and halving move the exponent by one, rounding in the
+/// direction of `rounding` where halving shifts bits off a subnormal and overflowing to
+/// infinity, or the largest finite value, where doubling leaves the format's range.
+pub fn apply(operator: UnaryOperator, a: &Encoding, rounding: RoundingMode) -> (Encoding, Flags) {
+    let mut result = a.clone();
+    match operator {
+        UnaryOperator::Negate => result.sign_bit = !a.sign_bit,
+        UnaryOperator::Abs => result.sign_bit = false,
+        _ if a.is_nan() => {
+            let flags = Flags {
+                invalid: a.is_signaling_nan(),
+                ..Flags::default()
+            };
+            return (a.quieted(), flags);
+        }
+        // Infinities and zeros are their own doubles and halves.
+        _ if a.is_infinite() || a.is_zero() => {}
+        UnaryOperator::Double => {
+            let (numerator, denominator) = a.exact_ratio();
+            return a.round_ratio(a.sign_bit, &numerator.shl(1), &denominator, rounding);
+        }
+        UnaryOperator::Halve => {
+            let (numerator, denominator) = a.exact_ratio();
+            return a.round_ratio(a.sign_bit, &numerator, &denominator.shl(1), rounding);
+        }
+    }
+    (result, Flags::default())
+}
@@ -0,0 +1,163 @@
+//! Source code which builds the current encoding from its fields.
+
+use crate::encoding::{divide_by_zero, BitType, Encoding, Flags};
+
+/// Languages which code can be generated in.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Language {
+    Rust,
+    C,
+}
+
+impl Language {
+    pub const ALL: [Self; 2] = [Self::Rust, Self::C];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::C => "C",
+        }
+    }
+
+    /// The unsigned integer type `width` bits wide, rounded up to a power of two.
+    fn unsigned(self, width: usize) -> &'static str {
+        let bits = width.next_power_of_two().max(8);
+        match (self, bits) {
+            (Self::Rust, 8) => "u8",
+            (Self::Rust, 16) => "u16",
+            (Self::Rust, 32) => "u32",
+            (Self::Rust, _) => "u64",
+            (Self::C, 8) => "uint8_t",
+            (Self::C, 16) => "uint16_t",
+            (Self::C, 32) => "uint32_t",
+            (Self::C, _) => "uint64_t",
+        }
+    }
+
+    /// The native floating point type with the layout of `encoding`, if there is one.
+    fn float(self, encoding: &Encoding) -> Option<&'static str> {
+        if encoding.options.bias.is_some() {
+            return None;
+        }
+        match (
+            self,
+            encoding.exponent_bits.len(),
+            encoding.significand_bits.len(),
+        ) {
+            (Self::Rust, 8, 23) => Some("f32"),
+            (Self::Rust, 11, 52) => Some("f64"),
+            (Self::C, 8, 23) => Some("float"),
+            (Self::C, 11, 52) => Some("double"),
+            _ => None,
+        }
+    }
+}
+
+/// Statements which assemble the encoding from its fields with shifts and ors, e.g.
+/// `(sign << 63) | (exponent << 52) | significand` for binary64, followed by a conversion to
+/// the native floating point type if there is one.
+pub fn construction(encoding: &Encoding, language: Language) -> String {
+    let ty = language.unsigned(encoding.len());
+    let significand_len = encoding.significand_bits.len();
+    let declare = |name: &str, value: u64| match language {
+        Language::Rust => format!("let {}: {} = 0x{:X};\n", name, ty, value),
+        Language::C => format!("{} {} = 0x{:X};\n", ty, name, value),
+    };
+
+    let mut code = String::new();
+    if language == Language::C {
+        code.push_str("#include <stdint.h>\n");
+        if language.float(encoding).is_some() {
+            code.push_str("#include <string.h>\n");
+        }
+        code.push('\n');
+    }
+    code.push_str(&declare("sign", encoding.field(BitType::Sign)));
+    code.push_str(&declare("exponent", encoding.field(BitType::Exponent)));
+    code.push_str(&declare(
+        "significand",
+        encoding.field(BitType::Significand),
+    ));
+    let expression = format!(
+        "(sign << {}) | (exponent << {}) | significand",
+        encoding.len() - 1,
+        significand_len
+    );
+    code.push_str(&match language {
+        Language::Rust => format!("let bits: {} = {};\n", ty, expression),
+        Language::C => format!("{} bits = {};\n", ty, expression),
+    });
+    match (language, language.float(encoding)) {
+        (Language::Rust, Some(float)) => {
+            code.push_str(&format!("let value = {}::from_bits(bits);\n", float))
+        }
+        (Language::C, Some(float)) => code.push_str(&format!(
+            "{} value;\nmemcpy(&value, &bits, sizeof value);\n",
+            float
+        )),
+        (_, None) if encoding.options.bias.is_some() => code.push_str(&format!(
+            "// No native type has an exponent bias of {}.\n",
+            encoding.bias()
+        )),
+        (_, None) => code.push_str(&format!(
+            "// No native type has {} exponent and {} significand bits.\n",
+            encoding.exponent_bits.len(),
+            significand_len
+        )),
+    }
+    code
+}
+
+/// Directed test vectors for the operations the app evaluates, one per line as
+/// `operation rounding a b result flags` with the operands and result in hex, for driving an
+/// FPU testbench with the current value as `x`.
+pub fn test_vectors(x: &Encoding) -> String {
+    let zero = x.zero(false);
+    // Formats without NaNs have no result for invalid operations, so those are left out, as
+    // are the ones on infinities in formats without them.
+    let invalid = x.default_nan().map(|nan| {
+        (
+            nan,
+            Flags {
+                invalid: true,
+                ..Flags::default()
+            },
+        )
+    });
+    let mut vectors = Vec::new();
+    for &(a, negative_zero) in &[(x, false), (x, true), (&zero, false)] {
+        if let Some(result) = divide_by_zero(a, negative_zero) {
+            vectors.push(("div", a.clone(), x.zero(negative_zero), result));
+        }
+    }
+    if let (Some(infinity), Some(invalid)) = (x.infinity(false), &invalid) {
+        vectors.push(("sub", infinity.clone(), infinity.clone(), invalid.clone()));
+        vectors.push(("mul", zero.clone(), infinity.clone(), invalid.clone()));
+        vectors.push(("div", infinity.clone(), infinity, invalid.clone()));
+    }
+    if let (Some(mut minus_one), Some(invalid)) = (x.power_of_two(0), invalid) {
+        minus_one.sign_bit = true;
+        vectors.push(("sqrt", minus_one, zero, invalid));
+    }
+
+    let mut text = format!(
+        "// {} exponent and {} significand bits\n\
+         // operation rounding a b result flags\n\
+         // b is zero for unary operations. Flags are invalid, divideByZero, overflow, \
+         underflow and inexact from bit 4 down to bit 0.\n",
+        x.exponent_bits.len(),
+        x.significand_bits.len()
+    );
+    for (operation, a, b, (result, flags)) in vectors {
+        // None of these operations round, so the rounding mode doesn't affect them.
+        text.push_str(&format!(
+            "{} rne {} {} {} {:02X}\n",
+            operation,
+            &a.hex()[2..],
+            &b.hex()[2..],
+            &result.hex()[2..],
+            flags.to_bits()
+        ));
+    }
+    text
+}
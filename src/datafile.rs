@@ -0,0 +1,140 @@
+//! Arrays of floating point numbers loaded from raw binary or `NumPy` `.npy` files.
+
+use crate::encoding::Encoding;
+use std::convert::TryFrom;
+
+/// The element types which can be read from a file.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ElementType {
+    F16,
+    F32,
+    F64,
+}
+
+impl ElementType {
+    pub const ALL: [Self; 3] = [Self::F16, Self::F32, Self::F64];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::F16 => "f16",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+
+    /// Exponent and significand widths.
+    pub fn layout(self) -> (usize, usize) {
+        match self {
+            Self::F16 => (5, 10),
+            Self::F32 => (8, 23),
+            Self::F64 => (11, 52),
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::F16 => 2,
+            Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+}
+
+/// The contents of a file viewed as an array of floating point numbers.
+pub struct DataFile {
+    pub name: String,
+    /// The array itself, without any header.
+    data: Vec<u8>,
+    pub element: ElementType,
+    pub little_endian: bool,
+    /// Whether the element type came from a `.npy` header rather than the user.
+    pub typed: bool,
+    /// Index of the element loaded into the editor.
+    pub index: usize,
+}
+
+impl DataFile {
+    /// Reads `bytes` as a `.npy` file if it has the magic string, and otherwise as raw
+    /// little endian `f32`s until the user says otherwise.
+    pub fn new(name: String, bytes: Vec<u8>) -> Result<Self, String> {
+        let mut file = Self {
+            name,
+            data: bytes,
+            element: ElementType::F32,
+            little_endian: true,
+            typed: false,
+            index: 0,
+        };
+        if file.data.starts_with(b"\x93NUMPY") {
+            file.read_npy_header()?;
+        }
+        Ok(file)
+    }
+
+    /// Strips the header from a `.npy` file, taking the element type from it.
+    fn read_npy_header(&mut self) -> Result<(), String> {
+        let major = *self.data.get(6).ok_or("Truncated .npy header")?;
+        let (header_start, header_len) = if major == 1 {
+            let len = self.data.get(8..10).ok_or("Truncated .npy header")?;
+            (10, usize::from(u16::from_le_bytes([len[0], len[1]])))
+        } else {
+            let len = self.data.get(8..12).ok_or("Truncated .npy header")?;
+            let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]);
+            (
+                12,
+                usize::try_from(len).map_err(|_| "Oversized .npy header")?,
+            )
+        };
+        let header = self
+            .data
+            .get(header_start..header_start + header_len)
+            .ok_or("Truncated .npy header")?;
+        let header = String::from_utf8_lossy(header);
+        // The header is a Python dict literal such as
+        // {'descr': '<f8', 'fortran_order': False, 'shape': (3,), }
+        let descr = header
+            .split("'descr':")
+            .nth(1)
+            .and_then(|rest| rest.split('\'').nth(1))
+            .ok_or("The .npy header has no element type")?;
+        let (order, ty) = descr.split_at(1.min(descr.len()));
+        self.element = match ty {
+            "f2" => ElementType::F16,
+            "f4" => ElementType::F32,
+            "f8" => ElementType::F64,
+            _ => return Err(format!("Unsupported .npy element type {}", descr)),
+        };
+        self.little_endian = order != ">";
+        self.typed = true;
+        self.data.drain(..header_start + header_len);
+        Ok(())
+    }
+
+    /// Number of whole elements in the file.
+    pub fn len(&self) -> usize {
+        self.data.len() / self.element.size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Element `i`, or `None` if it is out of range.
+    pub fn get(&self, i: usize) -> Option<Encoding> {
+        let size = self.element.size();
+        let bytes = self.data.get(i * size..(i + 1) * size)?;
+        let fold = |acc: u64, &b: &u8| (acc << 8) | u64::from(b);
+        let bits = if self.little_endian {
+            bytes.iter().rev().fold(0, fold)
+        } else {
+            bytes.iter().fold(0, fold)
+        };
+        let (exponent_len, significand_len) = self.element.layout();
+        Some(Encoding::from_bits(exponent_len, significand_len, bits))
+    }
+
+    /// Index of the first NaN after element `i`, if there is one.
+    pub fn next_nan(&self, i: usize) -> Option<usize> {
+        (i + 1..self.len()).find(|&j| self.get(j).map_or(false, |e| e.is_nan()))
+    }
+}
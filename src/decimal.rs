@@ -0,0 +1,256 @@
+//! The IEEE 754 decimal interchange formats, read from the same bits as the binary ones in
+//! either of the two encodings the standard allows for the significand.
+
+use std::fmt;
+
+/// How the significand of a decimal format is stored.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DecimalEncoding {
+    /// As a binary integer, as Intel's decimal library does.
+    Bid,
+    /// Three digits to every ten bits, as IBM's hardware does.
+    Dpd,
+}
+
+impl DecimalEncoding {
+    pub const ALL: [Self; 2] = [Self::Bid, Self::Dpd];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bid => "BID (binary integer)",
+            Self::Dpd => "DPD (densely packed decimal)",
+        }
+    }
+}
+
+/// The parameters of decimal32, decimal64 or decimal128.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DecimalFormat {
+    /// Bits of exponent continuation, `w` in the standard.
+    pub continuation_len: usize,
+    /// Decimal digits of precision.
+    pub precision: u32,
+    pub bias: i32,
+}
+
+impl DecimalFormat {
+    /// The decimal format `width` bits wide, if there is one.
+    pub fn with_width(width: usize) -> Option<Self> {
+        match width {
+            32 => Some(Self {
+                continuation_len: 6,
+                precision: 7,
+                bias: 101,
+            }),
+            64 => Some(Self {
+                continuation_len: 8,
+                precision: 16,
+                bias: 398,
+            }),
+            128 => Some(Self {
+                continuation_len: 12,
+                precision: 34,
+                bias: 6176,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Bits of the combination field, which holds the leading digit and the top of the exponent
+    /// along with the exponent continuation.
+    fn combination_len(self) -> usize {
+        self.continuation_len + 5
+    }
+
+    /// The fields of `bits` in `encoding`. DPD has the layout the standard draws: the sign,
+    /// the five bits which combine the leading digit with the top two exponent bits, the rest
+    /// of the exponent and the trailing digits. BID only keeps that layout for infinities and
+    /// NaNs. Otherwise its exponent comes straight after the sign, or after a `11` prefix
+    /// standing for an implicit `100` at the top of the coefficient, and the coefficient takes
+    /// the rest.
+    pub fn fields(self, bits: &[bool], encoding: DecimalEncoding) -> Vec<(bool, &'static str)> {
+        let g = &bits[1..=self.combination_len()];
+        let special = g[..4].iter().all(|&b| b);
+        // Where the exponent starts and ends in BID, counting from the sign bit.
+        let (exponent_start, exponent_end) = if g[0] && g[1] {
+            (3, self.continuation_len + 5)
+        } else {
+            (1, self.continuation_len + 3)
+        };
+        bits.iter()
+            .enumerate()
+            .map(|(i, &bit)| {
+                let field = if i == 0 {
+                    "Sign"
+                } else if encoding == DecimalEncoding::Dpd || special {
+                    if i <= 5 {
+                        "Combination"
+                    } else if i <= self.combination_len() {
+                        "Exponent continuation"
+                    } else {
+                        "Trailing significand"
+                    }
+                } else if i < exponent_start {
+                    "Combination"
+                } else if i < exponent_end {
+                    "Exponent"
+                } else {
+                    "Coefficient"
+                };
+                (bit, field)
+            })
+            .collect()
+    }
+
+    /// The three digits held by each ten bit declet of the trailing significand of `bits` in
+    /// DPD, most significant first, as numbers from 0 to 999.
+    pub fn declets(self, bits: &[bool]) -> Vec<u128> {
+        bits[1 + self.combination_len()..]
+            .chunks(10)
+            .map(decode_declet)
+            .collect()
+    }
+
+    /// Decodes `bits`, which must be as wide as the format.
+    pub fn decode(self, bits: &[bool], encoding: DecimalEncoding) -> Decoded {
+        let sign = bits[0];
+        let g = &bits[1..=self.combination_len()];
+        let trailing = &bits[1 + self.combination_len()..];
+        let number = |bits: &[bool]| bits.iter().fold(0, |acc, &b| (acc << 1) | u128::from(b));
+        if g[..4].iter().all(|&b| b) {
+            return if g[4] {
+                Decoded::Nan {
+                    sign,
+                    signaling: g[5],
+                }
+            } else {
+                Decoded::Infinity { sign }
+            };
+        }
+        let w = self.continuation_len;
+        let (exponent, coefficient) = match encoding {
+            DecimalEncoding::Bid if g[0] && g[1] => {
+                let exponent = number(&g[2..w + 4]);
+                let coefficient = (number(&g[w + 4..]) | 0b1000) << trailing.len();
+                (exponent, coefficient | number(trailing))
+            }
+            DecimalEncoding::Bid => {
+                let exponent = number(&g[..w + 2]);
+                let coefficient = number(&g[w + 2..]) << trailing.len();
+                (exponent, coefficient | number(trailing))
+            }
+            DecimalEncoding::Dpd => {
+                let (top, leading) = if g[0] && g[1] {
+                    (number(&g[2..4]), 8 + number(&g[4..5]))
+                } else {
+                    (number(&g[..2]), number(&g[2..5]))
+                };
+                let exponent = (top << w) | number(&g[5..]);
+                let coefficient = self
+                    .declets(bits)
+                    .into_iter()
+                    .fold(leading, |acc, digits| acc * 1000 + digits);
+                (exponent, coefficient)
+            }
+        };
+        // BID can encode coefficients beyond the precision, which are taken to be zero.
+        let coefficient = if coefficient < 10_u128.pow(self.precision) {
+            coefficient
+        } else {
+            0
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let exponent = exponent as i32 - self.bias;
+        Decoded::Finite {
+            sign,
+            coefficient,
+            exponent,
+        }
+    }
+}
+
+/// The three digits in a declet of densely packed decimal, as a number from 0 to 999.
+fn decode_declet(declet: &[bool]) -> u128 {
+    let bit = |i: usize| u128::from(declet[9 - i]);
+    // Digits 0 to 7 take three bits and 8 or 9 take one, with the indicator bits saying which
+    // is which.
+    let small = |a: usize, b: usize, c: usize| (bit(a) << 2) | (bit(b) << 1) | bit(c);
+    let large = |a: usize| 8 + bit(a);
+    let (hundreds, tens, units) = if bit(3) == 0 {
+        (small(9, 8, 7), small(6, 5, 4), small(2, 1, 0))
+    } else {
+        match (bit(2), bit(1), bit(6), bit(5)) {
+            (0, 0, _, _) => (small(9, 8, 7), small(6, 5, 4), large(0)),
+            (0, 1, _, _) => (small(9, 8, 7), large(4), small(6, 5, 0)),
+            (1, 0, _, _) => (large(7), small(6, 5, 4), small(9, 8, 0)),
+            (_, _, 0, 0) => (large(7), large(4), small(9, 8, 0)),
+            (_, _, 0, 1) => (large(7), small(9, 8, 4), large(0)),
+            (_, _, 1, 0) => (small(9, 8, 7), large(4), large(0)),
+            _ => (large(7), large(4), large(0)),
+        }
+    };
+    hundreds * 100 + tens * 10 + units
+}
+
+/// A decoded decimal value.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Decoded {
+    /// `coefficient × 10^exponent`, negated if `sign` is set. Values with the same product but
+    /// different exponents are distinct members of a cohort.
+    Finite {
+        sign: bool,
+        coefficient: u128,
+        exponent: i32,
+    },
+    Infinity {
+        sign: bool,
+    },
+    Nan {
+        sign: bool,
+        signaling: bool,
+    },
+}
+
+impl fmt::Display for Decoded {
+    /// The standard's to-scientific-string: plain notation unless the exponent is positive or
+    /// the value is very small, which keeps every member of a cohort distinct.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minus = |sign: bool| if sign { "-" } else { "" };
+        match *self {
+            Self::Nan { sign, signaling } => {
+                write!(f, "{}{}NaN", minus(sign), if signaling { "s" } else { "" })
+            }
+            Self::Infinity { sign } => write!(f, "{}Infinity", minus(sign)),
+            Self::Finite {
+                sign,
+                coefficient,
+                exponent,
+            } => {
+                let digits = coefficient.to_string();
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                let adjusted = exponent + digits.len() as i32 - 1;
+                if exponent <= 0 && adjusted >= -6 {
+                    #[allow(clippy::cast_sign_loss)]
+                    let fraction_len = -exponent as usize;
+                    if fraction_len == 0 {
+                        return write!(f, "{}{}", minus(sign), digits);
+                    }
+                    let padded = format!("{:0>1$}", digits, fraction_len + 1);
+                    let (integer, fraction) = padded.split_at(padded.len() - fraction_len);
+                    write!(f, "{}{}.{}", minus(sign), integer, fraction)
+                } else {
+                    let (leading, rest) = digits.split_at(1);
+                    write!(
+                        f,
+                        "{}{}{}{}E{:+}",
+                        minus(sign),
+                        leading,
+                        if rest.is_empty() { "" } else { "." },
+                        rest,
+                        adjusted
+                    )
+                }
+            }
+        }
+    }
+}
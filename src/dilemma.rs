@@ -0,0 +1,207 @@
+//! Inputs whose exp or ln lies so close to a rounding boundary that a long run of bits after
+//! the rounding bit are all alike, which is the table-maker's dilemma: a library can't know how
+//! many bits it must work out before the rounding is settled. The results are worked out with
+//! integers, well past the end of each run.
+
+use crate::{
+    bignum::BigUint,
+    encoding::{Encoding, FormatPreset},
+};
+use std::convert::TryFrom;
+
+/// Fraction bits carried through the working, so that every bit of an `Expansion` is right.
+const WORKING_BITS: usize = 320;
+
+/// Bits of a result kept in its `Expansion`, comfortably within the working precision.
+const EXPANSION_BITS: usize = 160;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Function {
+    Exp,
+    Ln,
+}
+
+impl Function {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Exp => "exp",
+            Self::Ln => "ln",
+        }
+    }
+
+    /// The function of `x`, or `None` if `x` isn't finite, is outside the function's domain or
+    /// is at least 2^16 for exp, whose result would then have too many bits to work out.
+    pub fn evaluate(self, x: &Encoding) -> Option<Expansion> {
+        if x.is_nan() || x.is_infinite() {
+            return None;
+        }
+        let (significand, scale) = x.scaled_significand();
+        match self {
+            Self::Exp => {
+                if significand.bit_len() as i32 + scale > 16 {
+                    return None;
+                }
+                // |x| as a fixed point number, with enough fraction bits to hold it exactly.
+                let fraction_bits = WORKING_BITS.max(scale.unsigned_abs() as usize);
+                let magnitude = significand.shl((fraction_bits as i32 + scale) as usize);
+                let (power, fraction_bits) = exp(&magnitude, fraction_bits);
+                Some(if x.sign_bit {
+                    // e^−x = 1 / e^x, divided out to as many bits again as e^x has.
+                    let extra = power.bit_len() + WORKING_BITS;
+                    let one = BigUint::from_u64(1).shl(fraction_bits + extra);
+                    Expansion::new(false, &one.div_rem(&power).0, extra)
+                } else {
+                    Expansion::new(false, &power, fraction_bits)
+                })
+            }
+            Self::Ln => {
+                if x.sign_bit || x.is_zero() {
+                    return None;
+                }
+                // x = m × 2^e with 1 ≤ m < 2, so ln x = ln m + e ln 2.
+                let leading = significand.bit_len() - 1;
+                let m = significand.shl(WORKING_BITS).shr(leading);
+                let e = i64::from(scale) + leading as i64;
+                let ln_m = ln_small(&m);
+                let ln_2 = ln_small(&BigUint::from_u64(2).shl(WORKING_BITS));
+                let mut e_ln_2 = ln_2;
+                e_ln_2.mul_small(u32::try_from(e.abs()).expect("exponents fit in 32 bits"));
+                Some(if e >= 0 {
+                    Expansion::new(false, &ln_m.add(&e_ln_2), WORKING_BITS)
+                } else if ln_m >= e_ln_2 {
+                    Expansion::new(false, &ln_m.sub(&e_ln_2), WORKING_BITS)
+                } else {
+                    Expansion::new(true, &e_ln_2.sub(&ln_m), WORKING_BITS)
+                })
+            }
+        }
+    }
+}
+
+/// e^x for `x` ≥ 0 with `fraction_bits` fraction bits, and the fraction bits of the result.
+/// x is halved until it is small enough for a few terms of the Taylor series, which is then
+/// squared as many times.
+fn exp(x: &BigUint, fraction_bits: usize) -> (BigUint, usize) {
+    let halvings = x.bit_len().saturating_sub(fraction_bits - 8);
+    // Halving by reading x with more fraction bits loses none of them.
+    let fraction_bits = fraction_bits + halvings;
+    let one = BigUint::from_u64(1).shl(fraction_bits);
+    let (mut sum, mut term) = (one.clone(), one);
+    for k in 1.. {
+        term = term.mul(x).shr(fraction_bits);
+        term.div_small(k);
+        if term.is_zero() {
+            break;
+        }
+        sum = sum.add(&term);
+    }
+    for _ in 0..halvings {
+        sum = sum.mul(&sum).shr(fraction_bits);
+    }
+    (sum, fraction_bits)
+}
+
+/// ln m for 1 ≤ m ≤ 2 with `WORKING_BITS` fraction bits, as 2 atanh((m − 1) / (m + 1)), whose
+/// series gains more than three bits a term.
+fn ln_small(m: &BigUint) -> BigUint {
+    let one = BigUint::from_u64(1).shl(WORKING_BITS);
+    let t = m.sub(&one).shl(WORKING_BITS).div_rem(&m.add(&one)).0;
+    let t_squared = t.mul(&t).shr(WORKING_BITS);
+    let (mut sum, mut power) = (t.clone(), t);
+    for k in 1.. {
+        power = power.mul(&t_squared).shr(WORKING_BITS);
+        if power.is_zero() {
+            break;
+        }
+        let mut term = power.clone();
+        term.div_small(2 * k + 1);
+        sum = sum.add(&term);
+    }
+    sum.shl(1)
+}
+
+/// The leading bits of a result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expansion {
+    pub negative: bool,
+    /// The bits from the leading one on.
+    pub bits: Vec<bool>,
+    /// The power of two the leading one is worth.
+    pub exponent: i32,
+}
+
+impl Expansion {
+    /// The leading bits of `magnitude` with `fraction_bits` fraction bits.
+    fn new(negative: bool, magnitude: &BigUint, fraction_bits: usize) -> Self {
+        let len = magnitude.bit_len();
+        Self {
+            negative,
+            bits: (0..len)
+                .rev()
+                .take(EXPANSION_BITS)
+                .map(|i| magnitude.bit(i))
+                .collect(),
+            exponent: (len as i32 - 1) - fraction_bits as i32,
+        }
+    }
+
+    /// How many bits after the rounding bit of a `precision`-bit significand are the same as
+    /// the first of them.
+    pub fn run(&self, precision: usize) -> usize {
+        let mut after = self.bits.iter().skip(precision + 1);
+        after.next().map_or(0, |&first| {
+            1 + after.take_while(|&&bit| bit == first).count()
+        })
+    }
+}
+
+/// An input whose result is hard to round.
+#[derive(Debug, Copy, Clone)]
+pub struct HardCase {
+    pub function: Function,
+    pub format: FormatPreset,
+    pub bits: u64,
+}
+
+/// The binary64 cases are from Lefèvre and Muller's search for the worst cases of the
+/// elementary functions. The binary32 ones have runs as long as any other `f32` input's, leaving
+/// aside inputs within 2^−10 of 0 for exp and of 1 for ln, whose results are little more than
+/// the first terms of their Taylor series.
+pub const HARD_CASES: [HardCase; 4] = [
+    HardCase {
+        function: Function::Exp,
+        format: FormatPreset::Binary64,
+        // 0x1.9e9cbbfd6080bp-31
+        bits: 0x3E09_E9CB_BFD6_080B,
+    },
+    HardCase {
+        function: Function::Ln,
+        format: FormatPreset::Binary64,
+        // 0x1.62a88613629b6p+678
+        bits: 0x6A56_2A88_6136_29B6,
+    },
+    HardCase {
+        function: Function::Exp,
+        format: FormatPreset::Binary32,
+        // -0x1.d2259ap+3
+        bits: 0xC169_12CD,
+    },
+    HardCase {
+        function: Function::Ln,
+        format: FormatPreset::Binary32,
+        // 0x1.b121a6p+76
+        bits: 0x65D8_90D3,
+    },
+];
+
+impl HardCase {
+    pub fn input(&self) -> Encoding {
+        self.format.zero().with_bits(self.bits)
+    }
+
+    pub fn result(&self) -> Expansion {
+        self.function
+            .evaluate(&self.input())
+            .expect("hard cases are in their functions' domains")
+    }
+}
@@ -0,0 +1,95 @@
+//! Functions exported to page scripts, so that a page embedding the app, such as course
+//! material, can drive it and follow its value.
+
+use crate::{
+    encoding::{Encoding, RoundingMode, MAX_EXPONENT_BITS, MAX_SIGNIFICAND_BITS},
+    text::PasteFormat,
+    Model, Msg,
+};
+use seed::{prelude::*, App};
+use std::cell::RefCell;
+
+type RunningApp = App<Msg, Model, Vec<Node<Msg>>>;
+
+/// The running app and what page scripts know of it.
+#[derive(Default)]
+struct Embedding {
+    app: Option<RunningApp>,
+    /// The encoding as of the last update.
+    encoding: Option<Encoding>,
+    on_change: Option<js_sys::Function>,
+}
+
+thread_local! {
+    static EMBEDDING: RefCell<Embedding> = RefCell::new(Embedding::default());
+}
+
+/// Keeps the started app so that the exported functions can send it messages.
+pub fn register(app: RunningApp) {
+    EMBEDDING.with(|embedding| embedding.borrow_mut().app = Some(app));
+}
+
+/// Records the encoding after an update, returning the callback to tell if it has changed.
+pub fn publish(encoding: &Encoding) -> Option<js_sys::Function> {
+    EMBEDDING.with(|embedding| {
+        let mut embedding = embedding.borrow_mut();
+        if embedding.encoding.as_ref() == Some(encoding) {
+            return None;
+        }
+        embedding.encoding = Some(encoding.clone());
+        embedding.on_change.clone()
+    })
+}
+
+fn current() -> Result<Encoding, JsValue> {
+    EMBEDDING
+        .with(|embedding| embedding.borrow().encoding.clone())
+        .ok_or_else(|| JsValue::from_str("The app has not started"))
+}
+
+/// Handles `msg` as if it came from the page. The app is cloned out first, as handling the
+/// message publishes the new encoding.
+fn send(msg: Msg) -> Result<(), JsValue> {
+    let app = EMBEDDING
+        .with(|embedding| embedding.borrow().app.clone())
+        .ok_or_else(|| JsValue::from_str("The app has not started"))?;
+    app.update(msg);
+    Ok(())
+}
+
+/// Sets every bit from a hex pattern no wider than the format, e.g. `"3FF0000000000000"`,
+/// throwing if it isn't one.
+#[wasm_bindgen]
+pub fn set_bits(hex: &str) -> Result<(), JsValue> {
+    PasteFormat::Hex
+        .parse(hex, &current()?, RoundingMode::TiesToEven)
+        .map_err(|e| JsValue::from_str(&e))?;
+    send(Msg::SetFromHex(hex.to_owned()))
+}
+
+/// The current value, or the nearest `f64` to it.
+#[wasm_bindgen]
+pub fn get_value() -> Result<f64, JsValue> {
+    Ok(current()?.value())
+}
+
+/// Changes the field widths as the sliders do, keeping the bits which still fit.
+#[wasm_bindgen]
+pub fn set_format(exponent_bits: usize, significand_bits: usize) -> Result<(), JsValue> {
+    if !(1..=MAX_EXPONENT_BITS).contains(&exponent_bits)
+        || !(1..=MAX_SIGNIFICAND_BITS).contains(&significand_bits)
+    {
+        return Err(JsValue::from_str(&format!(
+            "Formats have 1 to {} exponent bits and 1 to {} significand bits",
+            MAX_EXPONENT_BITS, MAX_SIGNIFICAND_BITS
+        )));
+    }
+    send(Msg::SetLayout(exponent_bits, significand_bits))
+}
+
+/// Registers `callback` to be called with the value and hex pattern, e.g. `(1, "0x3FF0…")`,
+/// whenever the encoding changes, replacing any earlier callback.
+#[wasm_bindgen]
+pub fn on_change(callback: js_sys::Function) {
+    EMBEDDING.with(|embedding| embedding.borrow_mut().on_change = Some(callback));
+}
@@ -0,0 +1,2273 @@
+//! Translations of the interface. Each language is a function from `Text` to a string, whose
+//! exhaustive match makes the compiler point out every string a translation is missing. A new
+//! language starts as a copy of `english`, translated in place.
+
+use crate::{
+    arithmetic::UnaryOperator,
+    decimal::DecimalEncoding,
+    encoding::{BitType, Class, NonCanonical, Overflow, SpecialValue},
+    interpretation::Unrepresentable,
+    text::PasteFormat,
+    Theme,
+};
+
+/// A language the interface can be shown in.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Locale {
+    English,
+    Spanish,
+    French,
+}
+
+impl Locale {
+    pub const ALL: [Self; 3] = [Self::English, Self::Spanish, Self::French];
+
+    /// The name of the language in itself, as the selector shows it.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Español",
+            Self::French => "Français",
+        }
+    }
+
+    /// The language's BCP 47 tag, for the page's `lang` attribute.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::French => "fr",
+        }
+    }
+
+    /// The locale for a tag such as the browser's `es-MX`, going by the language alone.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split('-').next()?;
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(language))
+    }
+
+    /// `text` in this language.
+    pub fn text(self, text: impl Into<Text>) -> &'static str {
+        let text = text.into();
+        match self {
+            Self::English => english(text),
+            Self::Spanish => spanish(text),
+            Self::French => french(text),
+        }
+    }
+}
+
+/// A string shown in the interface.
+#[derive(Debug, Copy, Clone)]
+pub enum Text {
+    Presets,
+    ExponentBits,
+    SignificandBits,
+    CopyBits,
+    Infinities,
+    Nans,
+    ImplicitLeadingBit,
+    Bias,
+    Field(BitType),
+    ResetColours,
+    ResetToDefaults,
+    Theme(Theme),
+    Language,
+    Undo,
+    Redo,
+    Class(Class),
+    Heading(Heading),
+    FiniteValue,
+    NotFinite,
+    Overflow(Overflow),
+    SpecialValue(SpecialValue),
+    UnaryOperator(UnaryOperator),
+    PasteFormat(PasteFormat),
+    DecimalEncoding(DecimalEncoding),
+    Unrepresentable(Unrepresentable),
+    NonCanonical(NonCanonical),
+    Button(Button),
+    Label(Label),
+    Sentence(Sentence),
+}
+
+/// The heading of a panel. Those with a `{}` are filled in with `fill`.
+#[derive(Debug, Copy, Clone)]
+pub enum Heading {
+    SignedZero,
+    DivisionByZero,
+    InvalidOperations,
+    Characteristics,
+    Thresholds,
+    Constants,
+    UlpOfOne,
+    FormatComparison,
+    Density,
+    RangePlot,
+    Tradeoff,
+    Allocator,
+    Reinterpretations,
+    Interpolation,
+    InterpolationComparison,
+    SortableBits,
+    Codegen,
+    TestVectors,
+    TestFloat,
+    Summation,
+    Dilemma,
+    DataFile,
+    BrowsingDataFile,
+    NeighboursLogScale,
+    NeighboursToScale,
+    Comparison,
+    Flags,
+    FieldBit(BitType),
+    ExactDecimal,
+    ExactFraction,
+    Practice,
+    Walkthrough,
+}
+
+/// The label of a button.
+#[derive(Debug, Copy, Clone)]
+pub enum Button {
+    TimeTravel,
+    StopTimeTravel,
+    Record,
+    StopRecording,
+    Rewind,
+    Step,
+    Play,
+    Pause,
+    Stop,
+    CopyScript,
+    Close,
+    ExportState,
+    HideState,
+    Copy,
+    Download,
+    DownloadSvg,
+    FlipSignBit,
+    Load,
+    LoadResult,
+    LoadOne,
+    LoadOnePlusUlp,
+    Use,
+    UseCurrent,
+    CopyCode,
+    CopyTestVectors,
+    Previous,
+    Next,
+    NextNan,
+    Cancel,
+    Dismiss,
+    Randomize,
+    Fill(BitType),
+    Clear(BitType),
+    ClearAll,
+    CopyFormat(PasteFormat),
+    PreviousValue,
+    NextValue,
+    SwapOperands,
+    UseAsA,
+    ClearFlags,
+    ClearField(BitType),
+    Restore,
+    Discard,
+    WalkThrough,
+    Practise,
+    Check,
+    LoadAnswer,
+    RevealAnswer,
+    NextQuestion,
+    StopPractising,
+    PreviousStep,
+    NextStep,
+}
+
+/// A short piece of text: the name of a row, column, field or option, or a tooltip.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Label {
+    Sign,
+    Exponent,
+    Fraction,
+    Integer,
+    Regime,
+    Combination,
+    ExponentContinuation,
+    TrailingSignificand,
+    Coefficient,
+    SvgTitle,
+    ResetTitle,
+    RecordTitle,
+    LoadRecording,
+    StateHint,
+    NoFlags,
+    NoResultNoNans,
+    PrecisionP,
+    MachineEpsilon,
+    LargestFinite,
+    SmallestNormal,
+    SmallestSubnormal,
+    FiniteEncodings,
+    None,
+    OverflowsToInfinity,
+    OverflowsToNan,
+    Saturates,
+    BecomesSubnormal,
+    NoNormalValues,
+    UnderflowsToZero,
+    NotRepresentable,
+    Format,
+    PrecisionDigits,
+    RangeDecades,
+    LargestExponentAxis,
+    DecimalDigitsAxis,
+    TotalBits,
+    SmallestMagnitude,
+    LargestMagnitude,
+    BitSplit,
+    DecimalDigits,
+    CoversRange,
+    CoversYes,
+    CoversWithSubnormals,
+    CoversNoOverflows,
+    CoversNoUnderflows,
+    CompareFixedPoint,
+    FractionBits,
+    SignedTwosComplement,
+    ComparePosit,
+    PositEs,
+    CompareTwosComplement,
+    NotSet,
+    LinearInValue,
+    LinearInBits,
+    NegativeInvert,
+    PositiveSetSign,
+    Bits,
+    Sortable,
+    Value,
+    SortableKey,
+    Pass,
+    NotChecked,
+    NaiveSum,
+    KahanSum,
+    Compensation,
+    ExactSum,
+    CorrectlyRounded,
+    Error,
+    NotFinite,
+    BigEndian,
+    GroupInFours,
+    ImplicitBitTitle,
+    StoredExponent,
+    UnbiasedExponent,
+    LeadingBit,
+    SignificandInteger,
+    SignificandFraction,
+    NoUnbiasedExponent,
+    LeadingImplicitOne,
+    LeadingImplicitZero,
+    LeadingStoredOne,
+    LeadingStoredZero,
+    PlayStepsTitle,
+    StepTitle,
+    NoValueBeyond,
+    EqualEncodingsDiffer,
+    Unordered,
+    NoResultFormatNoNans,
+    SetBFromDecimal,
+    Raised,
+    Clear,
+    TypingHexHint,
+    ClickHexHint,
+    ExplainBits,
+    ReservedBoth,
+    ReservedInfinity,
+    ReservedNan,
+    ReservedNone,
+    SignalingNan,
+    QuietNan,
+    ExactSingleOne,
+    ExactFractionZeros,
+    RoundedNotPowerOfTwo,
+    ExactPowerOfTen,
+    RoundedNotPowerOfTen,
+    SetFromDecimal,
+    Hex,
+    Binary,
+    HexFloat,
+    WhatValue,
+    FormatChanged,
+    Rounded,
+    Exact,
+    SplitParts,
+    IntegerPart,
+    FractionPart,
+    Normalize,
+    BiasTheExponent,
+    Round,
+}
+
+/// A sentence or phrase, whose `{}`s are filled in with `fill` in order. Translations must keep
+/// the order of the values, rewording the sentence around them where need be.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Sentence {
+    RecordingOneStep,
+    RecordingSteps,
+    StepOf,
+    ZerosDiffer,
+    ZerosCompareEqual,
+    Reciprocals,
+    DefaultNan,
+    NoNansStillRaise,
+    InfinityMinusInfinity,
+    ZeroTimesInfinity,
+    InfinityOverInfinity,
+    SqrtOfNegative,
+    PrecisionBits,
+    FiniteOf,
+    NextAfterOne,
+    EachBinade,
+    NotSmallest,
+    Custom,
+    Current,
+    FloatingPoint,
+    FixedPoint,
+    CompareDecimal,
+    DecimalOf,
+    TwosComplementInteger,
+    DensityGaps,
+    TradeoffPoint,
+    EnterWidth,
+    NotRepresentable,
+    PositNegation,
+    InterpolateFrom,
+    InterpolateTo,
+    BitsAreLogarithms,
+    SortableKeys,
+    OrdinalOf,
+    PasteTestFloat,
+    Mismatch,
+    SummationIntro,
+    RoundedFrom,
+    DilemmaIntro,
+    BitsAlike,
+    NearMidpoint,
+    NearValue,
+    OpenDataFile,
+    NoWholeElements,
+    ElementOf,
+    PasteAs,
+    Copied,
+    ImplicitBitAria,
+    FieldOfOneBit,
+    FieldOfBits,
+    BitAria,
+    SubnormalExponent,
+    NoFormulaNan,
+    NoFormulaInfinity,
+    RawBits,
+    OneUlp,
+    BinadeValues,
+    FromZeroTo,
+    FromTo,
+    StepsApart,
+    RoundedWith,
+    ResultExact,
+    PaddedWith,
+    SignBitRole,
+    ExponentBitRole,
+    ExplicitLeadingBitRole,
+    SignificandBitRole,
+    FlipWouldChange,
+    RestorePrompt,
+    NanPayload,
+    ClassTitle,
+    TooManyDigits,
+    NumeratorTooLong,
+    ForExample,
+    AskBuildBits,
+    QuizProgress,
+    WrongOnce,
+    Wrong,
+    RightFirstTime,
+    Right,
+    TheAnswer,
+    AnswerNegative,
+    AnswerPositive,
+    AnswerSubnormal,
+    AnswerScaled,
+    AnswerSignificand,
+    AnswerTogether,
+    StoredExactly,
+    OffBy,
+    ErrorTooLong,
+    TypedNegative,
+    TypedPositive,
+    IntegerPartIs,
+    FractionPartIs,
+    PartsSeparately,
+    Halving,
+    MoreHalvings,
+    IntegerZero,
+    Remainders,
+    Doubling,
+    MoreDoublings,
+    FractionZero,
+    IntegerHoldsAll,
+    FractionExact,
+    FractionForever,
+    Together,
+    LeadingTooLarge,
+    MoveNowhere,
+    MoveOneLeft,
+    MoveOneRight,
+    MoveLeft,
+    MoveRight,
+    LeadingSubnormal,
+    ZeroHasNoLeading,
+    NoOneBit,
+    LeadingStored,
+    LeadingImplied,
+    BiasOverflows,
+    OverflowsTo,
+    ExponentStores,
+    InBits,
+    ExponentZeros,
+    Keeping,
+    RoundsUp,
+    RoundsDown,
+    CarryOverflows,
+    CarryIntoExponent,
+    NothingLost,
+    Result,
+}
+
+impl From<Button> for Text {
+    fn from(button: Button) -> Self {
+        Self::Button(button)
+    }
+}
+
+impl From<Label> for Text {
+    fn from(label: Label) -> Self {
+        Self::Label(label)
+    }
+}
+
+impl From<Sentence> for Text {
+    fn from(sentence: Sentence) -> Self {
+        Self::Sentence(sentence)
+    }
+}
+
+/// A sentence with its values, for text worked out before the language it is shown in is
+/// known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    pub sentence: Sentence,
+    pub values: Vec<String>,
+}
+
+impl Line {
+    pub fn new(sentence: Sentence, values: Vec<String>) -> Self {
+        Self { sentence, values }
+    }
+
+    /// The line in `locale`.
+    pub fn text(&self, locale: Locale) -> String {
+        let values: Vec<&str> = self.values.iter().map(String::as_str).collect();
+        fill(locale.text(self.sentence), &values)
+    }
+}
+
+/// `template` with `values` in place of its `{}`s, in order. Placeholders beyond the values
+/// are left empty, and a `{}` within a value is kept as it is.
+pub fn fill(template: &str, values: &[&str]) -> String {
+    let mut parts = template.split("{}");
+    let mut filled = parts.next().unwrap_or_default().to_owned();
+    for (i, part) in parts.enumerate() {
+        filled.push_str(values.get(i).copied().unwrap_or_default());
+        filled.push_str(part);
+    }
+    filled
+}
+
+fn english(text: Text) -> &'static str {
+    match text {
+        Text::Presets => "Presets",
+        Text::ExponentBits => "Exponent Bits",
+        Text::SignificandBits => "Significand Bits",
+        Text::CopyBits => "Copy bits",
+        Text::Infinities => "Infinities",
+        Text::Nans => "NaNs",
+        Text::ImplicitLeadingBit => "Implicit leading bit",
+        Text::Bias => "Bias",
+        Text::Field(t) => t.name(),
+        Text::ResetColours => "Reset colours",
+        Text::ResetToDefaults => "Reset to defaults",
+        Text::Theme(theme) => theme.name(),
+        Text::Language => "Language",
+        Text::Undo => "Undo",
+        Text::Redo => "Redo",
+        Text::Class(class) => class.name(),
+        Text::Heading(heading) => match heading {
+            Heading::SignedZero => "Signed zero: this is {}",
+            Heading::DivisionByZero => "Dividing x = {} by zero",
+            Heading::InvalidOperations => "Invalid operations",
+            Heading::Characteristics => "Format characteristics",
+            Heading::Thresholds => "Overflow and underflow thresholds",
+            Heading::Constants => "Constants",
+            Heading::UlpOfOne => "Precision at one",
+            Heading::FormatComparison => "Compared to standard formats",
+            Heading::Density => "Every non-negative finite value (linear scale)",
+            Heading::RangePlot => "Positive ranges (log scale)",
+            Heading::Tradeoff => "Range against precision for {} bits",
+            Heading::Allocator => "Bit budget allocator",
+            Heading::Reinterpretations => "Reinterpreting {}",
+            Heading::Interpolation => "Interpolating bit patterns",
+            Heading::InterpolationComparison => "Value space against bit space",
+            Heading::SortableBits => "Sortable bits",
+            Heading::Codegen => "Construct it in code",
+            Heading::TestVectors => "Test vectors",
+            Heading::TestFloat => "Import TestFloat vectors",
+            Heading::Summation => "Naive and Kahan summation",
+            Heading::Dilemma => "The table-maker's dilemma",
+            Heading::DataFile => "Browse a data file",
+            Heading::BrowsingDataFile => "Browsing {}",
+            Heading::NeighboursLogScale => "Nearby values (log scale of distance)",
+            Heading::NeighboursToScale => "Nearby values to scale",
+            Heading::Comparison => "Comparing and combining two values",
+            Heading::Flags => "Exception flags",
+            Heading::FieldBit(BitType::Sign) => "Sign bit",
+            Heading::FieldBit(BitType::Exponent) => "Exponent bit",
+            Heading::FieldBit(BitType::Significand) => "Significand bit",
+            Heading::ExactDecimal => "Exact decimal value",
+            Heading::ExactFraction => "Exact fraction",
+            Heading::Practice => "Practice",
+            Heading::Walkthrough => "Converting to binary",
+        },
+        Text::FiniteValue => "Finite value #",
+        Text::NotFinite => "Not a finite value. Jump to finite value #",
+        Text::Overflow(overflow) => overflow.name(),
+        Text::SpecialValue(special) => special.name(),
+        Text::UnaryOperator(operator) => operator.name(),
+        Text::PasteFormat(format) => format.name(),
+        Text::DecimalEncoding(encoding) => encoding.name(),
+        Text::Unrepresentable(reason) => reason.description(),
+        Text::NonCanonical(kind) => kind.description(),
+        Text::Button(button) => match button {
+            Button::TimeTravel => "Time travel",
+            Button::StopTimeTravel => "Stop time travel",
+            Button::Record => "Record",
+            Button::StopRecording => "Stop recording",
+            Button::Rewind => "Rewind",
+            Button::Step => "Step",
+            Button::Play => "Play",
+            Button::Pause => "Pause",
+            Button::Stop => "Stop",
+            Button::CopyScript => "Copy script",
+            Button::Close => "Close",
+            Button::ExportState => "Export or import state",
+            Button::HideState => "Hide state",
+            Button::Copy => "Copy",
+            Button::Download => "Download",
+            Button::DownloadSvg => "Download SVG",
+            Button::FlipSignBit => "Flip the sign bit",
+            Button::Load => "Load",
+            Button::LoadResult => "Load result",
+            Button::LoadOne => "Load 1.0",
+            Button::LoadOnePlusUlp => "Load 1.0 + ulp",
+            Button::Use => "Use",
+            Button::UseCurrent => "Use current",
+            Button::CopyCode => "Copy code",
+            Button::CopyTestVectors => "Copy test vectors",
+            Button::Previous => "Previous",
+            Button::Next => "Next",
+            Button::NextNan => "Next NaN",
+            Button::Cancel => "Cancel",
+            Button::Dismiss => "Dismiss",
+            Button::Randomize => "Randomize",
+            Button::Fill(BitType::Sign) => "Fill sign",
+            Button::Fill(BitType::Exponent) => "Fill exponent",
+            Button::Fill(BitType::Significand) => "Fill significand",
+            Button::Clear(BitType::Sign) => "Clear sign",
+            Button::Clear(BitType::Exponent) => "Clear exponent",
+            Button::Clear(BitType::Significand) => "Clear significand",
+            Button::ClearAll => "Clear all",
+            Button::CopyFormat(PasteFormat::Decimal) => "Copy decimal",
+            Button::CopyFormat(PasteFormat::Hex) => "Copy hex pattern",
+            Button::CopyFormat(PasteFormat::Binary) => "Copy binary string",
+            Button::CopyFormat(PasteFormat::HexFloat) => "Copy hex float",
+            Button::PreviousValue => "\u{2190} Previous value",
+            Button::NextValue => "Next value \u{2192}",
+            Button::SwapOperands => "Swap a and b",
+            Button::UseAsA => "Use as a",
+            Button::ClearFlags => "Clear flags",
+            Button::ClearField(BitType::Sign) => "Clear the sign field",
+            Button::ClearField(BitType::Exponent) => "Clear the exponent field",
+            Button::ClearField(BitType::Significand) => "Clear the significand field",
+            Button::Restore => "Restore",
+            Button::Discard => "Discard",
+            Button::WalkThrough => "Walk through the conversion",
+            Button::Practise => "Practise encoding and reading values",
+            Button::Check => "Check",
+            Button::LoadAnswer => "Load the answer",
+            Button::RevealAnswer => "Reveal the answer",
+            Button::NextQuestion => "Next question",
+            Button::StopPractising => "Stop practising",
+            Button::PreviousStep => "Previous step",
+            Button::NextStep => "Next step",
+        },
+        Text::Label(label) => match label {
+            Label::Sign => "Sign",
+            Label::Exponent => "Exponent",
+            Label::Fraction => "Fraction",
+            Label::Integer => "Integer",
+            Label::Regime => "Regime",
+            Label::Combination => "Combination",
+            Label::ExponentContinuation => "Exponent continuation",
+            Label::TrailingSignificand => "Trailing significand",
+            Label::Coefficient => "Coefficient",
+            Label::SvgTitle => "The bit row as an image, for slides",
+            Label::ResetTitle => "Everything is remembered between visits until reset",
+            Label::RecordTitle => "Record edits to replay later",
+            Label::LoadRecording => "Load a recording",
+            Label::StateHint => "Edit the JSON or paste in another, then click away to apply it",
+            Label::NoFlags => "no flags",
+            Label::NoResultNoNans => "no result: this format has no NaNs",
+            Label::PrecisionP => "Precision p",
+            Label::MachineEpsilon => "Machine epsilon",
+            Label::LargestFinite => "Largest finite",
+            Label::SmallestNormal => "Smallest normal",
+            Label::SmallestSubnormal => "Smallest subnormal",
+            Label::FiniteEncodings => "Finite encodings",
+            Label::None => "none",
+            Label::OverflowsToInfinity => "Overflows to infinity at or above",
+            Label::OverflowsToNan => "Overflows to NaN at or above",
+            Label::Saturates => "Saturates to the largest finite value at or above",
+            Label::BecomesSubnormal => "Becomes subnormal below",
+            Label::NoNormalValues => "No normal values with one exponent bit",
+            Label::UnderflowsToZero => "Underflows to zero at or below",
+            Label::NotRepresentable => "not representable",
+            Label::Format => "Format",
+            Label::PrecisionDigits => "Precision (decimal digits)",
+            Label::RangeDecades => "Range (decades)",
+            Label::LargestExponentAxis => "Largest exponent \u{2192}",
+            Label::DecimalDigitsAxis => "Decimal digits \u{2192}",
+            Label::TotalBits => "Total bits",
+            Label::SmallestMagnitude => "Smallest magnitude",
+            Label::LargestMagnitude => "Largest magnitude",
+            Label::BitSplit => "Split",
+            Label::DecimalDigits => "Decimal digits",
+            Label::CoversRange => "Covers range",
+            Label::CoversYes => "yes",
+            Label::CoversWithSubnormals => "only with subnormals",
+            Label::CoversNoOverflows => "no, overflows",
+            Label::CoversNoUnderflows => "no, underflows",
+            Label::CompareFixedPoint => "Compare with fixed point",
+            Label::FractionBits => "Fraction bits",
+            Label::SignedTwosComplement => "Signed (two's complement)",
+            Label::ComparePosit => "Compare with a posit",
+            Label::PositEs => "Exponent bits, es",
+            Label::CompareTwosComplement => "Compare with a two's complement integer",
+            Label::NotSet => "not set",
+            Label::LinearInValue => "\u{25cf} linear in value",
+            Label::LinearInBits => "\u{25cf} linear in bits",
+            Label::NegativeInvert => "Negative: invert every bit.",
+            Label::PositiveSetSign => "Positive: set the sign bit.",
+            Label::Bits => "Bits",
+            Label::Sortable => "Sortable",
+            Label::Value => "Value",
+            Label::SortableKey => "Sortable key",
+            Label::Pass => "pass",
+            Label::NotChecked => "not checked",
+            Label::NaiveSum => "Naive sum",
+            Label::KahanSum => "Kahan sum",
+            Label::Compensation => "Compensation",
+            Label::ExactSum => "Exact sum",
+            Label::CorrectlyRounded => "Correctly rounded",
+            Label::Error => "Error",
+            Label::NotFinite => "not finite",
+            Label::BigEndian => "big endian",
+            Label::GroupInFours => "Group in fours",
+            Label::ImplicitBitTitle => "The implicit leading bit, which isn't stored",
+            Label::StoredExponent => "Stored exponent",
+            Label::UnbiasedExponent => "Unbiased exponent",
+            Label::LeadingBit => "Leading bit",
+            Label::SignificandInteger => "Significand as an integer",
+            Label::SignificandFraction => "Significand as a fraction",
+            Label::NoUnbiasedExponent => "none: this encoding stands for infinity or NaN",
+            Label::LeadingImplicitOne => "1 (implicit, as the stored exponent isn't zero)",
+            Label::LeadingImplicitZero => "0 (implicit, as the stored exponent is zero)",
+            Label::LeadingStoredOne => "1 (stored)",
+            Label::LeadingStoredZero => "0 (stored)",
+            Label::PlayStepsTitle => "Add one to the raw bits over and over until infinity or NaN",
+            Label::StepTitle => "Add one to the raw bits",
+            Label::NoValueBeyond => "There is no representable value beyond this one",
+            Label::EqualEncodingsDiffer => "a == b, although the encodings differ",
+            Label::Unordered => "a and b are unordered, as one is a NaN, so even a == a is false",
+            Label::NoResultFormatNoNans => "No result, as the format has no NaNs",
+            Label::SetBFromDecimal => "Set b from decimal",
+            Label::Raised => "raised",
+            Label::Clear => "clear",
+            Label::TypingHexHint => "Type hex digits to overwrite, arrows to move, Esc to finish",
+            Label::ClickHexHint => "Click a digit to type over it",
+            Label::ExplainBits => "Explain bits instead of toggling them",
+            Label::ReservedBoth => "an all ones exponent encodes infinity or NaN",
+            Label::ReservedInfinity => "only the all ones encoding is infinity",
+            Label::ReservedNan => "only the all ones encoding is NaN",
+            Label::ReservedNone => "no encodings are reserved for infinity or NaN",
+            Label::SignalingNan => "Signaling NaN: the quiet bit is clear",
+            Label::QuietNan => "Quiet NaN: the quiet bit is set",
+            Label::ExactSingleOne => "exact, as the significand has a single one bit",
+            Label::ExactFractionZeros => "exact, as the fraction bits are all zeros",
+            Label::RoundedNotPowerOfTwo => "rounded, as the value isn't a power of two",
+            Label::ExactPowerOfTen => "exact, as the value is a power of ten",
+            Label::RoundedNotPowerOfTen => {
+                "rounded: only 1, 10, 100 and so on have integer logarithms, as smaller powers of \
+                 ten aren't representable in binary"
+            }
+            Label::SetFromDecimal => "Set from decimal",
+            Label::Hex => "Hex",
+            Label::Binary => "Binary",
+            Label::HexFloat => "Hex float",
+            Label::WhatValue => "What value do these bits encode?",
+            Label::FormatChanged => {
+                "The format has changed since the question was asked, so ask another."
+            }
+            Label::Rounded => "rounded",
+            Label::Exact => "exact",
+            Label::SplitParts => "Split",
+            Label::IntegerPart => "Integer part",
+            Label::FractionPart => "Fraction part",
+            Label::Normalize => "Normalize",
+            Label::BiasTheExponent => "Bias the exponent",
+            Label::Round => "Round",
+        },
+        Text::Sentence(sentence) => match sentence {
+            Sentence::RecordingOneStep => "Recording: 1 step",
+            Sentence::RecordingSteps => "Recording: {} steps",
+            Sentence::StepOf => "Step {} of {}",
+            Sentence::ZerosDiffer => {
+                "The only difference between +0 and \u{2212}0 is the sign bit, which is \
+                 currently {}."
+            }
+            Sentence::ZerosCompareEqual => "{} == {} is {}: the two zeros compare equal.",
+            Sentence::Reciprocals => "Yet 1 / {} = {} while 1 / {} = {}, and sign({}) = {}.",
+            Sentence::DefaultNan => {
+                "Each of these yields the default quiet NaN {} ({}) and raises {}."
+            }
+            Sentence::NoNansStillRaise => {
+                "This format has no NaNs, so these have no result, but they still raise {}."
+            }
+            Sentence::InfinityMinusInfinity => {
+                "Infinity stands in for any value too large to represent, so the difference of \
+                 two infinities could be anything from \u{2212}\u{221e} to +\u{221e}. Overflow \
+                 means the answer is known but too big; here there is no single answer at all."
+            }
+            Sentence::ZeroTimesInfinity => {
+                "Zero may be the result of underflow and infinity of overflow, so their product \
+                 could be any value whatsoever."
+            }
+            Sentence::InfinityOverInfinity => {
+                "The ratio of two values which are both too large to represent depends entirely \
+                 on which values they were, so it is undefined."
+            }
+            Sentence::SqrtOfNegative => {
+                "No real number squares to a negative number. The result is not large, merely \
+                 outside the real numbers."
+            }
+            Sentence::PrecisionBits => "{} bits (\u{2248}{} decimal digits)",
+            Sentence::FiniteOf => "{} of 2^{} ({}%)",
+            Sentence::NextAfterOne => "The next value after 1.0 is {}, so ulp(1.0) = {} = 2^{}.",
+            Sentence::EachBinade => {
+                "With {} stored significand bits (precision p = {}), each binade holds 2^{} \
+                 evenly spaced values, so the spacing at one is 2^(1 \u{2212} p). This is machine \
+                 epsilon; values within half of it of 1.0 round to 1.0."
+            }
+            Sentence::NotSmallest => "It is not the smallest positive value, which is {}.",
+            Sentence::Custom => "Custom ({}/{})",
+            Sentence::Current => "Current ({}/{})",
+            Sentence::FloatingPoint => "Floating point ({}/{})",
+            Sentence::FixedPoint => "Fixed point ({})",
+            Sentence::CompareDecimal => "Compare with decimal{}",
+            Sentence::DecimalOf => "decimal{} ({} digits)",
+            Sentence::TwosComplementInteger => "i{} (two's complement)",
+            Sentence::DensityGaps => {
+                "Neighbouring values are {} apart near zero, but {} apart at the top."
+            }
+            Sentence::TradeoffPoint => "{}/{}: largest exponent {}, {} decimal digits",
+            Sentence::EnterWidth => "Enter a total width between 3 and {} bits.",
+            Sentence::NotRepresentable => "Not representable: {}",
+            Sentence::PositNegation => {
+                "The bits of negative posits are negated in two's complement before their \
+                 fields are read."
+            }
+            Sentence::InterpolateFrom => "From {} ",
+            Sentence::InterpolateTo => "To {} ",
+            Sentence::BitsAreLogarithms => {
+                "Within a binade the bits grow linearly with the value, but each step of the \
+                 exponent doubles the spacing, so the bit pattern read as an integer is roughly \
+                 a scaled and shifted log2 of the value. Interpolating the bits therefore \
+                 interpolates the value geometrically, which is the trick behind the fast \
+                 inverse square root's initial guess."
+            }
+            Sentence::SortableKeys => {
+                "Negative values get smaller as their magnitude grows, so inverting their bits \
+                 reverses their order and puts them below every positive value. Comparing the \
+                 results as unsigned integers then orders floats numerically (with \u{2212}0 \
+                 just below +0 and NaNs at the ends), so floats can be radix sorted a byte at a \
+                 time."
+            }
+            Sentence::OrdinalOf => " of {} from \u{2212}{} to {}",
+            Sentence::PasteTestFloat => {
+                "Paste the output of e.g. testfloat_gen f{}_{} for this {}-bit format."
+            }
+            Sentence::Mismatch => "mismatch: the app gives {} with flags {}",
+            Sentence::SummationIntro => {
+                "Type values to add up in this format, separated by spaces, commas or new lines. \
+                 Kahan's algorithm carries the low order bits each addition loses in a \
+                 compensation term and adds them back into the next value."
+            }
+            Sentence::RoundedFrom => "Rounded from the {} typed",
+            Sentence::DilemmaIntro => {
+                "Rounding f(x) correctly means working out enough of its bits to tell which side \
+                 of a rounding boundary it lies on. A few bits past the rounding bit usually \
+                 settle it, but for these inputs the bits after the rounding bit run on alike for \
+                 a long way, so a library must work far more precisely than the format to round \
+                 them."
+            }
+            Sentence::BitsAlike => "{} bits alike after the rounding bit",
+            Sentence::NearMidpoint => {
+                "After the rounding bit, {} bits in a row are {}, so the exact result is within \
+                 2^\u{2212}{} ulp of the midpoint between two neighbouring values, where rounding \
+                 to nearest changes direction."
+            }
+            Sentence::NearValue => {
+                "After the rounding bit, {} bits in a row are {}, so the exact result is within \
+                 2^\u{2212}{} ulp of a value of the format, where directed roundings change \
+                 direction."
+            }
+            Sentence::OpenDataFile => "Open a .npy file or a raw array of floats.",
+            Sentence::NoWholeElements => "The file has no whole elements of this type.",
+            Sentence::ElementOf => "Element {} of {} ({})",
+            Sentence::PasteAs => "Paste \"{}\" as: ",
+            Sentence::Copied => "{} copied",
+            Sentence::ImplicitBitAria => "implicit leading bit, value {}",
+            Sentence::FieldOfOneBit => "{} field, 1 bit",
+            Sentence::FieldOfBits => "{} field, {} bits",
+            Sentence::BitAria => "{} bit {}, value {}",
+            Sentence::SubnormalExponent => {
+                "{} (subnormals share the exponent of the smallest normal values, 1 \u{2212} bias)"
+            }
+            Sentence::NoFormulaNan => {
+                "No formula gives NaN: the format reserves this encoding for it."
+            }
+            Sentence::NoFormulaInfinity => {
+                "No formula gives infinity: the format reserves this encoding for it."
+            }
+            Sentence::RawBits => "Raw bits {}",
+            Sentence::OneUlp => "One ulp here is 2^{} \u{2248} {}",
+            Sentence::BinadeValues => "There are 2^{}{} values {}, all this far apart",
+            Sentence::FromZeroTo => "from 0 to 2^{}",
+            Sentence::FromTo => "from 2^{} to 2^{}",
+            Sentence::StepsApart => {
+                "They are {} steps apart in the ordinal numbering of the format."
+            }
+            Sentence::RoundedWith => {
+                "The exact result isn't in the format, so it was rounded with {}. "
+            }
+            Sentence::ResultExact => "The result is exact. ",
+            Sentence::PaddedWith => "Padded with {} zero bits above the sign bit",
+            Sentence::SignBitRole => {
+                "The sign bit: 0 for positive and 1 for negative. Flipping it negates the value, \
+                 including zeros, infinities and NaNs."
+            }
+            Sentence::ExponentBitRole => {
+                "Exponent bit worth {} in the stored exponent, which is biased by {}. Raising \
+                 the exponent by one doubles a normal value; {}."
+            }
+            Sentence::ExplicitLeadingBitRole => {
+                "The explicitly stored leading bit: 1 for normal values and 0 for subnormals. \
+                 Without it the exponent alone would have to say which is which."
+            }
+            Sentence::SignificandBitRole => {
+                "Significand bit worth 2^-{} of the leading bit, i.e. it adds that fraction of \
+                 the power of two given by the exponent."
+            }
+            Sentence::FlipWouldChange => {
+                "Flipping it would change the value from {} to {} (a change of {})."
+            }
+            Sentence::RestorePrompt => "Restore {} ({}) from your last visit?",
+            Sentence::NanPayload => "{}, and the payload is {}",
+            Sentence::ClassTitle => "{}, fpclassify gives {}",
+            Sentence::TooManyDigits => {
+                "This value has more than {} decimal digits, too many to show."
+            }
+            Sentence::NumeratorTooLong => {
+                "The numerator has more than {} decimal digits, too many to show."
+            }
+            Sentence::ForExample => "e.g. {}",
+            Sentence::AskBuildBits => "Set the bits above to encode {}.",
+            Sentence::QuizProgress => "Question {}. Solved {} of {} so far.",
+            Sentence::WrongOnce => "Not quite. 1 attempt so far; try again or reveal the answer.",
+            Sentence::Wrong => "Not quite. {} attempts so far; try again or reveal the answer.",
+            Sentence::RightFirstTime => "Right, in one attempt.",
+            Sentence::Right => "Right, in {} attempts.",
+            Sentence::TheAnswer => "The answer is {} = {}.",
+            Sentence::AnswerNegative => "The sign bit is 1, so the value is negative.",
+            Sentence::AnswerPositive => "The sign bit is 0, so the value is positive.",
+            Sentence::AnswerSubnormal => {
+                "The exponent field is 0, so the value is subnormal and scaled by 2^(1 \u{2212} \
+                 {}) = 2^{}."
+            }
+            Sentence::AnswerScaled => {
+                "The exponent field is {}, so the value is scaled by 2^({} \u{2212} {}) = 2^{}."
+            }
+            Sentence::AnswerSignificand => "The significand is {}.{} in binary, which is {}.",
+            Sentence::AnswerTogether => "Together they give {}{} \u{d7} 2^{} = {}.",
+            Sentence::StoredExactly => "{} is stored exactly.",
+            Sentence::OffBy => "{} can't be stored exactly. The stored value is off by {}.",
+            Sentence::ErrorTooLong => {
+                "{} can't be stored exactly, and the error has more than {} digits."
+            }
+            Sentence::TypedNegative => "{} is negative, so the sign bit is 1.",
+            Sentence::TypedPositive => "{} is positive, so the sign bit is 0.",
+            Sentence::IntegerPartIs => "Integer part: {}",
+            Sentence::FractionPartIs => "Fraction part: {}",
+            Sentence::PartsSeparately => "Each part is converted to binary on its own.",
+            Sentence::Halving => "{} \u{f7} 2 = {} remainder {}",
+            Sentence::MoreHalvings => "\u{2026} and {} more halvings",
+            Sentence::IntegerZero => "The integer part is 0, so it adds no binary digits.",
+            Sentence::Remainders => "The remainders, last first, make {} in binary.",
+            Sentence::Doubling => "{} \u{d7} 2 = {} \u{2192} {}",
+            Sentence::MoreDoublings => "\u{2026} and {} more doublings",
+            Sentence::FractionZero => "The fraction part is 0, so it adds no binary digits.",
+            Sentence::IntegerHoldsAll => {
+                "The integer part already holds every bit that can be kept, so the fraction part \
+                 only matters for rounding."
+            }
+            Sentence::FractionExact => {
+                "Nothing is left to double, so the fraction part is exactly 0.{} in binary."
+            }
+            Sentence::FractionForever => {
+                "The doublings would go on forever, but the bits that can be kept and the one \
+                 after them are known: 0.{}\u{2026} in binary."
+            }
+            Sentence::Together => "Together: {}.{}{} in binary.",
+            Sentence::LeadingTooLarge => {
+                "The leading 1 is worth 2^{}, beyond 2^{} at the bottom of the largest binade, \
+                 so the value is too large for the format."
+            }
+            Sentence::MoveNowhere => {
+                "Moving the point nowhere puts it just after the leading 1: {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveOneLeft => {
+                "Moving the point 1 place left puts it just after the leading 1: {}\u{2026} \
+                 \u{d7} 2^{}."
+            }
+            Sentence::MoveOneRight => {
+                "Moving the point 1 place right puts it just after the leading 1: {}\u{2026} \
+                 \u{d7} 2^{}."
+            }
+            Sentence::MoveLeft => {
+                "Moving the point {} places left puts it just after the leading 1: {}\u{2026} \
+                 \u{d7} 2^{}."
+            }
+            Sentence::MoveRight => {
+                "Moving the point {} places right puts it just after the leading 1: {}\u{2026} \
+                 \u{d7} 2^{}."
+            }
+            Sentence::LeadingSubnormal => {
+                "The leading 1 is worth 2^{}, below 2^{} at the bottom of the smallest normal \
+                 binade, so the value is subnormal: {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::ZeroHasNoLeading => {
+                "Zero has no leading 1, and every bit of its significand is 0."
+            }
+            Sentence::NoOneBit => {
+                "There is no 1 bit down to 2^{}, the first bit below the smallest subnormal, so \
+                 only rounding can keep the value from being zero."
+            }
+            Sentence::LeadingStored => {
+                "The bit before the point is stored, as the leading bit of the significand field."
+            }
+            Sentence::LeadingImplied => {
+                "The bit before the point isn't stored, as the exponent field implies it."
+            }
+            Sentence::BiasOverflows => {
+                "{} + bias {} = {} is too large for the exponent field of a finite value."
+            }
+            Sentence::OverflowsTo => "The value overflows to {}.",
+            Sentence::ExponentStores => "The exponent field stores {} + bias {} = {}.",
+            Sentence::InBits => "That is {} in {} bits.",
+            Sentence::ExponentZeros => {
+                "Without a leading 1 at 2^{} or above, the exponent field is all zeros, which \
+                 stands for 2^{} as the smallest normal exponent does."
+            }
+            Sentence::Keeping => {
+                "Keeping {} bits of significand leaves {}, with rounding bit {} and sticky bit {}."
+            }
+            Sentence::RoundsUp => {
+                "They aren't both 0, so the value is inexact, and {} rounds its magnitude up."
+            }
+            Sentence::RoundsDown => {
+                "They aren't both 0, so the value is inexact, and {} rounds its magnitude down."
+            }
+            Sentence::CarryOverflows => {
+                "Rounding up carries past the largest finite value, so the value overflows."
+            }
+            Sentence::CarryIntoExponent => {
+                "Rounding up carries out of the significand and into the exponent field."
+            }
+            Sentence::NothingLost => {
+                "They are both 0, so nothing is lost and the value is stored exactly."
+            }
+            Sentence::Result => "Result: {} = {}",
+        },
+    }
+}
+
+fn spanish(text: Text) -> &'static str {
+    match text {
+        Text::Presets => "Formatos predefinidos",
+        Text::ExponentBits => "Bits de exponente",
+        Text::SignificandBits => "Bits de significando",
+        Text::CopyBits => "Copiar bits",
+        Text::Infinities => "Infinitos",
+        Text::Nans => "NaN",
+        Text::ImplicitLeadingBit => "Bit inicial implícito",
+        Text::Bias => "Sesgo",
+        Text::Field(BitType::Sign) => "Signo",
+        Text::Field(BitType::Exponent) => "Exponente",
+        Text::Field(BitType::Significand) => "Significando",
+        Text::ResetColours => "Restablecer colores",
+        Text::ResetToDefaults => "Restablecer todo",
+        Text::Theme(Theme::Light) => "Claro",
+        Text::Theme(Theme::Dark) => "Oscuro",
+        Text::Theme(Theme::HighContrast) => "Alto contraste",
+        Text::Language => "Idioma",
+        Text::Undo => "Deshacer",
+        Text::Redo => "Rehacer",
+        Text::Class(class) => match class {
+            Class::SignalingNan => "NaN señalizador",
+            Class::QuietNan => "NaN silencioso",
+            Class::NegativeInfinity => "infinito negativo",
+            Class::NegativeNormal => "normal negativo",
+            Class::NegativeSubnormal => "subnormal negativo",
+            Class::NegativeZero => "cero negativo",
+            Class::PositiveZero => "cero positivo",
+            Class::PositiveSubnormal => "subnormal positivo",
+            Class::PositiveNormal => "normal positivo",
+            Class::PositiveInfinity => "infinito positivo",
+        },
+        Text::Heading(heading) => match heading {
+            Heading::SignedZero => "Cero con signo: este es {}",
+            Heading::DivisionByZero => "Dividir x = {} entre cero",
+            Heading::InvalidOperations => "Operaciones inválidas",
+            Heading::Characteristics => "Características del formato",
+            Heading::Thresholds => "Umbrales de desbordamiento y subdesbordamiento",
+            Heading::Constants => "Constantes",
+            Heading::UlpOfOne => "Precisión en uno",
+            Heading::FormatComparison => "Comparación con los formatos estándar",
+            Heading::Density => "Todos los valores finitos no negativos (escala lineal)",
+            Heading::RangePlot => "Rangos positivos (escala logarítmica)",
+            Heading::Tradeoff => "Rango frente a precisión con {} bits",
+            Heading::Allocator => "Reparto de bits",
+            Heading::Reinterpretations => "Reinterpretación de {}",
+            Heading::Interpolation => "Interpolación de patrones de bits",
+            Heading::InterpolationComparison => "Espacio de valores frente a espacio de bits",
+            Heading::SortableBits => "Bits ordenables",
+            Heading::Codegen => "Construirlo en código",
+            Heading::TestVectors => "Vectores de prueba",
+            Heading::TestFloat => "Importar vectores de TestFloat",
+            Heading::Summation => "Suma ingenua y de Kahan",
+            Heading::Dilemma => "El dilema del fabricante de tablas",
+            Heading::DataFile => "Explorar un archivo de datos",
+            Heading::BrowsingDataFile => "Explorando {}",
+            Heading::NeighboursLogScale => "Valores cercanos (distancia en escala logarítmica)",
+            Heading::NeighboursToScale => "Valores cercanos a escala",
+            Heading::Comparison => "Comparar y combinar dos valores",
+            Heading::Flags => "Indicadores de excepción",
+            Heading::FieldBit(BitType::Sign) => "Bit de signo",
+            Heading::FieldBit(BitType::Exponent) => "Bit de exponente",
+            Heading::FieldBit(BitType::Significand) => "Bit de significando",
+            Heading::ExactDecimal => "Valor decimal exacto",
+            Heading::ExactFraction => "Fracción exacta",
+            Heading::Practice => "Práctica",
+            Heading::Walkthrough => "Conversión a binario",
+        },
+        Text::FiniteValue => "Valor finito n.º ",
+        Text::NotFinite => "No es un valor finito. Ir al valor finito n.º ",
+        Text::Overflow(Overflow::Infinity) => "Desbordar a infinito",
+        Text::Overflow(Overflow::Saturate) => "Saturar al mayor valor finito",
+        Text::SpecialValue(special) => match special {
+            SpecialValue::QuietNan => "NaN silencioso",
+            SpecialValue::MinSubnormal => "Menor subnormal",
+            SpecialValue::MinNormal => "Menor normal",
+            SpecialValue::MaxFinite => "Mayor finito",
+            SpecialValue::PositiveZero
+            | SpecialValue::NegativeZero
+            | SpecialValue::PositiveInfinity
+            | SpecialValue::NegativeInfinity
+            | SpecialValue::One => special.name(),
+        },
+        Text::UnaryOperator(operator) => match operator {
+            UnaryOperator::Negate => "Negar",
+            UnaryOperator::Abs => "Valor absoluto",
+            UnaryOperator::Double => "Duplicar",
+            UnaryOperator::Halve => "Dividir entre dos",
+        },
+        Text::PasteFormat(format) => match format {
+            PasteFormat::Decimal => "Decimal",
+            PasteFormat::Hex => "Patrón hexadecimal",
+            PasteFormat::Binary => "Cadena binaria",
+            PasteFormat::HexFloat => "Flotante hexadecimal",
+        },
+        Text::DecimalEncoding(DecimalEncoding::Bid) => "BID (entero binario)",
+        Text::DecimalEncoding(DecimalEncoding::Dpd) => "DPD (decimal densamente empaquetado)",
+        Text::Unrepresentable(reason) => match reason {
+            Unrepresentable::NotFinite => "los enteros no tienen infinitos ni NaN",
+            Unrepresentable::Fractional => "no es un número entero",
+            Unrepresentable::OutOfRange => "está fuera del rango del entero",
+        },
+        Text::NonCanonical(kind) => match kind {
+            NonCanonical::PseudoDenormal => {
+                "El campo de exponente es cero pero el bit inicial está activado"
+            }
+            NonCanonical::Unnormal => {
+                "El campo de exponente no es cero pero el bit inicial está desactivado"
+            }
+            NonCanonical::PseudoInfinity => {
+                "El campo de exponente es todo unos y el significando es todo ceros, bit inicial \
+                 incluido"
+            }
+            NonCanonical::PseudoNan => {
+                "El campo de exponente es todo unos y el bit inicial está desactivado, pero el \
+                 resto del significando no"
+            }
+        },
+        Text::Button(button) => match button {
+            Button::TimeTravel => "Viajar en el tiempo",
+            Button::StopTimeTravel => "Dejar de viajar en el tiempo",
+            Button::Record => "Grabar",
+            Button::StopRecording => "Detener la grabación",
+            Button::Rewind => "Rebobinar",
+            Button::Step => "Paso",
+            Button::Play => "Reproducir",
+            Button::Pause => "Pausa",
+            Button::Stop => "Detener",
+            Button::CopyScript => "Copiar el guion",
+            Button::Close => "Cerrar",
+            Button::ExportState => "Exportar o importar el estado",
+            Button::HideState => "Ocultar el estado",
+            Button::Copy => "Copiar",
+            Button::Download => "Descargar",
+            Button::DownloadSvg => "Descargar SVG",
+            Button::FlipSignBit => "Invertir el bit de signo",
+            Button::Load => "Cargar",
+            Button::LoadResult => "Cargar el resultado",
+            Button::LoadOne => "Cargar 1.0",
+            Button::LoadOnePlusUlp => "Cargar 1.0 + ulp",
+            Button::Use => "Usar",
+            Button::UseCurrent => "Usar el actual",
+            Button::CopyCode => "Copiar el código",
+            Button::CopyTestVectors => "Copiar los vectores de prueba",
+            Button::Previous => "Anterior",
+            Button::Next => "Siguiente",
+            Button::NextNan => "Siguiente NaN",
+            Button::Cancel => "Cancelar",
+            Button::Dismiss => "Descartar",
+            Button::Randomize => "Aleatorio",
+            Button::Fill(BitType::Sign) => "Llenar el signo",
+            Button::Fill(BitType::Exponent) => "Llenar el exponente",
+            Button::Fill(BitType::Significand) => "Llenar el significando",
+            Button::Clear(BitType::Sign) => "Vaciar el signo",
+            Button::Clear(BitType::Exponent) => "Vaciar el exponente",
+            Button::Clear(BitType::Significand) => "Vaciar el significando",
+            Button::ClearAll => "Vaciar todo",
+            Button::CopyFormat(PasteFormat::Decimal) => "Copiar en decimal",
+            Button::CopyFormat(PasteFormat::Hex) => "Copiar el patrón hexadecimal",
+            Button::CopyFormat(PasteFormat::Binary) => "Copiar la cadena binaria",
+            Button::CopyFormat(PasteFormat::HexFloat) => "Copiar el flotante hexadecimal",
+            Button::PreviousValue => "\u{2190} Valor anterior",
+            Button::NextValue => "Valor siguiente \u{2192}",
+            Button::SwapOperands => "Intercambiar a y b",
+            Button::UseAsA => "Usar como a",
+            Button::ClearFlags => "Borrar los indicadores",
+            Button::ClearField(BitType::Sign) => "Vaciar el campo de signo",
+            Button::ClearField(BitType::Exponent) => "Vaciar el campo de exponente",
+            Button::ClearField(BitType::Significand) => "Vaciar el campo de significando",
+            Button::Restore => "Restaurar",
+            Button::Discard => "Descartar",
+            Button::WalkThrough => "Ver la conversión paso a paso",
+            Button::Practise => "Practicar la codificación y lectura de valores",
+            Button::Check => "Comprobar",
+            Button::LoadAnswer => "Cargar la respuesta",
+            Button::RevealAnswer => "Mostrar la respuesta",
+            Button::NextQuestion => "Siguiente pregunta",
+            Button::StopPractising => "Dejar de practicar",
+            Button::PreviousStep => "Paso anterior",
+            Button::NextStep => "Paso siguiente",
+        },
+        Text::Label(label) => match label {
+            Label::Sign => "Signo",
+            Label::Exponent => "Exponente",
+            Label::Fraction => "Fracción",
+            Label::Integer => "Entero",
+            Label::Regime => "Régimen",
+            Label::Combination => "Combinación",
+            Label::ExponentContinuation => "Continuación del exponente",
+            Label::TrailingSignificand => "Significando final",
+            Label::Coefficient => "Coeficiente",
+            Label::SvgTitle => "La fila de bits como imagen, para diapositivas",
+            Label::ResetTitle => "Todo se recuerda entre visitas hasta que se restablece",
+            Label::RecordTitle => "Grabar las ediciones para reproducirlas después",
+            Label::LoadRecording => "Cargar una grabación",
+            Label::StateHint => "Edita el JSON o pega otro y haz clic fuera para aplicarlo",
+            Label::NoFlags => "ningún indicador",
+            Label::NoResultNoNans => "sin resultado: este formato no tiene NaN",
+            Label::PrecisionP => "Precisión p",
+            Label::MachineEpsilon => "Épsilon de máquina",
+            Label::LargestFinite => "Mayor finito",
+            Label::SmallestNormal => "Menor normal",
+            Label::SmallestSubnormal => "Menor subnormal",
+            Label::FiniteEncodings => "Codificaciones finitas",
+            Label::None => "ninguno",
+            Label::OverflowsToInfinity => "Desborda a infinito a partir de",
+            Label::OverflowsToNan => "Desborda a NaN a partir de",
+            Label::Saturates => "Satura al mayor valor finito a partir de",
+            Label::BecomesSubnormal => "Pasa a subnormal por debajo de",
+            Label::NoNormalValues => "No hay valores normales con un solo bit de exponente",
+            Label::UnderflowsToZero => "Se anula por subdesbordamiento hasta",
+            Label::NotRepresentable => "no representable",
+            Label::Format => "Formato",
+            Label::PrecisionDigits => "Precisión (dígitos decimales)",
+            Label::RangeDecades => "Rango (décadas)",
+            Label::LargestExponentAxis => "Mayor exponente \u{2192}",
+            Label::DecimalDigitsAxis => "Dígitos decimales \u{2192}",
+            Label::TotalBits => "Bits en total",
+            Label::SmallestMagnitude => "Menor magnitud",
+            Label::LargestMagnitude => "Mayor magnitud",
+            Label::BitSplit => "Reparto",
+            Label::DecimalDigits => "Dígitos decimales",
+            Label::CoversRange => "Cubre el rango",
+            Label::CoversYes => "sí",
+            Label::CoversWithSubnormals => "solo con subnormales",
+            Label::CoversNoOverflows => "no, desborda",
+            Label::CoversNoUnderflows => "no, subdesborda",
+            Label::CompareFixedPoint => "Comparar con coma fija",
+            Label::FractionBits => "Bits de fracción",
+            Label::SignedTwosComplement => "Con signo (complemento a dos)",
+            Label::ComparePosit => "Comparar con un posit",
+            Label::PositEs => "Bits de exponente, es",
+            Label::CompareTwosComplement => "Comparar con un entero en complemento a dos",
+            Label::NotSet => "sin fijar",
+            Label::LinearInValue => "\u{25cf} lineal en el valor",
+            Label::LinearInBits => "\u{25cf} lineal en los bits",
+            Label::NegativeInvert => "Negativo: se invierten todos los bits.",
+            Label::PositiveSetSign => "Positivo: se activa el bit de signo.",
+            Label::Bits => "Bits",
+            Label::Sortable => "Ordenable",
+            Label::Value => "Valor",
+            Label::SortableKey => "Clave ordenable",
+            Label::Pass => "correcto",
+            Label::NotChecked => "sin comprobar",
+            Label::NaiveSum => "Suma ingenua",
+            Label::KahanSum => "Suma de Kahan",
+            Label::Compensation => "Compensación",
+            Label::ExactSum => "Suma exacta",
+            Label::CorrectlyRounded => "Redondeo correcto",
+            Label::Error => "Error",
+            Label::NotFinite => "no finito",
+            Label::BigEndian => "big endian",
+            Label::GroupInFours => "Agrupar de cuatro en cuatro",
+            Label::ImplicitBitTitle => "El bit inicial implícito, que no se almacena",
+            Label::StoredExponent => "Exponente almacenado",
+            Label::UnbiasedExponent => "Exponente sin sesgo",
+            Label::LeadingBit => "Bit inicial",
+            Label::SignificandInteger => "Significando como entero",
+            Label::SignificandFraction => "Significando como fracción",
+            Label::NoUnbiasedExponent => "ninguno: esta codificación representa infinito o NaN",
+            Label::LeadingImplicitOne => "1 (implícito, ya que el exponente almacenado no es cero)",
+            Label::LeadingImplicitZero => "0 (implícito, ya que el exponente almacenado es cero)",
+            Label::LeadingStoredOne => "1 (almacenado)",
+            Label::LeadingStoredZero => "0 (almacenado)",
+            Label::PlayStepsTitle => "Sumar uno a los bits una y otra vez hasta llegar a infinito o NaN",
+            Label::StepTitle => "Sumar uno a los bits",
+            Label::NoValueBeyond => "No hay ningún valor representable más allá de este",
+            Label::EqualEncodingsDiffer => "a == b, aunque las codificaciones difieren",
+            Label::Unordered => {
+                "a y b no están ordenados, ya que uno es un NaN, así que incluso a == a es falso"
+            }
+            Label::NoResultFormatNoNans => "Sin resultado, ya que el formato no tiene NaN",
+            Label::SetBFromDecimal => "Fijar b desde un decimal",
+            Label::Raised => "activado",
+            Label::Clear => "desactivado",
+            Label::TypingHexHint => {
+                "Escribe dígitos hexadecimales para sobrescribir, flechas para moverte y Esc para \
+                 terminar"
+            }
+            Label::ClickHexHint => "Haz clic en un dígito para sobrescribirlo",
+            Label::ExplainBits => "Explicar los bits en lugar de invertirlos",
+            Label::ReservedBoth => "un exponente todo unos codifica infinito o NaN",
+            Label::ReservedInfinity => "solo la codificación todo unos es infinito",
+            Label::ReservedNan => "solo la codificación todo unos es NaN",
+            Label::ReservedNone => "no hay codificaciones reservadas para infinito ni NaN",
+            Label::SignalingNan => "NaN señalizador: el bit de silencio está desactivado",
+            Label::QuietNan => "NaN silencioso: el bit de silencio está activado",
+            Label::ExactSingleOne => "exacto, ya que el significando tiene un único bit a uno",
+            Label::ExactFractionZeros => "exacto, ya que los bits de fracción son todos cero",
+            Label::RoundedNotPowerOfTwo => "redondeado, ya que el valor no es una potencia de dos",
+            Label::ExactPowerOfTen => "exacto, ya que el valor es una potencia de diez",
+            Label::RoundedNotPowerOfTen => {
+                "redondeado: solo 1, 10, 100, etc. tienen logaritmos enteros, ya que las \
+                 potencias de diez menores no son representables en binario"
+            }
+            Label::SetFromDecimal => "Fijar desde un decimal",
+            Label::Hex => "Hexadecimal",
+            Label::Binary => "Binario",
+            Label::HexFloat => "Flotante hexadecimal",
+            Label::WhatValue => "¿Qué valor codifican estos bits?",
+            Label::FormatChanged => {
+                "El formato ha cambiado desde que se hizo la pregunta, así que pide otra."
+            }
+            Label::Rounded => "redondeado",
+            Label::Exact => "exacto",
+            Label::SplitParts => "Separar",
+            Label::IntegerPart => "Parte entera",
+            Label::FractionPart => "Parte fraccionaria",
+            Label::Normalize => "Normalizar",
+            Label::BiasTheExponent => "Sesgar el exponente",
+            Label::Round => "Redondear",
+        },
+        Text::Sentence(sentence) => match sentence {
+            Sentence::RecordingOneStep => "Grabando: 1 paso",
+            Sentence::RecordingSteps => "Grabando: {} pasos",
+            Sentence::StepOf => "Paso {} de {}",
+            Sentence::ZerosDiffer => {
+                "La única diferencia entre +0 y \u{2212}0 es el bit de signo, que ahora vale {}."
+            }
+            Sentence::ZerosCompareEqual => "{} == {} es {}: los dos ceros son iguales al comparar.",
+            Sentence::Reciprocals => "Sin embargo, 1 / {} = {} mientras que 1 / {} = {}, y sign({}) = {}.",
+            Sentence::DefaultNan => {
+                "Cada una de ellas da el NaN silencioso predeterminado {} ({}) y activa {}."
+            }
+            Sentence::NoNansStillRaise => {
+                "Este formato no tiene NaN, así que no tienen resultado, pero aun así activan {}."
+            }
+            Sentence::InfinityMinusInfinity => {
+                "Infinito representa cualquier valor demasiado grande para representarse, así que \
+                 la diferencia de dos infinitos podría ser cualquier cosa entre \u{2212}\u{221e} y \
+                 +\u{221e}. El desbordamiento significa que la respuesta se conoce pero es \
+                 demasiado grande; aquí no hay ninguna respuesta única."
+            }
+            Sentence::ZeroTimesInfinity => {
+                "Cero puede ser el resultado de un subdesbordamiento e infinito de un \
+                 desbordamiento, así que su producto podría ser cualquier valor."
+            }
+            Sentence::InfinityOverInfinity => {
+                "El cociente de dos valores demasiado grandes para representarse depende por \
+                 completo de cuáles eran, así que no está definido."
+            }
+            Sentence::SqrtOfNegative => {
+                "Ningún número real al cuadrado da un número negativo. El resultado no es grande, \
+                 simplemente está fuera de los números reales."
+            }
+            Sentence::PrecisionBits => "{} bits (\u{2248}{} dígitos decimales)",
+            Sentence::FiniteOf => "{} de 2^{} ({} %)",
+            Sentence::NextAfterOne => "El valor siguiente a 1.0 es {}, así que ulp(1.0) = {} = 2^{}.",
+            Sentence::EachBinade => {
+                "Con {} bits de significando almacenados (precisión p = {}), cada binada contiene \
+                 2^{} valores equiespaciados, así que la separación en uno es 2^(1 \u{2212} p). \
+                 Este es el épsilon de máquina; los valores a menos de su mitad de 1.0 se \
+                 redondean a 1.0."
+            }
+            Sentence::NotSmallest => "No es el menor valor positivo, que es {}.",
+            Sentence::Custom => "Personalizado ({}/{})",
+            Sentence::Current => "Actual ({}/{})",
+            Sentence::FloatingPoint => "Coma flotante ({}/{})",
+            Sentence::FixedPoint => "Coma fija ({})",
+            Sentence::CompareDecimal => "Comparar con decimal{}",
+            Sentence::DecimalOf => "decimal{} ({} dígitos)",
+            Sentence::TwosComplementInteger => "i{} (complemento a dos)",
+            Sentence::DensityGaps => {
+                "Los valores vecinos están a {} de distancia cerca de cero, pero a {} en lo más alto."
+            }
+            Sentence::TradeoffPoint => "{}/{}: mayor exponente {}, {} dígitos decimales",
+            Sentence::EnterWidth => "Introduce un ancho total de entre 3 y {} bits.",
+            Sentence::NotRepresentable => "No representable: {}",
+            Sentence::PositNegation => {
+                "Los bits de los posits negativos se niegan en complemento a dos antes de leer sus \
+                 campos."
+            }
+            Sentence::InterpolateFrom => "Desde {} ",
+            Sentence::InterpolateTo => "Hasta {} ",
+            Sentence::BitsAreLogarithms => {
+                "Dentro de una binada los bits crecen linealmente con el valor, pero cada paso del \
+                 exponente duplica la separación, así que el patrón de bits leído como entero es \
+                 aproximadamente un log2 del valor escalado y desplazado. Interpolar los bits \
+                 interpola por tanto el valor geométricamente, que es el truco detrás de la \
+                 estimación inicial de la raíz cuadrada inversa rápida."
+            }
+            Sentence::SortableKeys => {
+                "Los valores negativos disminuyen al crecer su magnitud, así que invertir sus bits \
+                 invierte su orden y los coloca por debajo de todo valor positivo. Comparar los \
+                 resultados como enteros sin signo ordena entonces los flotantes numéricamente \
+                 (con \u{2212}0 justo debajo de +0 y los NaN en los extremos), así que los \
+                 flotantes pueden ordenarse por radix byte a byte."
+            }
+            Sentence::OrdinalOf => " de {} entre \u{2212}{} y {}",
+            Sentence::PasteTestFloat => {
+                "Pega la salida de, por ejemplo, testfloat_gen f{}_{} para este formato de {} bits."
+            }
+            Sentence::Mismatch => "discrepancia: la aplicación da {} con indicadores {}",
+            Sentence::SummationIntro => {
+                "Escribe valores para sumar en este formato, separados por espacios, comas o \
+                 saltos de línea. El algoritmo de Kahan guarda los bits de menor orden que pierde \
+                 cada suma en un término de compensación y los vuelve a sumar al siguiente valor."
+            }
+            Sentence::RoundedFrom => "Redondeado a partir del {} escrito",
+            Sentence::DilemmaIntro => {
+                "Redondear f(x) correctamente exige calcular suficientes bits para saber a qué \
+                 lado de una frontera de redondeo queda. Unos pocos bits tras el bit de redondeo \
+                 suelen bastar, pero con estas entradas los bits que lo siguen son iguales \
+                 durante mucho tiempo, así que una biblioteca debe trabajar con mucha más \
+                 precisión que el formato para redondearlas."
+            }
+            Sentence::BitsAlike => "{} bits iguales tras el bit de redondeo",
+            Sentence::NearMidpoint => {
+                "Tras el bit de redondeo, {} bits seguidos valen {}, así que el resultado exacto \
+                 está a menos de 2^\u{2212}{} ulp del punto medio entre dos valores vecinos, \
+                 donde el redondeo al más cercano cambia de sentido."
+            }
+            Sentence::NearValue => {
+                "Tras el bit de redondeo, {} bits seguidos valen {}, así que el resultado exacto \
+                 está a menos de 2^\u{2212}{} ulp de un valor del formato, donde los redondeos \
+                 dirigidos cambian de sentido."
+            }
+            Sentence::OpenDataFile => "Abre un archivo .npy o un vector de flotantes sin formato.",
+            Sentence::NoWholeElements => "El archivo no tiene ningún elemento completo de este tipo.",
+            Sentence::ElementOf => "Elemento {} de {} ({})",
+            Sentence::PasteAs => "Pegar \"{}\" como: ",
+            Sentence::Copied => "{}: copiado",
+            Sentence::ImplicitBitAria => "bit inicial implícito, valor {}",
+            Sentence::FieldOfOneBit => "campo {}, 1 bit",
+            Sentence::FieldOfBits => "campo {}, {} bits",
+            Sentence::BitAria => "{}: bit {}, valor {}",
+            Sentence::SubnormalExponent => {
+                "{} (los subnormales comparten el exponente de los menores valores normales, 1 \
+                 \u{2212} sesgo)"
+            }
+            Sentence::NoFormulaNan => {
+                "Ninguna fórmula da NaN: el formato reserva esta codificación para él."
+            }
+            Sentence::NoFormulaInfinity => {
+                "Ninguna fórmula da infinito: el formato reserva esta codificación para él."
+            }
+            Sentence::RawBits => "Bits {}",
+            Sentence::OneUlp => "Aquí un ulp es 2^{} \u{2248} {}",
+            Sentence::BinadeValues => "Hay 2^{}{} valores {}, todos a esta distancia",
+            Sentence::FromZeroTo => "de 0 a 2^{}",
+            Sentence::FromTo => "de 2^{} a 2^{}",
+            Sentence::StepsApart => {
+                "Están a {} pasos de distancia en la numeración ordinal del formato."
+            }
+            Sentence::RoundedWith => {
+                "El resultado exacto no está en el formato, así que se ha redondeado con {}. "
+            }
+            Sentence::ResultExact => "El resultado es exacto. ",
+            Sentence::PaddedWith => "Rellenado con {} bits a cero por encima del bit de signo",
+            Sentence::SignBitRole => {
+                "El bit de signo: 0 para positivo y 1 para negativo. Invertirlo niega el valor, \
+                 incluidos los ceros, los infinitos y los NaN."
+            }
+            Sentence::ExponentBitRole => {
+                "Bit de exponente que vale {} en el exponente almacenado, cuyo sesgo es {}. Subir \
+                 el exponente en uno duplica un valor normal; {}."
+            }
+            Sentence::ExplicitLeadingBitRole => {
+                "El bit inicial almacenado explícitamente: 1 para los valores normales y 0 para los \
+                 subnormales. Sin él, el exponente tendría que indicar por sí solo cuál es cuál."
+            }
+            Sentence::SignificandBitRole => {
+                "Bit de significando que vale 2^-{} del bit inicial, es decir, suma esa fracción de \
+                 la potencia de dos que da el exponente."
+            }
+            Sentence::FlipWouldChange => {
+                "Invertirlo cambiaría el valor de {} a {} (un cambio de {})."
+            }
+            Sentence::RestorePrompt => "¿Restaurar {} ({}) de tu última visita?",
+            Sentence::NanPayload => "{}, y la carga útil es {}",
+            Sentence::ClassTitle => "{}, fpclassify da {}",
+            Sentence::TooManyDigits => {
+                "Este valor tiene más de {} dígitos decimales, demasiados para mostrarlos."
+            }
+            Sentence::NumeratorTooLong => {
+                "El numerador tiene más de {} dígitos decimales, demasiados para mostrarlos."
+            }
+            Sentence::ForExample => "p. ej. {}",
+            Sentence::AskBuildBits => "Fija los bits de arriba para codificar {}.",
+            Sentence::QuizProgress => "Pregunta {}. Resueltas {} de {} hasta ahora.",
+            Sentence::WrongOnce => {
+                "No exactamente. 1 intento hasta ahora; vuelve a intentarlo o muestra la respuesta."
+            }
+            Sentence::Wrong => {
+                "No exactamente. {} intentos hasta ahora; vuelve a intentarlo o muestra la \
+                 respuesta."
+            }
+            Sentence::RightFirstTime => "Correcto, al primer intento.",
+            Sentence::Right => "Correcto, en {} intentos.",
+            Sentence::TheAnswer => "La respuesta es {} = {}.",
+            Sentence::AnswerNegative => "El bit de signo es 1, así que el valor es negativo.",
+            Sentence::AnswerPositive => "El bit de signo es 0, así que el valor es positivo.",
+            Sentence::AnswerSubnormal => {
+                "El campo de exponente es 0, así que el valor es subnormal y se escala por 2^(1 \
+                 \u{2212} {}) = 2^{}."
+            }
+            Sentence::AnswerScaled => {
+                "El campo de exponente es {}, así que el valor se escala por 2^({} \u{2212} {}) = \
+                 2^{}."
+            }
+            Sentence::AnswerSignificand => "El significando es {}.{} en binario, es decir, {}.",
+            Sentence::AnswerTogether => "Juntos dan {}{} \u{d7} 2^{} = {}.",
+            Sentence::StoredExactly => "{} se almacena exactamente.",
+            Sentence::OffBy => {
+                "{} no puede almacenarse exactamente. El valor almacenado difiere en {}."
+            }
+            Sentence::ErrorTooLong => {
+                "{} no puede almacenarse exactamente, y el error tiene más de {} dígitos."
+            }
+            Sentence::TypedNegative => "{} es negativo, así que el bit de signo es 1.",
+            Sentence::TypedPositive => "{} es positivo, así que el bit de signo es 0.",
+            Sentence::IntegerPartIs => "Parte entera: {}",
+            Sentence::FractionPartIs => "Parte fraccionaria: {}",
+            Sentence::PartsSeparately => "Cada parte se convierte a binario por separado.",
+            Sentence::Halving => "{} \u{f7} 2 = {} resto {}",
+            Sentence::MoreHalvings => "\u{2026} y {} divisiones más",
+            Sentence::IntegerZero => "La parte entera es 0, así que no aporta dígitos binarios.",
+            Sentence::Remainders => "Los restos, del último al primero, forman {} en binario.",
+            Sentence::Doubling => "{} \u{d7} 2 = {} \u{2192} {}",
+            Sentence::MoreDoublings => "\u{2026} y {} duplicaciones más",
+            Sentence::FractionZero => {
+                "La parte fraccionaria es 0, así que no aporta dígitos binarios."
+            }
+            Sentence::IntegerHoldsAll => {
+                "La parte entera ya contiene todos los bits que pueden conservarse, así que la \
+                 parte fraccionaria solo importa para el redondeo."
+            }
+            Sentence::FractionExact => {
+                "No queda nada por duplicar, así que la parte fraccionaria es exactamente 0.{} en \
+                 binario."
+            }
+            Sentence::FractionForever => {
+                "Las duplicaciones no terminarían nunca, pero ya se conocen los bits que pueden \
+                 conservarse y el siguiente: 0.{}\u{2026} en binario."
+            }
+            Sentence::Together => "Juntas: {}.{}{} en binario.",
+            Sentence::LeadingTooLarge => {
+                "El 1 inicial vale 2^{}, por encima de 2^{} en la base de la mayor binada, así que \
+                 el valor es demasiado grande para el formato."
+            }
+            Sentence::MoveNowhere => {
+                "Sin mover la coma, ya queda justo después del 1 inicial: {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveOneLeft => {
+                "Mover la coma 1 posición a la izquierda la deja justo después del 1 inicial: \
+                 {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveOneRight => {
+                "Mover la coma 1 posición a la derecha la deja justo después del 1 inicial: \
+                 {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveLeft => {
+                "Mover la coma {} posiciones a la izquierda la deja justo después del 1 inicial: \
+                 {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveRight => {
+                "Mover la coma {} posiciones a la derecha la deja justo después del 1 inicial: \
+                 {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::LeadingSubnormal => {
+                "El 1 inicial vale 2^{}, por debajo de 2^{} en la base de la menor binada normal, \
+                 así que el valor es subnormal: {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::ZeroHasNoLeading => {
+                "El cero no tiene 1 inicial, y todos los bits de su significando son 0."
+            }
+            Sentence::NoOneBit => {
+                "No hay ningún bit a 1 hasta 2^{}, el primer bit por debajo del menor subnormal, \
+                 así que solo el redondeo puede evitar que el valor sea cero."
+            }
+            Sentence::LeadingStored => {
+                "El bit anterior a la coma se almacena, como bit inicial del campo de \
+                 significando."
+            }
+            Sentence::LeadingImplied => {
+                "El bit anterior a la coma no se almacena, ya que el campo de exponente lo implica."
+            }
+            Sentence::BiasOverflows => {
+                "{} + sesgo {} = {} es demasiado grande para el campo de exponente de un valor \
+                 finito."
+            }
+            Sentence::OverflowsTo => "El valor desborda a {}.",
+            Sentence::ExponentStores => "El campo de exponente almacena {} + sesgo {} = {}.",
+            Sentence::InBits => "Es decir, {} en {} bits.",
+            Sentence::ExponentZeros => {
+                "Sin un 1 inicial en 2^{} o más, el campo de exponente es todo ceros, que \
+                 representa 2^{} igual que el menor exponente normal."
+            }
+            Sentence::Keeping => {
+                "Al conservar {} bits de significando queda {}, con bit de redondeo {} y bit \
+                 pegajoso {}."
+            }
+            Sentence::RoundsUp => {
+                "No son ambos 0, así que el valor es inexacto, y {} redondea su magnitud hacia \
+                 arriba."
+            }
+            Sentence::RoundsDown => {
+                "No son ambos 0, así que el valor es inexacto, y {} redondea su magnitud hacia \
+                 abajo."
+            }
+            Sentence::CarryOverflows => {
+                "Redondear hacia arriba lleva más allá del mayor valor finito, así que el valor \
+                 desborda."
+            }
+            Sentence::CarryIntoExponent => {
+                "Redondear hacia arriba produce un acarreo que sale del significando y entra en el \
+                 campo de exponente."
+            }
+            Sentence::NothingLost => {
+                "Ambos son 0, así que no se pierde nada y el valor se almacena exactamente."
+            }
+            Sentence::Result => "Resultado: {} = {}",
+        },
+    }
+}
+
+fn french(text: Text) -> &'static str {
+    match text {
+        Text::Presets => "Formats prédéfinis",
+        Text::ExponentBits => "Bits d'exposant",
+        Text::SignificandBits => "Bits de mantisse",
+        Text::CopyBits => "Copier les bits",
+        Text::Infinities => "Infinis",
+        Text::Nans => "NaN",
+        Text::ImplicitLeadingBit => "Bit de tête implicite",
+        Text::Bias => "Biais",
+        Text::Field(BitType::Sign) => "Signe",
+        Text::Field(BitType::Exponent) => "Exposant",
+        Text::Field(BitType::Significand) => "Mantisse",
+        Text::ResetColours => "Réinitialiser les couleurs",
+        Text::ResetToDefaults => "Tout réinitialiser",
+        Text::Theme(Theme::Light) => "Clair",
+        Text::Theme(Theme::Dark) => "Sombre",
+        Text::Theme(Theme::HighContrast) => "Contraste élevé",
+        Text::Language => "Langue",
+        Text::Undo => "Annuler",
+        Text::Redo => "Rétablir",
+        Text::Class(class) => match class {
+            Class::SignalingNan => "NaN signalant",
+            Class::QuietNan => "NaN silencieux",
+            Class::NegativeInfinity => "infini négatif",
+            Class::NegativeNormal => "normal négatif",
+            Class::NegativeSubnormal => "sous-normal négatif",
+            Class::NegativeZero => "zéro négatif",
+            Class::PositiveZero => "zéro positif",
+            Class::PositiveSubnormal => "sous-normal positif",
+            Class::PositiveNormal => "normal positif",
+            Class::PositiveInfinity => "infini positif",
+        },
+        Text::Heading(heading) => match heading {
+            Heading::SignedZero => "Zéro signé : celui-ci est {}",
+            Heading::DivisionByZero => "Diviser x = {} par zéro",
+            Heading::InvalidOperations => "Opérations invalides",
+            Heading::Characteristics => "Caractéristiques du format",
+            Heading::Thresholds => "Seuils de dépassement et de sous-dépassement",
+            Heading::Constants => "Constantes",
+            Heading::UlpOfOne => "Précision en un",
+            Heading::FormatComparison => "Comparaison avec les formats standard",
+            Heading::Density => "Valeurs finies positives ou nulles (échelle linéaire)",
+            Heading::RangePlot => "Plages positives (échelle logarithmique)",
+            Heading::Tradeoff => "Plage contre précision sur {} bits",
+            Heading::Allocator => "Répartition des bits",
+            Heading::Reinterpretations => "Réinterprétation de {}",
+            Heading::Interpolation => "Interpolation des motifs de bits",
+            Heading::InterpolationComparison => "Espace des valeurs contre espace des bits",
+            Heading::SortableBits => "Bits triables",
+            Heading::Codegen => "Le construire en code",
+            Heading::TestVectors => "Vecteurs de test",
+            Heading::TestFloat => "Importer des vecteurs TestFloat",
+            Heading::Summation => "Sommation naïve et de Kahan",
+            Heading::Dilemma => "Le dilemme du fabricant de tables",
+            Heading::DataFile => "Parcourir un fichier de données",
+            Heading::BrowsingDataFile => "Exploration de {}",
+            Heading::NeighboursLogScale => "Valeurs voisines (distance en échelle logarithmique)",
+            Heading::NeighboursToScale => "Valeurs voisines à l'échelle",
+            Heading::Comparison => "Comparer et combiner deux valeurs",
+            Heading::Flags => "Indicateurs d'exception",
+            Heading::FieldBit(BitType::Sign) => "Bit de signe",
+            Heading::FieldBit(BitType::Exponent) => "Bit d'exposant",
+            Heading::FieldBit(BitType::Significand) => "Bit de mantisse",
+            Heading::ExactDecimal => "Valeur décimale exacte",
+            Heading::ExactFraction => "Fraction exacte",
+            Heading::Practice => "Entraînement",
+            Heading::Walkthrough => "Conversion en binaire",
+        },
+        Text::FiniteValue => "Valeur finie n° ",
+        Text::NotFinite => "Pas une valeur finie. Aller à la valeur finie n° ",
+        Text::Overflow(Overflow::Infinity) => "Dépasser vers l'infini",
+        Text::Overflow(Overflow::Saturate) => "Saturer à la plus grande valeur finie",
+        Text::SpecialValue(special) => match special {
+            SpecialValue::QuietNan => "NaN silencieux",
+            SpecialValue::MinSubnormal => "Plus petit sous-normal",
+            SpecialValue::MinNormal => "Plus petit normal",
+            SpecialValue::MaxFinite => "Plus grand fini",
+            SpecialValue::PositiveZero
+            | SpecialValue::NegativeZero
+            | SpecialValue::PositiveInfinity
+            | SpecialValue::NegativeInfinity
+            | SpecialValue::One => special.name(),
+        },
+        Text::UnaryOperator(operator) => match operator {
+            UnaryOperator::Negate => "Opposé",
+            UnaryOperator::Abs => "Valeur absolue",
+            UnaryOperator::Double => "Doubler",
+            UnaryOperator::Halve => "Diviser par deux",
+        },
+        Text::PasteFormat(format) => match format {
+            PasteFormat::Decimal => "Décimal",
+            PasteFormat::Hex => "Motif hexadécimal",
+            PasteFormat::Binary => "Chaîne binaire",
+            PasteFormat::HexFloat => "Flottant hexadécimal",
+        },
+        Text::DecimalEncoding(DecimalEncoding::Bid) => "BID (entier binaire)",
+        Text::DecimalEncoding(DecimalEncoding::Dpd) => "DPD (décimal densément empaqueté)",
+        Text::Unrepresentable(reason) => match reason {
+            Unrepresentable::NotFinite => "les entiers n'ont ni infinis ni NaN",
+            Unrepresentable::Fractional => "ce n'est pas un nombre entier",
+            Unrepresentable::OutOfRange => "c'est hors de la plage de l'entier",
+        },
+        Text::NonCanonical(kind) => match kind {
+            NonCanonical::PseudoDenormal => "Le champ d'exposant est nul mais le bit de tête est à 1",
+            NonCanonical::Unnormal => "Le champ d'exposant n'est pas nul mais le bit de tête est à 0",
+            NonCanonical::PseudoInfinity => {
+                "Le champ d'exposant est tout à 1 et la mantisse tout à 0, bit de tête compris"
+            }
+            NonCanonical::PseudoNan => {
+                "Le champ d'exposant est tout à 1 et le bit de tête est à 0, mais pas le reste de \
+                 la mantisse"
+            }
+        },
+        Text::Button(button) => match button {
+            Button::TimeTravel => "Voyager dans le temps",
+            Button::StopTimeTravel => "Arrêter le voyage dans le temps",
+            Button::Record => "Enregistrer",
+            Button::StopRecording => "Arrêter l'enregistrement",
+            Button::Rewind => "Rembobiner",
+            Button::Step => "Pas",
+            Button::Play => "Lecture",
+            Button::Pause => "Pause",
+            Button::Stop => "Arrêter",
+            Button::CopyScript => "Copier le script",
+            Button::Close => "Fermer",
+            Button::ExportState => "Exporter ou importer l'état",
+            Button::HideState => "Masquer l'état",
+            Button::Copy => "Copier",
+            Button::Download => "Télécharger",
+            Button::DownloadSvg => "Télécharger en SVG",
+            Button::FlipSignBit => "Inverser le bit de signe",
+            Button::Load => "Charger",
+            Button::LoadResult => "Charger le résultat",
+            Button::LoadOne => "Charger 1.0",
+            Button::LoadOnePlusUlp => "Charger 1.0 + ulp",
+            Button::Use => "Utiliser",
+            Button::UseCurrent => "Utiliser la valeur actuelle",
+            Button::CopyCode => "Copier le code",
+            Button::CopyTestVectors => "Copier les vecteurs de test",
+            Button::Previous => "Précédent",
+            Button::Next => "Suivant",
+            Button::NextNan => "NaN suivant",
+            Button::Cancel => "Annuler",
+            Button::Dismiss => "Ignorer",
+            Button::Randomize => "Aléatoire",
+            Button::Fill(BitType::Sign) => "Remplir le signe",
+            Button::Fill(BitType::Exponent) => "Remplir l'exposant",
+            Button::Fill(BitType::Significand) => "Remplir la mantisse",
+            Button::Clear(BitType::Sign) => "Vider le signe",
+            Button::Clear(BitType::Exponent) => "Vider l'exposant",
+            Button::Clear(BitType::Significand) => "Vider la mantisse",
+            Button::ClearAll => "Tout vider",
+            Button::CopyFormat(PasteFormat::Decimal) => "Copier en décimal",
+            Button::CopyFormat(PasteFormat::Hex) => "Copier le motif hexadécimal",
+            Button::CopyFormat(PasteFormat::Binary) => "Copier la chaîne binaire",
+            Button::CopyFormat(PasteFormat::HexFloat) => "Copier le flottant hexadécimal",
+            Button::PreviousValue => "\u{2190} Valeur précédente",
+            Button::NextValue => "Valeur suivante \u{2192}",
+            Button::SwapOperands => "Échanger a et b",
+            Button::UseAsA => "Utiliser comme a",
+            Button::ClearFlags => "Effacer les indicateurs",
+            Button::ClearField(BitType::Sign) => "Vider le champ de signe",
+            Button::ClearField(BitType::Exponent) => "Vider le champ d'exposant",
+            Button::ClearField(BitType::Significand) => "Vider le champ de mantisse",
+            Button::Restore => "Restaurer",
+            Button::Discard => "Abandonner",
+            Button::WalkThrough => "Suivre la conversion pas à pas",
+            Button::Practise => "S'entraîner à coder et lire des valeurs",
+            Button::Check => "Vérifier",
+            Button::LoadAnswer => "Charger la réponse",
+            Button::RevealAnswer => "Révéler la réponse",
+            Button::NextQuestion => "Question suivante",
+            Button::StopPractising => "Arrêter l'entraînement",
+            Button::PreviousStep => "Étape précédente",
+            Button::NextStep => "Étape suivante",
+        },
+        Text::Label(label) => match label {
+            Label::Sign => "Signe",
+            Label::Exponent => "Exposant",
+            Label::Fraction => "Fraction",
+            Label::Integer => "Entier",
+            Label::Regime => "Régime",
+            Label::Combination => "Combinaison",
+            Label::ExponentContinuation => "Suite de l'exposant",
+            Label::TrailingSignificand => "Mantisse de queue",
+            Label::Coefficient => "Coefficient",
+            Label::SvgTitle => "La rangée de bits en image, pour des diapositives",
+            Label::ResetTitle => "Tout est conservé d'une visite à l'autre jusqu'à réinitialisation",
+            Label::RecordTitle => "Enregistrer les modifications pour les rejouer plus tard",
+            Label::LoadRecording => "Charger un enregistrement",
+            Label::StateHint => {
+                "Modifiez le JSON ou collez-en un autre, puis cliquez ailleurs pour l'appliquer"
+            }
+            Label::NoFlags => "aucun indicateur",
+            Label::NoResultNoNans => "pas de résultat : ce format n'a pas de NaN",
+            Label::PrecisionP => "Précision p",
+            Label::MachineEpsilon => "Epsilon machine",
+            Label::LargestFinite => "Plus grand fini",
+            Label::SmallestNormal => "Plus petit normal",
+            Label::SmallestSubnormal => "Plus petit sous-normal",
+            Label::FiniteEncodings => "Encodages finis",
+            Label::None => "aucun",
+            Label::OverflowsToInfinity => "Dépasse vers l'infini à partir de",
+            Label::OverflowsToNan => "Dépasse vers NaN à partir de",
+            Label::Saturates => "Sature à la plus grande valeur finie à partir de",
+            Label::BecomesSubnormal => "Devient sous-normal en dessous de",
+            Label::NoNormalValues => "Aucune valeur normale avec un seul bit d'exposant",
+            Label::UnderflowsToZero => "Sous-dépasse vers zéro jusqu'à",
+            Label::NotRepresentable => "non représentable",
+            Label::Format => "Format",
+            Label::PrecisionDigits => "Précision (chiffres décimaux)",
+            Label::RangeDecades => "Plage (décades)",
+            Label::LargestExponentAxis => "Plus grand exposant \u{2192}",
+            Label::DecimalDigitsAxis => "Chiffres décimaux \u{2192}",
+            Label::TotalBits => "Bits au total",
+            Label::SmallestMagnitude => "Plus petite magnitude",
+            Label::LargestMagnitude => "Plus grande magnitude",
+            Label::BitSplit => "Répartition",
+            Label::DecimalDigits => "Chiffres décimaux",
+            Label::CoversRange => "Couvre la plage",
+            Label::CoversYes => "oui",
+            Label::CoversWithSubnormals => "seulement avec les sous-normaux",
+            Label::CoversNoOverflows => "non, dépassement",
+            Label::CoversNoUnderflows => "non, sous-dépassement",
+            Label::CompareFixedPoint => "Comparer avec la virgule fixe",
+            Label::FractionBits => "Bits de fraction",
+            Label::SignedTwosComplement => "Signé (complément à deux)",
+            Label::ComparePosit => "Comparer avec un posit",
+            Label::PositEs => "Bits d'exposant, es",
+            Label::CompareTwosComplement => "Comparer avec un entier en complément à deux",
+            Label::NotSet => "non défini",
+            Label::LinearInValue => "\u{25cf} linéaire en valeur",
+            Label::LinearInBits => "\u{25cf} linéaire en bits",
+            Label::NegativeInvert => "Négatif : tous les bits sont inversés.",
+            Label::PositiveSetSign => "Positif : le bit de signe est mis à 1.",
+            Label::Bits => "Bits",
+            Label::Sortable => "Triable",
+            Label::Value => "Valeur",
+            Label::SortableKey => "Clé de tri",
+            Label::Pass => "réussi",
+            Label::NotChecked => "non vérifié",
+            Label::NaiveSum => "Somme naïve",
+            Label::KahanSum => "Somme de Kahan",
+            Label::Compensation => "Compensation",
+            Label::ExactSum => "Somme exacte",
+            Label::CorrectlyRounded => "Arrondi correct",
+            Label::Error => "Erreur",
+            Label::NotFinite => "non fini",
+            Label::BigEndian => "gros-boutiste",
+            Label::GroupInFours => "Grouper par quatre",
+            Label::ImplicitBitTitle => "Le bit de tête implicite, qui n'est pas stocké",
+            Label::StoredExponent => "Exposant stocké",
+            Label::UnbiasedExponent => "Exposant non biaisé",
+            Label::LeadingBit => "Bit de tête",
+            Label::SignificandInteger => "Mantisse en entier",
+            Label::SignificandFraction => "Mantisse en fraction",
+            Label::NoUnbiasedExponent => "aucun : cet encodage représente l'infini ou NaN",
+            Label::LeadingImplicitOne => "1 (implicite, car l'exposant stocké n'est pas nul)",
+            Label::LeadingImplicitZero => "0 (implicite, car l'exposant stocké est nul)",
+            Label::LeadingStoredOne => "1 (stocké)",
+            Label::LeadingStoredZero => "0 (stocké)",
+            Label::PlayStepsTitle => {
+                "Ajouter un aux bits encore et encore jusqu'à l'infini ou NaN"
+            }
+            Label::StepTitle => "Ajouter un aux bits",
+            Label::NoValueBeyond => "Il n'y a aucune valeur représentable au-delà de celle-ci",
+            Label::EqualEncodingsDiffer => "a == b, bien que les encodages diffèrent",
+            Label::Unordered => {
+                "a et b ne sont pas ordonnés, car l'un est un NaN, donc même a == a est faux"
+            }
+            Label::NoResultFormatNoNans => "Pas de résultat, car le format n'a pas de NaN",
+            Label::SetBFromDecimal => "Définir b depuis un décimal",
+            Label::Raised => "levé",
+            Label::Clear => "baissé",
+            Label::TypingHexHint => {
+                "Tapez des chiffres hexadécimaux pour remplacer, les flèches pour vous déplacer, \
+                 Échap pour terminer"
+            }
+            Label::ClickHexHint => "Cliquez sur un chiffre pour le remplacer",
+            Label::ExplainBits => "Expliquer les bits au lieu de les inverser",
+            Label::ReservedBoth => "un exposant tout à 1 encode l'infini ou NaN",
+            Label::ReservedInfinity => "seul l'encodage tout à 1 est l'infini",
+            Label::ReservedNan => "seul l'encodage tout à 1 est NaN",
+            Label::ReservedNone => "aucun encodage n'est réservé à l'infini ou à NaN",
+            Label::SignalingNan => "NaN signalant : le bit de silence est à 0",
+            Label::QuietNan => "NaN silencieux : le bit de silence est à 1",
+            Label::ExactSingleOne => "exact, car la mantisse n'a qu'un seul bit à 1",
+            Label::ExactFractionZeros => "exact, car les bits de fraction sont tous nuls",
+            Label::RoundedNotPowerOfTwo => "arrondi, car la valeur n'est pas une puissance de deux",
+            Label::ExactPowerOfTen => "exact, car la valeur est une puissance de dix",
+            Label::RoundedNotPowerOfTen => {
+                "arrondi : seuls 1, 10, 100, etc. ont des logarithmes entiers, car les puissances \
+                 de dix plus petites ne sont pas représentables en binaire"
+            }
+            Label::SetFromDecimal => "Définir depuis un décimal",
+            Label::Hex => "Hexadécimal",
+            Label::Binary => "Binaire",
+            Label::HexFloat => "Flottant hexadécimal",
+            Label::WhatValue => "Quelle valeur ces bits encodent-ils ?",
+            Label::FormatChanged => {
+                "Le format a changé depuis que la question a été posée, demandez-en une autre."
+            }
+            Label::Rounded => "arrondi",
+            Label::Exact => "exact",
+            Label::SplitParts => "Séparer",
+            Label::IntegerPart => "Partie entière",
+            Label::FractionPart => "Partie fractionnaire",
+            Label::Normalize => "Normaliser",
+            Label::BiasTheExponent => "Biaiser l'exposant",
+            Label::Round => "Arrondir",
+        },
+        Text::Sentence(sentence) => match sentence {
+            Sentence::RecordingOneStep => "Enregistrement : 1 étape",
+            Sentence::RecordingSteps => "Enregistrement : {} étapes",
+            Sentence::StepOf => "Étape {} sur {}",
+            Sentence::ZerosDiffer => {
+                "La seule différence entre +0 et \u{2212}0 est le bit de signe, qui vaut \
+                 actuellement {}."
+            }
+            Sentence::ZerosCompareEqual => "{} == {} vaut {} : les deux zéros sont égaux.",
+            Sentence::Reciprocals => {
+                "Pourtant 1 / {} = {} alors que 1 / {} = {}, et sign({}) = {}."
+            }
+            Sentence::DefaultNan => {
+                "Chacune donne le NaN silencieux par défaut {} ({}) et lève {}."
+            }
+            Sentence::NoNansStillRaise => {
+                "Ce format n'a pas de NaN, donc elles n'ont pas de résultat, mais elles lèvent \
+                 quand même {}."
+            }
+            Sentence::InfinityMinusInfinity => {
+                "L'infini tient lieu de toute valeur trop grande pour être représentée, donc la \
+                 différence de deux infinis pourrait être n'importe quoi entre \u{2212}\u{221e} \
+                 et +\u{221e}. Un dépassement signifie que la réponse est connue mais trop \
+                 grande ; ici il n'y a aucune réponse unique."
+            }
+            Sentence::ZeroTimesInfinity => {
+                "Zéro peut résulter d'un sous-dépassement et l'infini d'un dépassement, donc leur \
+                 produit pourrait être n'importe quelle valeur."
+            }
+            Sentence::InfinityOverInfinity => {
+                "Le rapport de deux valeurs toutes deux trop grandes pour être représentées \
+                 dépend entièrement de ces valeurs, il est donc indéfini."
+            }
+            Sentence::SqrtOfNegative => {
+                "Aucun nombre réel n'a un carré négatif. Le résultat n'est pas grand, il est \
+                 simplement hors des nombres réels."
+            }
+            Sentence::PrecisionBits => "{} bits (\u{2248}{} chiffres décimaux)",
+            Sentence::FiniteOf => "{} sur 2^{} ({} %)",
+            Sentence::NextAfterOne => {
+                "La valeur suivant 1.0 est {}, donc ulp(1.0) = {} = 2^{}."
+            }
+            Sentence::EachBinade => {
+                "Avec {} bits de mantisse stockés (précision p = {}), chaque binade contient 2^{} \
+                 valeurs régulièrement espacées, donc l'écart en un est 2^(1 \u{2212} p). C'est \
+                 l'epsilon machine ; les valeurs à moins de sa moitié de 1.0 sont arrondies à \
+                 1.0."
+            }
+            Sentence::NotSmallest => "Ce n'est pas la plus petite valeur positive, qui est {}.",
+            Sentence::Custom => "Personnalisé ({}/{})",
+            Sentence::Current => "Actuel ({}/{})",
+            Sentence::FloatingPoint => "Virgule flottante ({}/{})",
+            Sentence::FixedPoint => "Virgule fixe ({})",
+            Sentence::CompareDecimal => "Comparer avec decimal{}",
+            Sentence::DecimalOf => "decimal{} ({} chiffres)",
+            Sentence::TwosComplementInteger => "i{} (complément à deux)",
+            Sentence::DensityGaps => {
+                "Les valeurs voisines sont espacées de {} près de zéro, mais de {} tout en haut."
+            }
+            Sentence::TradeoffPoint => "{}/{} : plus grand exposant {}, {} chiffres décimaux",
+            Sentence::EnterWidth => "Saisissez une largeur totale comprise entre 3 et {} bits.",
+            Sentence::NotRepresentable => "Non représentable : {}",
+            Sentence::PositNegation => {
+                "Les bits des posits négatifs sont pris en complément à deux avant la lecture de \
+                 leurs champs."
+            }
+            Sentence::InterpolateFrom => "De {} ",
+            Sentence::InterpolateTo => "À {} ",
+            Sentence::BitsAreLogarithms => {
+                "Au sein d'une binade, les bits croissent linéairement avec la valeur, mais \
+                 chaque pas de l'exposant double l'écart, donc le motif de bits lu comme un entier \
+                 est à peu près un log2 de la valeur, mis à l'échelle et décalé. Interpoler les \
+                 bits interpole donc la valeur géométriquement, ce qui est l'astuce derrière \
+                 l'estimation initiale de la racine carrée inverse rapide."
+            }
+            Sentence::SortableKeys => {
+                "Les valeurs négatives diminuent quand leur magnitude croît, donc inverser leurs \
+                 bits inverse leur ordre et les place sous toute valeur positive. Comparer les \
+                 résultats comme des entiers non signés ordonne alors les flottants \
+                 numériquement (avec \u{2212}0 juste sous +0 et les NaN aux extrémités), si bien \
+                 que les flottants peuvent être triés par base un octet à la fois."
+            }
+            Sentence::OrdinalOf => " sur {} de \u{2212}{} à {}",
+            Sentence::PasteTestFloat => {
+                "Collez la sortie de, par exemple, testfloat_gen f{}_{} pour ce format de {} bits."
+            }
+            Sentence::Mismatch => "écart : l'application donne {} avec les indicateurs {}",
+            Sentence::SummationIntro => {
+                "Saisissez des valeurs à additionner dans ce format, séparées par des espaces, \
+                 des virgules ou des retours à la ligne. L'algorithme de Kahan garde les bits de \
+                 poids faible que perd chaque addition dans un terme de compensation et les \
+                 rajoute à la valeur suivante."
+            }
+            Sentence::RoundedFrom => "Arrondi à partir du {} saisi",
+            Sentence::DilemmaIntro => {
+                "Arrondir f(x) correctement demande d'en calculer assez de bits pour savoir de \
+                 quel côté d'une frontière d'arrondi il se trouve. Quelques bits après le bit \
+                 d'arrondi suffisent d'ordinaire, mais pour ces entrées les bits qui le suivent \
+                 restent identiques très longtemps, si bien qu'une bibliothèque doit travailler \
+                 bien plus précisément que le format pour les arrondir."
+            }
+            Sentence::BitsAlike => "{} bits identiques après le bit d'arrondi",
+            Sentence::NearMidpoint => {
+                "Après le bit d'arrondi, {} bits d'affilée valent {}, donc le résultat exact est \
+                 à moins de 2^\u{2212}{} ulp du milieu entre deux valeurs voisines, où l'arrondi \
+                 au plus proche change de sens."
+            }
+            Sentence::NearValue => {
+                "Après le bit d'arrondi, {} bits d'affilée valent {}, donc le résultat exact est \
+                 à moins de 2^\u{2212}{} ulp d'une valeur du format, où les arrondis dirigés \
+                 changent de sens."
+            }
+            Sentence::OpenDataFile => "Ouvrez un fichier .npy ou un tableau brut de flottants.",
+            Sentence::NoWholeElements => "Le fichier n'a aucun élément complet de ce type.",
+            Sentence::ElementOf => "Élément {} sur {} ({})",
+            Sentence::PasteAs => "Coller « {} » comme : ",
+            Sentence::Copied => "{} : copié",
+            Sentence::ImplicitBitAria => "bit de tête implicite, valeur {}",
+            Sentence::FieldOfOneBit => "champ {}, 1 bit",
+            Sentence::FieldOfBits => "champ {}, {} bits",
+            Sentence::BitAria => "{} : bit {}, valeur {}",
+            Sentence::SubnormalExponent => {
+                "{} (les sous-normaux partagent l'exposant des plus petites valeurs normales, 1 \
+                 \u{2212} biais)"
+            }
+            Sentence::NoFormulaNan => {
+                "Aucune formule ne donne NaN : le format lui réserve cet encodage."
+            }
+            Sentence::NoFormulaInfinity => {
+                "Aucune formule ne donne l'infini : le format lui réserve cet encodage."
+            }
+            Sentence::RawBits => "Bits bruts {}",
+            Sentence::OneUlp => "Ici, un ulp vaut 2^{} \u{2248} {}",
+            Sentence::BinadeValues => "Il y a 2^{}{} valeurs {}, toutes espacées ainsi",
+            Sentence::FromZeroTo => "de 0 à 2^{}",
+            Sentence::FromTo => "de 2^{} à 2^{}",
+            Sentence::StepsApart => {
+                "Elles sont séparées de {} pas dans la numérotation ordinale du format."
+            }
+            Sentence::RoundedWith => {
+                "Le résultat exact n'est pas dans le format, il a donc été arrondi avec {}. "
+            }
+            Sentence::ResultExact => "Le résultat est exact. ",
+            Sentence::PaddedWith => "Complété par {} bits nuls au-dessus du bit de signe",
+            Sentence::SignBitRole => {
+                "Le bit de signe : 0 pour positif et 1 pour négatif. L'inverser donne l'opposé de \
+                 la valeur, zéros, infinis et NaN compris."
+            }
+            Sentence::ExponentBitRole => {
+                "Bit d'exposant valant {} dans l'exposant stocké, dont le biais est {}. Augmenter \
+                 l'exposant de un double une valeur normale ; {}."
+            }
+            Sentence::ExplicitLeadingBitRole => {
+                "Le bit de tête stocké explicitement : 1 pour les valeurs normales et 0 pour les \
+                 sous-normales. Sans lui, l'exposant seul devrait dire laquelle est laquelle."
+            }
+            Sentence::SignificandBitRole => {
+                "Bit de mantisse valant 2^-{} du bit de tête, c'est-à-dire qu'il ajoute cette \
+                 fraction de la puissance de deux donnée par l'exposant."
+            }
+            Sentence::FlipWouldChange => {
+                "L'inverser changerait la valeur de {} à {} (une variation de {})."
+            }
+            Sentence::RestorePrompt => "Restaurer {} ({}) de votre dernière visite ?",
+            Sentence::NanPayload => "{}, et la charge utile est {}",
+            Sentence::ClassTitle => "{}, fpclassify donne {}",
+            Sentence::TooManyDigits => {
+                "Cette valeur a plus de {} chiffres décimaux, trop pour les afficher."
+            }
+            Sentence::NumeratorTooLong => {
+                "Le numérateur a plus de {} chiffres décimaux, trop pour les afficher."
+            }
+            Sentence::ForExample => "p. ex. {}",
+            Sentence::AskBuildBits => "Réglez les bits ci-dessus pour encoder {}.",
+            Sentence::QuizProgress => "Question {}. {} résolues sur {} jusqu'ici.",
+            Sentence::WrongOnce => {
+                "Pas tout à fait. 1 tentative jusqu'ici ; réessayez ou révélez la réponse."
+            }
+            Sentence::Wrong => {
+                "Pas tout à fait. {} tentatives jusqu'ici ; réessayez ou révélez la réponse."
+            }
+            Sentence::RightFirstTime => "Exact, du premier coup.",
+            Sentence::Right => "Exact, en {} tentatives.",
+            Sentence::TheAnswer => "La réponse est {} = {}.",
+            Sentence::AnswerNegative => "Le bit de signe est 1, donc la valeur est négative.",
+            Sentence::AnswerPositive => "Le bit de signe est 0, donc la valeur est positive.",
+            Sentence::AnswerSubnormal => {
+                "Le champ d'exposant vaut 0, donc la valeur est sous-normale et multipliée par \
+                 2^(1 \u{2212} {}) = 2^{}."
+            }
+            Sentence::AnswerScaled => {
+                "Le champ d'exposant vaut {}, donc la valeur est multipliée par 2^({} \u{2212} {}) \
+                 = 2^{}."
+            }
+            Sentence::AnswerSignificand => "La mantisse est {}.{} en binaire, soit {}.",
+            Sentence::AnswerTogether => "Ensemble, ils donnent {}{} \u{d7} 2^{} = {}.",
+            Sentence::StoredExactly => "{} est stocké exactement.",
+            Sentence::OffBy => "{} ne peut pas être stocké exactement. La valeur stockée s'en écarte de {}.",
+            Sentence::ErrorTooLong => {
+                "{} ne peut pas être stocké exactement, et l'erreur a plus de {} chiffres."
+            }
+            Sentence::TypedNegative => "{} est négatif, donc le bit de signe est 1.",
+            Sentence::TypedPositive => "{} est positif, donc le bit de signe est 0.",
+            Sentence::IntegerPartIs => "Partie entière : {}",
+            Sentence::FractionPartIs => "Partie fractionnaire : {}",
+            Sentence::PartsSeparately => "Chaque partie est convertie en binaire séparément.",
+            Sentence::Halving => "{} \u{f7} 2 = {} reste {}",
+            Sentence::MoreHalvings => "\u{2026} et {} divisions de plus",
+            Sentence::IntegerZero => {
+                "La partie entière vaut 0, elle n'ajoute donc aucun chiffre binaire."
+            }
+            Sentence::Remainders => "Les restes, du dernier au premier, donnent {} en binaire.",
+            Sentence::Doubling => "{} \u{d7} 2 = {} \u{2192} {}",
+            Sentence::MoreDoublings => "\u{2026} et {} doublements de plus",
+            Sentence::FractionZero => {
+                "La partie fractionnaire vaut 0, elle n'ajoute donc aucun chiffre binaire."
+            }
+            Sentence::IntegerHoldsAll => {
+                "La partie entière contient déjà tous les bits qui peuvent être gardés, donc la \
+                 partie fractionnaire ne compte que pour l'arrondi."
+            }
+            Sentence::FractionExact => {
+                "Il ne reste rien à doubler, donc la partie fractionnaire vaut exactement 0.{} en \
+                 binaire."
+            }
+            Sentence::FractionForever => {
+                "Les doublements ne finiraient jamais, mais les bits qui peuvent être gardés et le \
+                 suivant sont connus : 0.{}\u{2026} en binaire."
+            }
+            Sentence::Together => "Ensemble : {}.{}{} en binaire.",
+            Sentence::LeadingTooLarge => {
+                "Le 1 de tête vaut 2^{}, au-delà de 2^{} au bas de la plus grande binade, donc la \
+                 valeur est trop grande pour le format."
+            }
+            Sentence::MoveNowhere => {
+                "Sans déplacer la virgule, elle est déjà juste après le 1 de tête : {}\u{2026} \
+                 \u{d7} 2^{}."
+            }
+            Sentence::MoveOneLeft => {
+                "Déplacer la virgule d'un rang vers la gauche la place juste après le 1 de tête : \
+                 {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveOneRight => {
+                "Déplacer la virgule d'un rang vers la droite la place juste après le 1 de tête : \
+                 {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveLeft => {
+                "Déplacer la virgule de {} rangs vers la gauche la place juste après le 1 de \
+                 tête : {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::MoveRight => {
+                "Déplacer la virgule de {} rangs vers la droite la place juste après le 1 de \
+                 tête : {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::LeadingSubnormal => {
+                "Le 1 de tête vaut 2^{}, sous 2^{} au bas de la plus petite binade normale, donc la \
+                 valeur est sous-normale : {}\u{2026} \u{d7} 2^{}."
+            }
+            Sentence::ZeroHasNoLeading => {
+                "Zéro n'a pas de 1 de tête, et tous les bits de sa mantisse sont 0."
+            }
+            Sentence::NoOneBit => {
+                "Il n'y a aucun bit à 1 jusqu'à 2^{}, le premier bit sous le plus petit \
+                 sous-normal, donc seul l'arrondi peut empêcher la valeur d'être nulle."
+            }
+            Sentence::LeadingStored => {
+                "Le bit avant la virgule est stocké, comme bit de tête du champ de mantisse."
+            }
+            Sentence::LeadingImplied => {
+                "Le bit avant la virgule n'est pas stocké, car le champ d'exposant l'implique."
+            }
+            Sentence::BiasOverflows => {
+                "{} + biais {} = {} est trop grand pour le champ d'exposant d'une valeur finie."
+            }
+            Sentence::OverflowsTo => "La valeur dépasse vers {}.",
+            Sentence::ExponentStores => "Le champ d'exposant stocke {} + biais {} = {}.",
+            Sentence::InBits => "Soit {} sur {} bits.",
+            Sentence::ExponentZeros => {
+                "Sans 1 de tête à 2^{} ou au-dessus, le champ d'exposant est tout à 0, ce qui \
+                 représente 2^{} comme le plus petit exposant normal."
+            }
+            Sentence::Keeping => {
+                "Garder {} bits de mantisse laisse {}, avec le bit d'arrondi {} et le bit \
+                 collant {}."
+            }
+            Sentence::RoundsUp => {
+                "Ils ne sont pas tous deux nuls, donc la valeur est inexacte, et {} arrondit sa \
+                 magnitude vers le haut."
+            }
+            Sentence::RoundsDown => {
+                "Ils ne sont pas tous deux nuls, donc la valeur est inexacte, et {} arrondit sa \
+                 magnitude vers le bas."
+            }
+            Sentence::CarryOverflows => {
+                "L'arrondi vers le haut dépasse la plus grande valeur finie, donc la valeur \
+                 déborde."
+            }
+            Sentence::CarryIntoExponent => {
+                "L'arrondi vers le haut propage une retenue hors de la mantisse et dans le champ \
+                 d'exposant."
+            }
+            Sentence::NothingLost => {
+                "Ils sont tous deux nuls, donc rien n'est perdu et la valeur est stockée \
+                 exactement."
+            }
+            Sentence::Result => "Résultat : {} = {}",
+        },
+    }
+}
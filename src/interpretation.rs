@@ -0,0 +1,251 @@
+//! Other ways of reading the same bits.
+
+use crate::{bignum::BigUint, encoding::Encoding, text::format_value};
+use std::iter;
+
+/// A Qm.n fixed-point interpretation of the same bits.
+#[derive(Copy, Clone)]
+pub struct FixedPoint {
+    /// Number of bits after the binary point.
+    pub fraction_bits: usize,
+    /// Whether the bits are a two's complement number rather than unsigned.
+    pub signed: bool,
+}
+
+impl FixedPoint {
+    pub fn value(self, encoding: &Encoding) -> f64 {
+        #[allow(clippy::cast_precision_loss, clippy::cast_possible_wrap)]
+        let raw = if self.signed {
+            encoding.to_signed_bits() as f64
+        } else {
+            encoding.to_bits() as f64
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let fraction_bits = self.fraction_bits as i32;
+        raw * f64::from(-fraction_bits).exp2()
+    }
+
+    /// The Q notation for this interpretation of `width` bits, e.g. `Q3.12`.
+    pub fn name(self, width: usize) -> String {
+        let integer_bits = width - self.fraction_bits - usize::from(self.signed);
+        format!(
+            "{}Q{}.{}",
+            if self.signed { "" } else { "U" },
+            integer_bits,
+            self.fraction_bits
+        )
+    }
+}
+
+/// Largest number of exponent bits offered for posits. The 2022 posit standard fixes it at 2.
+pub const MAX_POSIT_ES: usize = 4;
+
+/// A posit (type III unum) interpretation of the same bits, with the encoding's width as
+/// `nbits`.
+#[derive(Copy, Clone)]
+pub struct Posit {
+    /// Number of exponent bits after the regime, `es` in the posit literature.
+    pub es: usize,
+}
+
+/// The fields of a posit, which unlike those of a float vary in width with the value.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PositField {
+    Sign,
+    /// A run of identical bits and the opposite bit which ends it.
+    Regime,
+    Exponent,
+    Fraction,
+}
+
+impl PositField {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sign => "Sign",
+            Self::Regime => "Regime",
+            Self::Exponent => "Exponent",
+            Self::Fraction => "Fraction",
+        }
+    }
+}
+
+impl Posit {
+    /// The bits read as a posit, with those of negative posits negated in two's complement as
+    /// they are before decoding, each with the field it falls in. The sign bit is kept as
+    /// stored.
+    pub fn fields(self, encoding: &Encoding) -> Vec<(bool, PositField)> {
+        let bits = Self::magnitude_bits(encoding);
+        let regime_len = Self::regime_len(&bits);
+        let rest = bits.len() - 1 - regime_len;
+        let exponent_len = self.es.min(rest);
+        iter::once((encoding.sign_bit, PositField::Sign))
+            .chain(bits[1..].iter().enumerate().map(|(i, &bit)| {
+                let field = if i < regime_len {
+                    PositField::Regime
+                } else if i < regime_len + exponent_len {
+                    PositField::Exponent
+                } else {
+                    PositField::Fraction
+                };
+                (bit, field)
+            }))
+            .collect()
+    }
+
+    /// The value of the posit, or NaN for `NaR` ("not a real"), its one exceptional encoding.
+    pub fn value(self, encoding: &Encoding) -> f64 {
+        let stored: Vec<bool> = encoding.bits().collect();
+        if !stored[1..].iter().any(|&b| b) {
+            return if encoding.sign_bit { f64::NAN } else { 0.0 };
+        }
+        let bits = Self::magnitude_bits(encoding);
+        let regime_len = Self::regime_len(&bits);
+        // A run of m ones is worth k = m - 1, and a run of m zeros k = -m.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let run = bits[1..].iter().take_while(|&&b| b == bits[1]).count() as i32;
+        let k = if bits[1] { run - 1 } else { -run };
+        let after_regime = &bits[1 + regime_len..];
+        let exponent_len = self.es.min(after_regime.len());
+        // Exponent bits cut off by the end of the posit count as zeros.
+        let exponent = after_regime[..exponent_len]
+            .iter()
+            .fold(0_i32, |acc, &b| (acc << 1) | i32::from(b))
+            << (self.es - exponent_len);
+        let fraction = after_regime[exponent_len..]
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &b| (acc + f64::from(u8::from(b))) / 2.0);
+        let scale = k * (1 << self.es) + exponent;
+        let magnitude = (1.0 + fraction) * f64::from(scale).exp2();
+        if encoding.sign_bit {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// The bits of the encoding, negated in two's complement if the sign bit is set.
+    fn magnitude_bits(encoding: &Encoding) -> Vec<bool> {
+        let mut bits: Vec<bool> = encoding.bits().collect();
+        if encoding.sign_bit {
+            for bit in &mut bits {
+                *bit = !*bit;
+            }
+            for bit in bits.iter_mut().rev() {
+                *bit = !*bit;
+                if *bit {
+                    break;
+                }
+            }
+        }
+        bits
+    }
+
+    /// Length of the regime of `bits`, counting the bit which ends its run if there is one.
+    fn regime_len(bits: &[bool]) -> usize {
+        let run = bits[1..].iter().take_while(|&&b| b == bits[1]).count();
+        (run + 1).min(bits.len() - 1)
+    }
+}
+
+/// Ways of reading the encoding, zero extended to 64 bits, like the members of a union.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Reinterpretation {
+    F64,
+    F32x2,
+    F16x4,
+    Bf16x4,
+    U64,
+    I64,
+}
+
+impl Reinterpretation {
+    pub const ALL: [Self; 6] = [
+        Self::F64,
+        Self::F32x2,
+        Self::F16x4,
+        Self::Bf16x4,
+        Self::U64,
+        Self::I64,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::F64 => "f64",
+            Self::F32x2 => "[f32; 2]",
+            Self::F16x4 => "[f16; 4]",
+            Self::Bf16x4 => "[bf16; 4]",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+        }
+    }
+
+    /// Decodes `bits`, listing lanes from most to least significant.
+    pub fn decode(self, bits: u64) -> Vec<String> {
+        let lanes = |exponent_len: usize, significand_len: usize| -> Vec<String> {
+            let width = 1 + exponent_len + significand_len;
+            (0..64 / width)
+                .rev()
+                .map(|lane| {
+                    let lane_bits = (bits >> (lane * width)) & ((1 << width) - 1);
+                    format_value(
+                        Encoding::from_bits(exponent_len, significand_len, lane_bits).value(),
+                    )
+                })
+                .collect()
+        };
+        match self {
+            Self::F64 => vec![format_value(f64::from_bits(bits))],
+            Self::F32x2 => lanes(8, 23),
+            Self::F16x4 => lanes(5, 10),
+            Self::Bf16x4 => lanes(8, 7),
+            Self::U64 => vec![bits.to_string()],
+            #[allow(clippy::cast_possible_wrap)]
+            Self::I64 => vec![(bits as i64).to_string()],
+        }
+    }
+}
+
+/// Why a value has no two's complement encoding as wide as its float encoding.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Unrepresentable {
+    NotFinite,
+    Fractional,
+    OutOfRange,
+}
+
+impl Unrepresentable {
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::NotFinite => "integers have no infinities or NaNs",
+            Self::Fractional => "it isn't a whole number",
+            Self::OutOfRange => "it is outside the integer's range",
+        }
+    }
+}
+
+/// The bits of the two's complement integer as wide as `encoding` with the same value, most
+/// significant first. Both zeros become the integer's only zero.
+pub fn twos_complement(encoding: &Encoding) -> Result<Vec<bool>, Unrepresentable> {
+    if encoding.is_nan() || encoding.is_infinite() {
+        return Err(Unrepresentable::NotFinite);
+    }
+    let (magnitude, denominator_log) = encoding.reduced_ratio();
+    if denominator_log != 0 {
+        return Err(Unrepresentable::Fractional);
+    }
+    let width = encoding.len();
+    let one = BigUint::from_u64(1);
+    // The range is -2^(width - 1) to 2^(width - 1) - 1.
+    let limit = one.shl(width - 1);
+    let negative = encoding.sign_bit && !magnitude.is_zero();
+    if magnitude > limit || (magnitude == limit && !negative) {
+        return Err(Unrepresentable::OutOfRange);
+    }
+    let bits = if negative {
+        one.shl(width).sub(&magnitude)
+    } else {
+        magnitude
+    };
+    Ok((0..width).rev().map(|i| bits.bit(i)).collect())
+}
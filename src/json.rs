@@ -0,0 +1,245 @@
+//! Just enough JSON to save and load the app's state. Numbers keep their text, so integers of
+//! any size survive the round trip.
+
+use std::{fmt, iter::Peekable, str::Chars};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Self>),
+    /// Members in the order they were written.
+    Object(Vec<(String, Self)>),
+}
+
+impl Json {
+    /// The member called `key`, if this is an object with one.
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// The value of a number written as a non-negative integer.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut chars = text.chars().peekable();
+        let value = parse_value(&mut chars)?;
+        skip_whitespace(&mut chars);
+        chars.next().map_or(Ok(value), |c| {
+            Err(format!("Unexpected '{}' after the end of the JSON", c))
+        })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let pad = |depth: usize| "  ".repeat(depth);
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Number(n) => write!(f, "{}", n),
+            Self::String(s) => write_string(f, s),
+            Self::Array(items) if items.is_empty() => write!(f, "[]"),
+            Self::Array(items) => {
+                writeln!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    write!(f, "{}", pad(indent + 1))?;
+                    item.write(f, indent + 1)?;
+                    writeln!(f, "{}", if i + 1 < items.len() { "," } else { "" })?;
+                }
+                write!(f, "{}]", pad(indent))
+            }
+            Self::Object(members) if members.is_empty() => write!(f, "{{}}"),
+            Self::Object(members) => {
+                writeln!(f, "{{")?;
+                for (i, (name, value)) in members.iter().enumerate() {
+                    write!(f, "{}", pad(indent + 1))?;
+                    write_string(f, name)?;
+                    write!(f, ": ")?;
+                    value.write(f, indent + 1)?;
+                    writeln!(f, "{}", if i + 1 < members.len() { "," } else { "" })?;
+                }
+                write!(f, "{}}}", pad(indent))
+            }
+        }
+    }
+}
+
+/// Indented two spaces a level, for reading and editing by hand.
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn expect(chars: &mut Peekable<Chars<'_>>, expected: char) -> Result<(), String> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(format!("Expected '{}' but found '{}'", expected, c)),
+        None => Err(format!("Expected '{}' but the JSON ended", expected)),
+    }
+}
+
+fn parse_value(chars: &mut Peekable<Chars<'_>>) -> Result<Json, String> {
+    skip_whitespace(chars);
+    match chars.peek().copied() {
+        Some('{') => {
+            chars.next();
+            let mut members = Vec::new();
+            skip_whitespace(chars);
+            if chars.peek() == Some(&'}') {
+                chars.next();
+                return Ok(Json::Object(members));
+            }
+            loop {
+                expect(chars, '"')?;
+                let name = parse_string(chars)?;
+                expect(chars, ':')?;
+                members.push((name, parse_value(chars)?));
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => {}
+                    Some('}') => return Ok(Json::Object(members)),
+                    _ => return Err("Expected ',' or '}' in an object".to_owned()),
+                }
+            }
+        }
+        Some('[') => {
+            chars.next();
+            let mut items = Vec::new();
+            skip_whitespace(chars);
+            if chars.peek() == Some(&']') {
+                chars.next();
+                return Ok(Json::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => {}
+                    Some(']') => return Ok(Json::Array(items)),
+                    _ => return Err("Expected ',' or ']' in an array".to_owned()),
+                }
+            }
+        }
+        Some('"') => {
+            chars.next();
+            parse_string(chars).map(Json::String)
+        }
+        Some(c) if c == '-' || c.is_ascii_digit() => {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_digit() || "+-.eE".contains(c)) {
+                    break;
+                }
+                number.push(c);
+                chars.next();
+            }
+            if number.parse::<f64>().is_err() {
+                return Err(format!("{} isn't a number", number));
+            }
+            Ok(Json::Number(number))
+        }
+        Some(c) if c.is_ascii_alphabetic() => {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_ascii_alphabetic() {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            match word.as_str() {
+                "null" => Ok(Json::Null),
+                "true" => Ok(Json::Bool(true)),
+                "false" => Ok(Json::Bool(false)),
+                _ => Err(format!("Unexpected word {}", word)),
+            }
+        }
+        Some(c) => Err(format!("Unexpected '{}'", c)),
+        None => Err("The JSON ended early".to_owned()),
+    }
+}
+
+/// The rest of a string whose opening quote has been read.
+fn parse_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => {
+                let c = match chars.next() {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('u') => {
+                        let digits: String = chars.by_ref().take(4).collect();
+                        u32::from_str_radix(&digits, 16)
+                            .ok()
+                            .and_then(std::char::from_u32)
+                            .ok_or_else(|| format!("\\u{} isn't a character", digits))?
+                    }
+                    Some(c) if "\"\\/".contains(c) => c,
+                    _ => return Err("Unknown escape in a string".to_owned()),
+                };
+                s.push(c);
+            }
+            Some(c) => s.push(c),
+            None => return Err("A string isn't closed".to_owned()),
+        }
+    }
+}
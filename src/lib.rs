@@ -1,14 +1,49 @@
 #![warn(rust_2018_idioms)]
 #![allow(clippy::wildcard_imports)]
 use seed::{prelude::*, *};
-use std::iter;
+use std::{convert::TryFrom, iter, mem};
 
-/// Number of **explicitly stored** significand bits for IEEE754 binary64.
-const BINARY_64_SIGNIFICAND_BITS: usize = 52;
+mod arithmetic;
+mod codegen;
+mod datafile;
+mod decimal;
+mod dilemma;
+mod embed;
+mod i18n;
+mod interpretation;
+mod json;
+mod quiz;
+mod recording;
+mod summation;
+mod svg;
+mod testfloat;
+#[cfg(test)]
+mod tests;
+mod text;
+mod view;
+mod walkthrough;
 
-const BINARY_64_EXPONENT_BITS: usize = 11;
-
-const BINARY_64_BIAS: usize = 1023;
+use arithmetic::{apply, calculate, Operator, UnaryOperator};
+use codegen::Language;
+use datafile::{DataFile, ElementType};
+use decimal::DecimalEncoding;
+use encoding::{
+    BitType, Encoding, Flags, FormatPreset, Options, Overflow, RoundingMode, SpecialValue,
+    MAX_BIAS, MAX_EXPONENT_BITS, MAX_SIGNIFICAND_BITS,
+};
+use i18n::Locale;
+use ieee754_core::{bignum, encoding};
+use interpretation::{FixedPoint, Posit, Reinterpretation};
+use json::Json;
+use quiz::Quiz;
+use recording::{Recording, Replay};
+use svg::Palette;
+use testfloat::Operation;
+use text::{
+    format_encoding, format_value, parse_decimal, parse_ordinal, parse_unsigned, PasteFormat,
+};
+use view::view;
+use walkthrough::Walkthrough;
 
 #[wasm_bindgen]
 extern "C" {
@@ -16,6 +51,10 @@ extern "C" {
     // `log(..)`
     #[wasm_bindgen(js_namespace = console)]
     fn log(s: &str);
+
+    // `navigator.clipboard.writeText(..)`, ignoring the returned promise
+    #[wasm_bindgen(js_namespace = ["navigator", "clipboard"], js_name = writeText)]
+    fn write_clipboard(s: &str);
 }
 
 // ------ ------
@@ -23,105 +62,801 @@ extern "C" {
 // ------ ------
 
 // `init` describes what should happen when your app started.
-fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
-    let exponent_len = 11;
-    let significand_len = 52;
-    Model {
-        sign_bit: false,
-        exponent_bits: vec![false; exponent_len],
-        significand_bits: vec![false; significand_len],
+fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+    orders.subscribe(Msg::UrlChanged);
+    orders.stream(streams::window_event(Ev::Paste, |event| {
+        let event: web_sys::ClipboardEvent = event.unchecked_into();
+        // Leave pastes into text fields alone.
+        if is_text_field(event.target()) {
+            return None;
+        }
+        let text = event.clipboard_data()?.get_data("text").ok()?;
+        Some(Msg::Paste(text))
+    }));
+    orders.stream(streams::window_event(Ev::KeyDown, |event| {
+        let event: web_sys::KeyboardEvent = event.unchecked_into();
+        // Text fields have undo of their own.
+        if !(event.ctrl_key() || event.meta_key())
+            || !event.key().eq_ignore_ascii_case("z")
+            || is_text_field(event.target())
+        {
+            return None;
+        }
+        event.prevent_default();
+        Some(if event.shift_key() {
+            Msg::Redo
+        } else {
+            Msg::Undo
+        })
+    }));
+    // Drags can end anywhere, not just over a bit.
+    orders.stream(streams::window_event(Ev::MouseUp, |_| Msg::EndDrag));
+    orders.stream(streams::window_event(Ev::BeforeUnload, |_| {
+        Msg::SaveSession
+    }));
+    let mut model = Model::new();
+    // The browser's language until one is chosen, which the saved session then restores.
+    if let Some(locale) = window()
+        .navigator()
+        .language()
+        .and_then(|tag| Locale::from_tag(&tag))
+    {
+        model.locale = locale;
+    }
+    if let Some(saved) = local_storage().and_then(|storage| storage.get_item(SESSION_KEY).ok()?) {
+        model.restore_session(&saved);
     }
+    // A shared link takes precedence over the value from the last visit.
+    if let Some(encoding) = url.hash().and_then(|hash| Encoding::from_fragment(hash)) {
+        model.start_from(encoding);
+    }
+    // The value as the page was closed is only offered if it isn't what was restored anyway,
+    // as when another tab saved later or the app was opened with a link.
+    model.restorable = local_storage()
+        .and_then(|storage| storage.get_item(SNAPSHOT_KEY).ok().flatten())
+        .and_then(|fragment| Encoding::from_fragment(&fragment))
+        .filter(|encoding| *encoding != model.encoding);
+    set_document_title(&model.encoding);
+    set_theme(model.theme);
+    set_language(model.locale);
+    embed::publish(&model.encoding);
+    // Give the initial history entry a fragment so that navigating back to it restores it, and
+    // tidy up the one from a shared link.
+    Url::current()
+        .set_hash(model.encoding.to_fragment())
+        .go_and_replace();
+    model
 }
 
 // ------ ------
 //     Model
 // ------ ------
 
+/// Maximum number of steps kept in the history panel.
+const HISTORY_LEN: usize = 50;
+
+/// Number of steps taken when animating between two bit patterns.
+const INTERPOLATION_FRAMES: u32 = 64;
+
+/// Milliseconds between frames of the interpolation animation.
+const INTERPOLATION_FRAME_MS: u32 = 100;
+
+/// Milliseconds between steps when playing through consecutive bit patterns.
+const STEPPING_FRAME_MS: u32 = 250;
+
+/// Milliseconds between the steps of a recording played on a timer, slow enough to talk over.
+const REPLAY_STEP_MS: u32 = 2000;
+
+/// Version written into exported state, to be raised if its layout ever changes incompatibly.
+const STATE_VERSION: usize = 1;
+
+/// Local storage key of the session, which is saved whenever it changes.
+const SESSION_KEY: &str = "ieee754_session";
+
+/// Local storage key of the value when the page was last closed, offered on the next visit.
+const SNAPSHOT_KEY: &str = "ieee754_snapshot";
+
+/// Milliseconds for which copying is confirmed.
+const COPIED_MS: u32 = 1500;
+
+/// Milliseconds of slider input gathered into a single resize, about one frame.
+const RESIZE_BATCH_MS: u32 = 16;
+
 // `Model` describes our app state.
 pub struct Model {
-    sign_bit: bool,
-    exponent_bits: Vec<bool>,
-    significand_bits: Vec<bool>,
+    encoding: Encoding,
+    theme: Theme,
+    locale: Locale,
+    colors: FieldColors,
+    /// Bits changed by the most recent `Msg`, indexed like `Encoding::bits()`.
+    flipped: Vec<bool>,
+    /// Alternates on every change so repeated flips restart the animation.
+    flip_parity: bool,
+    /// Every edit to the encoding, oldest first.
+    history: Vec<HistoryStep>,
+    /// Every `Msg` since time travel was enabled, or `None` if it is disabled.
+    timeline: Option<Timeline>,
+    /// Kind of the `Msg` which last added a browser history entry.
+    last_pushed: Option<mem::Discriminant<Msg>>,
+    /// Pasted text which needs the user's attention.
+    paste: Option<PasteState>,
+    /// Whether clicking a bit explains it rather than toggling it.
+    explain_mode: bool,
+    explained_bit: Option<usize>,
+    /// Bit under the mouse, whose toggled value is previewed.
+    hovered_bit: Option<usize>,
+    /// Value before the most recent edit, shown briefly next to the current one.
+    ghost: Option<f64>,
+    /// Alternates on every edit so the ghost's fade restarts.
+    ghost_parity: bool,
+    /// Inputs of the bit budget allocator.
+    allocator: Allocator,
+    /// Fixed-point interpretation shown alongside the float, or `None` if hidden.
+    fixed_point: Option<FixedPoint>,
+    /// Why the last typed unsigned integer couldn't be applied.
+    integer_error: Option<String>,
+    /// Interpretations shown in the reinterpretation panel.
+    reinterpretations: Vec<Reinterpretation>,
+    interpolation: Interpolation,
+    /// Why the last typed ordinal couldn't be applied.
+    ordinal_error: Option<String>,
+    /// Widths requested by slider input which hasn't been applied yet.
+    pending_layout: Option<(usize, usize)>,
+    /// Hex digit selected for overwriting by typing, counting from the most significant.
+    nibble_cursor: Option<usize>,
+    /// Value from the previous visit which the user can choose to restore.
+    restorable: Option<Encoding>,
+    /// Language of the generated bit manipulation code.
+    code_language: Language,
+    /// Pasted TestFloat vectors, parsed when rendering.
+    test_vectors: String,
+    /// Operation the pasted vectors test.
+    test_operation: Operation,
+    /// Values typed for the summation demo, parsed when rendering.
+    summands: String,
+    /// Binary file whose elements are being browsed.
+    data_file: Option<DataFile>,
+    data_error: Option<String>,
+    decimal_error: Option<String>,
+    hex_error: Option<String>,
+    binary_error: Option<String>,
+    hex_float_error: Option<String>,
+    bias_error: Option<String>,
+    /// Direction for rounding typed decimals and conversions into the format.
+    rounding: RoundingMode,
+    /// The last decimal typed, to show how it rounds in each direction.
+    decimal_input: Option<String>,
+    /// The second operand, in the same format as `encoding`, for comparisons.
+    second: Encoding,
+    second_error: Option<String>,
+    /// Operator last applied to the two operands, whose result follows them as they change.
+    operator: Option<Operator>,
+    /// The result of `operator`, or `None` if it has none in the format.
+    result: Option<(Encoding, Flags)>,
+    /// Exception flags raised by conversions and operations since they were last cleared.
+    flags: Flags,
+    /// Encodings before each edit, most recent last, for undo.
+    undo: Vec<Encoding>,
+    /// Encodings undone since the last edit, most recently undone last.
+    redo: Vec<Encoding>,
+    /// Format last copied to the clipboard, while the confirmation is showing.
+    copied: Option<PasteFormat>,
+    /// Bit which has, or last had, the keyboard focus, counting from the sign bit.
+    bit_cursor: usize,
+    /// Value being given to each bit dragged over, while the mouse button is held down.
+    drag: Option<bool>,
+    /// Whether the bits are spaced out in groups of four, as hex digits.
+    group_nibbles: bool,
+    /// Whether bytes are shown in little endian order, least significant first.
+    little_endian: bool,
+    /// Keeps the timer stepping through bit patterns running; dropping it stops the timer.
+    stepping: Option<StreamHandle>,
+    /// Posit interpretation compared with the float, if shown.
+    posit: Option<Posit>,
+    /// Encoding of the decimal format compared with the float, if shown.
+    decimal: Option<DecimalEncoding>,
+    /// Whether the value's two's complement integer encoding is shown next to the float's.
+    twos_complement: bool,
+    /// The conversion of the typed decimal being worked through, if any.
+    walkthrough: Option<Walkthrough>,
+    /// The practice session, if one is under way.
+    quiz: Option<Quiz>,
+    /// Edits made since recording started, or `None` if not recording.
+    recording: Option<Recording>,
+    /// The recording being replayed, if any.
+    replay: Option<Replay>,
+    /// Keeps the timer replaying steps running; dropping it stops the timer.
+    replay_timer: Option<StreamHandle>,
+    /// Why the last loaded recording couldn't be read.
+    recording_error: Option<String>,
+    /// Whether the state is shown as JSON for exporting and importing.
+    show_state: bool,
+    /// Why the last imported state couldn't be applied.
+    state_error: Option<String>,
+    /// What screen readers should read out after a bit is toggled.
+    announcement: String,
 }
 
 impl Model {
-    // Move out to other struct if we end up storing more data than just number in Model
-    fn value(&self) -> f64 {
-        match (
-            self.exponent_bits.iter().all(|&b| b),
-            self.exponent_bits.iter().any(|&b| b),
-        ) {
-            // Special
-            (true, _) => {
-                if self.significand_bits.iter().any(|&b| b) {
-                    f64::NAN
-                } else if self.sign_bit {
-                    f64::NEG_INFINITY
-                } else {
-                    f64::INFINITY
+    /// A binary64 zero with everything else at its defaults.
+    fn new() -> Self {
+        let encoding = FormatPreset::Binary64.zero();
+        let second = encoding.zero(false);
+        Self {
+            history: vec![HistoryStep {
+                label: "Initial".to_owned(),
+                encoding: encoding.clone(),
+            }],
+            encoding,
+            theme: Theme::Light,
+            locale: Locale::English,
+            colors: FieldColors::new(Theme::Light),
+            flipped: Vec::new(),
+            flip_parity: false,
+            timeline: None,
+            last_pushed: None,
+            paste: None,
+            explain_mode: false,
+            explained_bit: None,
+            hovered_bit: None,
+            ghost: None,
+            ghost_parity: false,
+            allocator: Allocator {
+                width: "16".to_owned(),
+                min: "0.001".to_owned(),
+                max: "10000".to_owned(),
+            },
+            fixed_point: None,
+            integer_error: None,
+            reinterpretations: vec![
+                Reinterpretation::F64,
+                Reinterpretation::F32x2,
+                Reinterpretation::F16x4,
+            ],
+            interpolation: Interpolation::default(),
+            ordinal_error: None,
+            pending_layout: None,
+            nibble_cursor: None,
+            restorable: None,
+            code_language: Language::Rust,
+            test_vectors: String::new(),
+            test_operation: Operation::Div,
+            summands: String::new(),
+            data_file: None,
+            data_error: None,
+            decimal_error: None,
+            hex_error: None,
+            binary_error: None,
+            hex_float_error: None,
+            bias_error: None,
+            rounding: RoundingMode::TiesToEven,
+            decimal_input: None,
+            second,
+            second_error: None,
+            operator: None,
+            result: None,
+            flags: Flags::default(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            copied: None,
+            bit_cursor: 0,
+            drag: None,
+            group_nibbles: false,
+            little_endian: true,
+            stepping: None,
+            posit: None,
+            decimal: None,
+            twos_complement: false,
+            walkthrough: None,
+            quiz: None,
+            recording: None,
+            replay: None,
+            replay_timer: None,
+            recording_error: None,
+            show_state: false,
+            state_error: None,
+            announcement: String::new(),
+        }
+    }
+
+    /// Applies `operator` to the current value and the second operand again.
+    fn calculate(&mut self) {
+        self.result = self
+            .operator
+            .and_then(|operator| calculate(operator, &self.encoding, &self.second, self.rounding));
+        if let Some((_, flags)) = self.result {
+            self.flags |= flags;
+        }
+    }
+
+    /// Records which bits differ from `before` so the view can animate them.
+    fn mark_flipped(&mut self, before: &Encoding) {
+        match self.encoding.changes_from(before) {
+            Some(changes) => {
+                if changes.contains(&true) {
+                    self.flip_parity = !self.flip_parity;
                 }
+                self.flipped = changes;
             }
-            (false, normal) => {
-                let bias: u64 = (1 << (self.exponent_bits.len() - 1)) - 1;
-                let exp: u64 = self
-                    .exponent_bits
-                    .iter()
-                    .fold(0, |acc, &b| (acc << 1) | (if b { 1 } else { 0 }));
-                let significand: u64 = self
-                    .significand_bits
+            // Bit positions shift when a field is resized, so there is nothing meaningful to
+            // compare against.
+            None => self.flipped.clear(),
+        }
+    }
+
+    /// The exponent and significand widths once any queued slider input is applied.
+    fn next_layout(&self) -> (usize, usize) {
+        self.pending_layout.unwrap_or((
+            self.encoding.exponent_bits.len(),
+            self.encoding.significand_bits.len(),
+        ))
+    }
+
+    /// Queues a resize from slider input, returning the effects needed to apply it later.
+    fn queue_layout(&mut self, exponent_len: usize, significand_len: usize) -> Vec<Effect> {
+        // Nothing changes until the resize is applied, so there is nothing to render yet.
+        let mut effects = vec![Effect::SkipRender];
+        if self.pending_layout.is_none() {
+            effects.push(Effect::ScheduleResize);
+        }
+        self.pending_layout = Some((exponent_len, significand_len));
+        effects
+    }
+
+    /// Replaces the encoding with `encoding` as if the app had been opened with it, so that it
+    /// begins the history. The second operand is kept if it is in the same format.
+    fn start_from(&mut self, encoding: Encoding) {
+        if !self.second.same_layout(&encoding) || self.second.options != encoding.options {
+            self.second = encoding.zero(false);
+        }
+        self.history = vec![HistoryStep {
+            label: "Initial".to_owned(),
+            encoding: encoding.clone(),
+        }];
+        self.encoding = encoding;
+    }
+
+    /// The state kept between visits as `key=value` lines: the two operands, the rounding
+    /// direction and the field colours.
+    fn session(&self) -> String {
+        let mut lines = vec![
+            format!("encoding={}", self.encoding.to_fragment()),
+            format!("second={}", self.second.to_fragment()),
+            format!("rounding={}", self.rounding.name()),
+            format!("theme={}", self.theme.name()),
+            format!("locale={}", self.locale.tag()),
+        ];
+        for &t in &BitType::ALL {
+            lines.push(format!(
+                "{}={}",
+                t.name().to_lowercase(),
+                self.colors.get(t)
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Restores the state saved by `session`, skipping anything unrecognised. Earlier versions
+    /// saved only the fragment of the encoding, which is accepted too.
+    fn restore_session(&mut self, saved: &str) {
+        let mut second = None;
+        for line in saved.lines() {
+            let mut parts = line.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some("encoding"), Some(fragment)) | (Some(fragment), None) => {
+                    if let Some(encoding) = Encoding::from_fragment(fragment) {
+                        self.start_from(encoding);
+                    }
+                }
+                (Some("second"), Some(fragment)) => second = Encoding::from_fragment(fragment),
+                (Some("rounding"), Some(name)) => {
+                    if let Some(&rounding) = RoundingMode::ALL.iter().find(|r| r.name() == name) {
+                        self.rounding = rounding;
+                    }
+                }
+                (Some("locale"), Some(tag)) => {
+                    if let Some(locale) = Locale::from_tag(tag) {
+                        self.locale = locale;
+                    }
+                }
+                (Some("theme"), Some(name)) => {
+                    if let Some(&theme) = Theme::ALL.iter().find(|t| t.name() == name) {
+                        self.theme = theme;
+                    }
+                }
+                (Some(key), Some(color)) => {
+                    if let Some(&t) = BitType::ALL
+                        .iter()
+                        .find(|t| t.name().eq_ignore_ascii_case(key))
+                    {
+                        self.colors.set(t, color.to_owned());
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(second) = second {
+            if second.same_layout(&self.encoding) && second.options == self.encoding.options {
+                self.second = second;
+            }
+        }
+    }
+
+    /// Everything needed to set the app up again elsewhere: the format, both operands, the
+    /// last operator, the rounding direction and the display settings.
+    fn state(&self) -> Json {
+        let string = |s: &str| Json::String(s.to_owned());
+        let number = |n: usize| Json::Number(n.to_string());
+        let options = &self.encoding.options;
+        Json::Object(vec![
+            ("version".to_owned(), number(STATE_VERSION)),
+            (
+                "format".to_owned(),
+                Json::Object(vec![
+                    (
+                        "exponent_bits".to_owned(),
+                        number(self.encoding.exponent_bits.len()),
+                    ),
+                    (
+                        "significand_bits".to_owned(),
+                        number(self.encoding.significand_bits.len()),
+                    ),
+                    (
+                        "bias".to_owned(),
+                        options
+                            .bias
+                            .map_or(Json::Null, |bias| Json::Number(bias.to_string())),
+                    ),
+                    ("implicit_bit".to_owned(), Json::Bool(options.implicit_bit)),
+                    ("infinities".to_owned(), Json::Bool(options.infinities)),
+                    ("nans".to_owned(), Json::Bool(options.nans)),
+                    (
+                        "saturate".to_owned(),
+                        Json::Bool(options.overflow == Overflow::Saturate),
+                    ),
+                ]),
+            ),
+            ("bits".to_owned(), string(&self.encoding.hex())),
+            ("second".to_owned(), string(&self.second.hex())),
+            (
+                "operator".to_owned(),
+                self.operator
+                    .map_or(Json::Null, |operator| string(operator.symbol())),
+            ),
+            ("rounding".to_owned(), string(self.rounding.name())),
+            ("theme".to_owned(), string(self.theme.name())),
+            ("locale".to_owned(), string(self.locale.tag())),
+            (
+                "colors".to_owned(),
+                Json::Object(
+                    BitType::ALL
+                        .iter()
+                        .map(|&t| (t.name().to_lowercase(), string(self.colors.get(t))))
+                        .collect(),
+                ),
+            ),
+            ("group_nibbles".to_owned(), Json::Bool(self.group_nibbles)),
+            ("little_endian".to_owned(), Json::Bool(self.little_endian)),
+        ])
+    }
+
+    /// Sets the app up from JSON written by `state`, changing nothing unless it is all valid.
+    /// Only the format and bits are required.
+    fn load_state(&mut self, text: &str) -> Result<(), String> {
+        let state = Json::parse(text)?;
+        let invalid = |key: &str| format!("The {} isn't valid", key.replace('_', " "));
+        let format = state.get("format").ok_or_else(|| invalid("format"))?;
+        let width = |key: &str, max: usize| {
+            format
+                .get(key)
+                .and_then(Json::as_u64)
+                .and_then(|n| usize::try_from(n).ok())
+                .filter(|n| (1..=max).contains(n))
+                .ok_or_else(|| invalid(key))
+        };
+        let flag = |key: &str, default: bool| match format.get(key) {
+            None => Ok(default),
+            Some(value) => value.as_bool().ok_or_else(|| invalid(key)),
+        };
+        let options = Options {
+            overflow: if flag("saturate", false)? {
+                Overflow::Saturate
+            } else {
+                Overflow::Infinity
+            },
+            infinities: flag("infinities", true)?,
+            nans: flag("nans", true)?,
+            implicit_bit: flag("implicit_bit", true)?,
+            bias: match format.get("bias") {
+                None | Some(Json::Null) => None,
+                Some(bias) => Some(
+                    bias.as_u64()
+                        .filter(|&bias| bias <= MAX_BIAS)
+                        .ok_or_else(|| invalid("bias"))?,
+                ),
+            },
+        };
+        let zero = Encoding {
+            options,
+            ..Encoding::from_bits(
+                width("exponent_bits", MAX_EXPONENT_BITS)?,
+                width("significand_bits", MAX_SIGNIFICAND_BITS)?,
+                0,
+            )
+        };
+        let bits = |key: &str| {
+            state
+                .get(key)
+                .and_then(Json::as_str)
+                .map(|hex| hex.trim_start_matches("0x"))
+                .and_then(|hex| zero.with_hex(hex))
+                .ok_or_else(|| invalid(key))
+        };
+        let encoding = bits("bits")?;
+        let second = match state.get("second") {
+            None => zero.clone(),
+            Some(_) => bits("second")?,
+        };
+        // Settings which are left out keep their current values.
+        let named = |key: &str| state.get(key).filter(|value| !value.is_null());
+        let operator = match named("operator") {
+            None => None,
+            Some(symbol) => Some(
+                Operator::ALL
                     .iter()
-                    .fold(0, |acc, &b| (acc << 1) | (if b { 1 } else { 0 }));
-                let sign = if self.sign_bit { 1 } else { 0 };
-                f64::from_bits(
-                    sign << (BINARY_64_EXPONENT_BITS + BINARY_64_SIGNIFICAND_BITS)
-                        | if normal {
-                            exp + (BINARY_64_BIAS as u64 - bias)
-                        } else if significand == 0 {
-                            0
-                        } else {
-                            BINARY_64_BIAS as u64 - bias
-                                // Account for subnormal exponent if not exactly binary64 exp
-                                + if self.exponent_bits.len() == 11 { 0 } else { 1 }
-                        } << BINARY_64_SIGNIFICAND_BITS
-                        | significand
-                            << (BINARY_64_SIGNIFICAND_BITS
-                                - self.significand_bits.len()
-                                - if !normal && self.exponent_bits.len() != BINARY_64_EXPONENT_BITS
-                                {
-                                    1
-                                } else {
-                                    0
-                                }),
-                ) - if !normal
-                    && significand != 0
-                    && self.exponent_bits.len() != BINARY_64_EXPONENT_BITS
-                {
-                    // Note: bias is maximally 1023 as self.exponent_bits.len() is at most 10 if
-                    // this branch is taken
-                    #[allow(clippy::cast_possible_truncation)]
-                    (-f64::from(bias as u16)).exp2()
-                } else {
-                    0_f64
+                    .copied()
+                    .find(|operator| symbol.as_str() == Some(operator.symbol()))
+                    .ok_or_else(|| invalid("operator"))?,
+            ),
+        };
+        let rounding = match named("rounding") {
+            None => self.rounding,
+            Some(name) => RoundingMode::ALL
+                .iter()
+                .copied()
+                .find(|rounding| name.as_str() == Some(rounding.name()))
+                .ok_or_else(|| invalid("rounding"))?,
+        };
+        let theme = match named("theme") {
+            None => self.theme,
+            Some(name) => Theme::ALL
+                .iter()
+                .copied()
+                .find(|theme| name.as_str() == Some(theme.name()))
+                .ok_or_else(|| invalid("theme"))?,
+        };
+        let locale = match named("locale") {
+            None => self.locale,
+            Some(tag) => tag
+                .as_str()
+                .and_then(Locale::from_tag)
+                .ok_or_else(|| invalid("locale"))?,
+        };
+        let mut colors = Vec::new();
+        if let Some(given) = named("colors") {
+            for &t in &BitType::ALL {
+                if let Some(color) = given.get(&t.name().to_lowercase()) {
+                    colors.push((t, color.as_str().ok_or_else(|| invalid("colors"))?));
                 }
             }
         }
+        let toggle = |key: &str, current: bool| match named(key) {
+            None => Ok(current),
+            Some(value) => value.as_bool().ok_or_else(|| invalid(key)),
+        };
+        self.group_nibbles = toggle("group_nibbles", self.group_nibbles)?;
+        self.little_endian = toggle("little_endian", self.little_endian)?;
+        self.encoding = encoding;
+        self.second = second;
+        self.operator = operator;
+        self.rounding = rounding;
+        self.theme = theme;
+        self.locale = locale;
+        for (t, color) in colors {
+            self.colors.set(t, color.to_owned());
+        }
+        Ok(())
+    }
+
+    /// Appends the current encoding to the history.
+    fn record(&mut self, label: String) {
+        self.history.push(HistoryStep {
+            label,
+            encoding: self.encoding.clone(),
+        });
+        if self.history.len() > HISTORY_LEN {
+            self.history.remove(0);
+        }
+    }
+}
+
+/// A recording of every `Msg` and the state it produced, for time travel debugging.
+struct Timeline {
+    entries: Vec<TimelineEntry>,
+    /// Index of the entry currently shown.
+    cursor: usize,
+}
+
+struct TimelineEntry {
+    /// Milliseconds since the Unix epoch at which the `Msg` was handled.
+    time: f64,
+    step: HistoryStep,
+}
+
+impl Timeline {
+    fn new(encoding: &Encoding, now: f64) -> Self {
+        Self {
+            entries: vec![TimelineEntry {
+                time: now,
+                step: HistoryStep {
+                    label: "Recording started".to_owned(),
+                    encoding: encoding.clone(),
+                },
+            }],
+            cursor: 0,
+        }
     }
+
+    fn push(&mut self, label: String, encoding: &Encoding, now: f64) {
+        self.entries.push(TimelineEntry {
+            time: now,
+            step: HistoryStep {
+                label,
+                encoding: encoding.clone(),
+            },
+        });
+        self.cursor = self.entries.len() - 1;
+    }
+
+    /// Moves the cursor to entry `i`, returning the state recorded there.
+    fn scrub(&mut self, i: usize) -> Option<&Encoding> {
+        let entry = self.entries.get(i)?;
+        self.cursor = i;
+        Some(&entry.step.encoding)
+    }
+}
+
+/// Pasted text which could not be applied directly.
+enum PasteState {
+    /// The text is valid in more than one format.
+    Ambiguous(String, Vec<PasteFormat>),
+    Error(String),
+}
+
+/// Text inputs of the bit budget allocator, parsed when rendering.
+struct Allocator {
+    /// Total width of the format.
+    width: String,
+    /// Smallest magnitude the format should represent.
+    min: String,
+    /// Largest magnitude the format should represent.
+    max: String,
+}
+
+/// Animation stepping the raw bits linearly from one saved value to another.
+#[derive(Default)]
+struct Interpolation {
+    start: Option<Encoding>,
+    end: Option<Encoding>,
+    /// Frames shown so far as (raw bits, value).
+    frames: Vec<(u64, f64)>,
+    /// Keeps the animation running; dropping it stops the timer.
+    timer: Option<StreamHandle>,
+}
+
+impl Interpolation {
+    /// The encoding `frame / INTERPOLATION_FRAMES` of the way from `start` to `end` in bit space.
+    fn frame(start: &Encoding, end: &Encoding, frame: u32) -> Encoding {
+        let (from, to) = (i128::from(start.to_bits()), i128::from(end.to_bits()));
+        let bits = from + (to - from) * i128::from(frame) / i128::from(INTERPOLATION_FRAMES);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        start.with_bits(bits as u64)
+    }
+}
+
+/// A single step in the edit history.
+struct HistoryStep {
+    /// Human readable description of the edit which produced `encoding`.
+    label: String,
+    encoding: Encoding,
 }
 
-// For some styling later
-#[derive(Debug, Copy, Clone)]
-enum BitType {
-    Sign,
-    Exponent,
-    Significand,
+/// Colours for the page, applied as a class on the root element so that the stylesheet can
+/// pick them.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Theme {
+    Light,
+    Dark,
+    HighContrast,
 }
 
-impl BitType {
-    fn color(self) -> &'static str {
+impl Theme {
+    pub const ALL: [Self; 3] = [Self::Light, Self::Dark, Self::HighContrast];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+            Self::HighContrast => "High contrast",
+        }
+    }
+
+    fn class(self) -> &'static str {
         match self {
-            Self::Sign => "#D72638",
-            Self::Exponent => "#00916E",
-            Self::Significand => "#F49D37",
+            Self::Light => "theme_light",
+            Self::Dark => "theme_dark",
+            Self::HighContrast => "theme_high_contrast",
+        }
+    }
+
+    /// The default colour of field `t`, which needs to stand out against the background.
+    fn color(self, t: BitType) -> &'static str {
+        match (self, t) {
+            (Self::Light, _) => t.default_color(),
+            (Self::Dark, BitType::Sign) => "#F25F5C",
+            (Self::Dark, BitType::Exponent) => "#3DBE8B",
+            (Self::Dark, BitType::Significand) => "#F6B15E",
+            (Self::HighContrast, BitType::Sign) => "#FF5C5C",
+            (Self::HighContrast, BitType::Exponent) => "#00E676",
+            (Self::HighContrast, BitType::Significand) => "#FFD600",
+        }
+    }
+
+    /// The background, text and on-field colours the stylesheet gives the theme, for drawings
+    /// made outside the page.
+    fn page_colors(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Light => ("#FEEFE5", "black", "#FEEFE5"),
+            Self::Dark => ("#1E1B18", "#FEEFE5", "#1E1B18"),
+            Self::HighContrast => ("black", "white", "black"),
+        }
+    }
+}
+
+/// User-selected display colours for each field of the encoding.
+#[derive(PartialEq)]
+struct FieldColors {
+    sign: String,
+    exponent: String,
+    significand: String,
+}
+
+impl FieldColors {
+    /// The diagram colours for these field colours on the page of `theme`.
+    fn palette(&self, theme: Theme) -> Palette<'_> {
+        let (background, foreground, on_field) = theme.page_colors();
+        Palette {
+            sign: &self.sign,
+            exponent: &self.exponent,
+            significand: &self.significand,
+            background,
+            foreground,
+            on_field,
+        }
+    }
+
+    /// The default colours for `theme`.
+    fn new(theme: Theme) -> Self {
+        Self {
+            sign: theme.color(BitType::Sign).to_owned(),
+            exponent: theme.color(BitType::Exponent).to_owned(),
+            significand: theme.color(BitType::Significand).to_owned(),
+        }
+    }
+
+    fn get(&self, t: BitType) -> &str {
+        match t {
+            BitType::Sign => &self.sign,
+            BitType::Exponent => &self.exponent,
+            BitType::Significand => &self.significand,
+        }
+    }
+
+    fn set(&mut self, t: BitType, color: String) {
+        match t {
+            BitType::Sign => self.sign = color,
+            BitType::Exponent => self.exponent = color,
+            BitType::Significand => self.significand = color,
         }
     }
 }
@@ -131,127 +866,1222 @@ impl BitType {
 // ------ ------
 
 // `Msg` describes the different events you can modify state with.
-#[derive(Copy, Clone)]
-enum Msg {
+#[derive(Clone)]
+pub enum Msg {
+    /// Slider input, queued so that a burst of events only causes a single resize.
     SetExpSize(usize),
     SetSigSize(usize),
+    /// Applies queued slider input.
+    FlushResize,
+    /// Sets both the exponent and significand widths.
+    SetLayout(usize, usize),
+    /// Sets the bit of a field worth `2^weight` within the field.
+    SetBit(BitType, usize, bool),
+    /// Sets a field to the low bits of an integer.
+    SetField(BitType, u64),
+    /// Sets the raw bits without changing the layout.
+    SetPattern(u64),
+    /// Toggles a bit by its position counting from the sign bit, as shown in the bit row.
     ToggleBit(usize),
+    SetColor(BitType, String),
+    ResetColors,
+    SetTheme(Theme),
+    SetLocale(Locale),
+    ToggleTimeline,
+    Scrub(usize),
+    UrlChanged(subs::UrlChanged),
+    CopyFieldString,
+    Paste(String),
+    PasteAs(PasteFormat),
+    DismissPaste,
+    ToggleExplainMode,
+    ToggleNibbleGroups,
+    ToggleByteOrder,
+    ExplainBit(Option<usize>),
+    HoverBit(Option<usize>),
+    Load(Encoding),
+    SetAllocatorWidth(String),
+    SetAllocatorMin(String),
+    SetAllocatorMax(String),
+    ToggleFixedPoint,
+    TogglePosit,
+    SetPositEs(usize),
+    ToggleDecimal,
+    SetDecimalEncoding(DecimalEncoding),
+    ToggleTwosComplement,
+    /// Sets the value from a decimal and works through its conversion.
+    StartWalkthrough(String),
+    ShowWalkthroughStep(usize),
+    CloseWalkthrough,
+    /// Starts practising with a question drawn from the seed.
+    StartQuiz(u64),
+    NextQuestion(u64),
+    /// Checks the bit row against the value the question asks for.
+    CheckQuizBits,
+    /// Checks a typed value against the bit pattern the question shows.
+    AnswerQuiz(String),
+    RevealAnswer,
+    StopQuiz,
+    StartRecording,
+    /// Stops recording, keeping the recording ready to replay.
+    StopRecording,
+    /// A recording saved as text, to be replayed.
+    LoadRecording(String),
+    CopyRecording,
+    /// Go back to the state the recording starts from.
+    RewindReplay,
+    /// Apply the next step of the recording.
+    ReplayStep,
+    /// Start applying a step of the recording every `REPLAY_STEP_MS`.
+    PlayReplay,
+    PauseReplay,
+    CloseReplay,
+    ToggleStateExport,
+    /// JSON exported from this or another copy of the app.
+    ImportState(String),
+    CopyState,
+    SetFractionBits(usize),
+    ToggleFixedPointSigned,
+    SetFromUnsigned(String),
+    ToggleReinterpretation(Reinterpretation),
+    SaveInterpolationStart,
+    SaveInterpolationEnd,
+    PlayInterpolation,
+    StopInterpolation,
+    InterpolationFrame,
+    /// Add one to the raw bits.
+    StepPattern,
+    PlaySteps,
+    PauseSteps,
+    SteppingFrame,
+    SetFromOrdinal(String),
+    /// Selects a hex digit for overwriting, or clears the selection.
+    SelectNibble(Option<usize>),
+    /// A key pressed while the hex digits have focus.
+    HexKey(String),
+    /// Saves the value as the page is closed.
+    SaveSession,
+    RestoreSession,
+    DiscardSession,
+    /// Go back to the defaults of everything kept between visits.
+    ResetToDefaults,
+    /// Put the encoding on the clipboard in the given format.
+    Copy(PasteFormat),
+    /// Take down the confirmation of the last copy.
+    HideCopied,
+    /// A bit received the keyboard focus.
+    BitFocused(usize),
+    /// A key which moves the focus between bits.
+    BitKey(String),
+    /// The mouse button went down on a bit, toggling it and setting every bit dragged over
+    /// afterwards to match.
+    StartDrag(usize),
+    /// The mouse entered a bit while dragging.
+    DragOver(usize),
+    EndDrag,
+    /// Replace every bit with ones drawn from a generator with the given seed.
+    Randomize(u64),
+    /// Set or clear every bit of a field.
+    FillField(BitType, bool),
+    /// Clear every bit, giving +0.
+    Clear,
+    /// Set the encoding to a special value of the current format.
+    SetSpecial(SpecialValue),
+    SetCodeLanguage(Language),
+    CopyCode,
+    CopyTestVectors,
+    SetTestVectors(String),
+    SetTestOperation(Operation),
+    /// Values to sum naively and with Kahan's algorithm.
+    SetSummands(String),
+    /// A file chosen for browsing, to be read asynchronously.
+    ChooseDataFile(web_sys::File),
+    /// The name and contents of the chosen file.
+    DataFileRead(String, Result<Vec<u8>, String>),
+    /// Reinterprets a raw file, which has no header saying what it holds.
+    SetDataElement(ElementType),
+    ToggleDataEndianness,
+    ShowElement(usize),
+    NextNan,
+    CloseDataFile,
+    SetOverflow(Overflow),
+    ToggleInfinities,
+    ToggleNans,
+    ToggleImplicitBit,
+    /// A typed exponent bias, or nothing for the standard one.
+    SetBias(String),
+    /// A typed decimal number, rounded to nearest in the current format.
+    SetFromDecimal(String),
+    /// A typed hex pattern for all of the bits.
+    SetFromHex(String),
+    /// A typed string of every bit, which may be split up by spaces, underscores or bars.
+    SetFromBinary(String),
+    /// A typed hex float, rounded in the current direction.
+    SetFromHexFloat(String),
+    /// Switch to a standard format, keeping the nearest value to the current one.
+    ApplyPreset(FormatPreset),
+    /// Move to the next representable value above the current one.
+    StepUp,
+    /// Move to the next representable value below the current one.
+    StepDown,
+    /// Negate, take the absolute value of, double or halve the current value.
+    ApplyUnary(UnaryOperator),
+    SetRounding(RoundingMode),
+    /// Flip a bit of the second operand, counting from its sign bit.
+    ToggleSecondBit(usize),
+    /// A typed decimal number for the second operand.
+    SetSecondFromDecimal(String),
+    /// Exchange the current value and the second operand.
+    SwapOperands,
+    /// Combine the current value and the second operand.
+    Calculate(Operator),
+    /// Replace the current value with the result of the last calculation.
+    UseResult,
+    /// Lower every exception flag.
+    ClearFlags,
+    /// Go back to the encoding before the last edit.
+    Undo,
+    /// Reapply the last undone edit.
+    Redo,
 }
 
-// `update` describes how to handle each `Msg`.
-fn update(msg: Msg, model: &mut Model, _: &mut impl Orders<Msg>) {
-    match msg {
-        Msg::SetExpSize(e) => model.exponent_bits.resize(e, false),
-        Msg::SetSigSize(s) => model.significand_bits.resize(s, false),
-        Msg::ToggleBit(b) => {
-            if let Some(bit) = iter::once(&mut model.sign_bit)
-                .chain(&mut model.exponent_bits)
-                .chain(&mut model.significand_bits)
-                .nth(b)
-            {
-                *bit = !*bit;
+impl Msg {
+    /// Description of the message for the history panel and timeline, or `None` for messages
+    /// which navigate recorded state rather than change it.
+    fn label(&self) -> Option<String> {
+        match self {
+            Self::SetLayout(e, s) => Some(format!("Format set to {}/{}", e, s)),
+            Self::SetBit(t, i, b) => {
+                Some(format!("{} bit {} set to {}", t.name(), i, u8::from(*b)))
+            }
+            Self::SetField(t, v) => Some(format!("{} field set to {}", t.name(), v)),
+            Self::SetPattern(bits) => Some(format!("Set to 0x{:X}", bits)),
+            Self::ToggleBit(b) | Self::StartDrag(b) => Some(format!("Toggled bit {}", b)),
+            Self::DragOver(b) => Some(format!("Dragged over bit {}", b)),
+            Self::Randomize(_) => Some("Randomized".to_owned()),
+            Self::StepPattern => Some("Added one to the raw bits".to_owned()),
+            Self::FillField(t, true) => Some(format!("{} field filled with ones", t.name())),
+            Self::FillField(t, false) => Some(format!("{} field cleared", t.name())),
+            Self::Clear => Some("Cleared".to_owned()),
+            Self::SetSpecial(special) => Some(format!("Set to {}", special.name())),
+            Self::HexKey(k) if k.len() == 1 => {
+                Some(format!("Typed hex digit {}", k.to_uppercase()))
+            }
+            Self::SetColor(t, _) => Some(format!("{} colour changed", t.name())),
+            Self::ResetColors => Some("Colours reset".to_owned()),
+            Self::SetTheme(theme) => Some(format!("{} theme chosen", theme.name())),
+            Self::SetLocale(locale) => Some(format!("Language set to {}", locale.name())),
+            Self::UrlChanged(_) => Some("Browser navigation".to_owned()),
+            Self::Paste(_) => Some("Pasted".to_owned()),
+            Self::PasteAs(f) => Some(format!("Pasted as {}", f.name())),
+            Self::Load(e) => Some(format!("Loaded {}", e.hex())),
+            Self::SetFromUnsigned(i) => Some(format!("Set to unsigned integer {}", i.trim())),
+            Self::SetFromOrdinal(o) => Some(format!("Jumped to finite value #{}", o.trim())),
+            Self::RestoreSession => Some("Restored the previous session".to_owned()),
+            Self::ResetToDefaults => Some("Reset to defaults".to_owned()),
+            Self::DataFileRead(name, _) => Some(format!("Opened {}", name)),
+            Self::SetDataElement(t) => Some(format!("Read the file as {}", t.name())),
+            Self::ToggleDataEndianness => Some("Swapped the file's byte order".to_owned()),
+            Self::ShowElement(i) => Some(format!("Showed element {} of the file", i)),
+            Self::NextNan => Some("Skipped to the next NaN in the file".to_owned()),
+            Self::SetOverflow(o) => Some(format!("Format set to {}", o.name().to_lowercase())),
+            Self::ToggleInfinities => Some("Toggled whether the format has infinities".to_owned()),
+            Self::ToggleNans => Some("Toggled whether the format has NaNs".to_owned()),
+            Self::ToggleImplicitBit => {
+                Some("Toggled whether the leading significand bit is stored".to_owned())
             }
+            Self::SetBias(b) if b.trim().is_empty() => Some("Bias set to the standard".to_owned()),
+            Self::SetBias(b) => Some(format!("Bias set to {}", b.trim())),
+            Self::SetFromDecimal(d) | Self::StartWalkthrough(d) => {
+                Some(format!("Set to {}", d.trim()))
+            }
+            Self::SetFromHex(h) | Self::SetFromBinary(h) | Self::SetFromHexFloat(h) => {
+                Some(format!("Set to {}", h.trim()))
+            }
+            Self::ApplyPreset(preset) => Some(format!("Format set to {}", preset.name())),
+            Self::StepUp => Some("Stepped up to the next value".to_owned()),
+            Self::StepDown => Some("Stepped down to the previous value".to_owned()),
+            Self::ApplyUnary(UnaryOperator::Negate) => Some("Negated".to_owned()),
+            Self::ApplyUnary(UnaryOperator::Abs) => Some("Took the absolute value".to_owned()),
+            Self::ApplyUnary(UnaryOperator::Double) => Some("Doubled".to_owned()),
+            Self::ApplyUnary(UnaryOperator::Halve) => Some("Halved".to_owned()),
+            Self::SwapOperands => Some("Swapped with the second operand".to_owned()),
+            Self::UseResult => Some("Set to the calculated result".to_owned()),
+            Self::RewindReplay => Some("Rewound to the start of the recording".to_owned()),
+            Self::ImportState(_) => Some("Imported state".to_owned()),
+            Self::Undo => Some("Undid the last edit".to_owned()),
+            Self::Redo => Some("Redid the last undone edit".to_owned()),
+            Self::SetExpSize(_)
+            | Self::SetSigSize(_)
+            | Self::FlushResize
+            | Self::ToggleTimeline
+            | Self::Scrub(_)
+            | Self::CopyFieldString
+            | Self::DismissPaste
+            | Self::ToggleExplainMode
+            | Self::ToggleNibbleGroups
+            | Self::ToggleByteOrder
+            | Self::ExplainBit(_)
+            | Self::HoverBit(_)
+            | Self::SetAllocatorWidth(_)
+            | Self::SetAllocatorMin(_)
+            | Self::SetAllocatorMax(_)
+            | Self::ToggleFixedPoint
+            | Self::TogglePosit
+            | Self::SetPositEs(_)
+            | Self::ToggleDecimal
+            | Self::SetDecimalEncoding(_)
+            | Self::ToggleTwosComplement
+            | Self::ShowWalkthroughStep(_)
+            | Self::CloseWalkthrough
+            | Self::StartQuiz(_)
+            | Self::NextQuestion(_)
+            | Self::CheckQuizBits
+            | Self::AnswerQuiz(_)
+            | Self::RevealAnswer
+            | Self::StopQuiz
+            | Self::StartRecording
+            | Self::StopRecording
+            | Self::LoadRecording(_)
+            | Self::CopyRecording
+            | Self::ReplayStep
+            | Self::PlayReplay
+            | Self::PauseReplay
+            | Self::CloseReplay
+            | Self::ToggleStateExport
+            | Self::CopyState
+            | Self::SetFractionBits(_)
+            | Self::ToggleFixedPointSigned
+            | Self::ToggleReinterpretation(_)
+            | Self::SaveInterpolationStart
+            | Self::SaveInterpolationEnd
+            | Self::PlayInterpolation
+            | Self::StopInterpolation
+            | Self::InterpolationFrame
+            | Self::PlaySteps
+            | Self::PauseSteps
+            | Self::SteppingFrame
+            | Self::SelectNibble(_)
+            | Self::HexKey(_)
+            | Self::SaveSession
+            | Self::DiscardSession
+            | Self::SetCodeLanguage(_)
+            | Self::CopyCode
+            | Self::CopyTestVectors
+            | Self::SetTestVectors(_)
+            | Self::SetTestOperation(_)
+            | Self::SetSummands(_)
+            | Self::ChooseDataFile(_)
+            | Self::CloseDataFile
+            | Self::SetRounding(_)
+            | Self::ToggleSecondBit(_)
+            | Self::SetSecondFromDecimal(_)
+            | Self::Copy(_)
+            | Self::HideCopied
+            | Self::BitFocused(_)
+            | Self::BitKey(_)
+            | Self::EndDrag
+            | Self::Calculate(_)
+            | Self::ClearFlags => None,
+        }
+    }
+
+    /// Whether handling the message would leave everything shown as it is, so that the
+    /// re-render can be skipped.
+    fn is_noop(&self, model: &Model) -> bool {
+        match self {
+            Self::SetExpSize(e) => *e == model.next_layout().0,
+            Self::SetSigSize(s) => *s == model.next_layout().1,
+            Self::SetLayout(e, s) => {
+                model.encoding.exponent_bits.len() == *e
+                    && model.encoding.significand_bits.len() == *s
+            }
+            Self::FlushResize => model.pending_layout.is_none(),
+            Self::SetColor(t, color) => model.colors.get(*t) == color,
+            Self::SetTheme(theme) => *theme == model.theme,
+            Self::SetLocale(locale) => *locale == model.locale,
+            Self::Scrub(i) => model.timeline.as_ref().map_or(true, |t| t.cursor == *i),
+            Self::DismissPaste => model.paste.is_none(),
+            Self::ExplainBit(b) => *b == model.explained_bit,
+            Self::HoverBit(b) => *b == model.hovered_bit,
+            Self::SelectNibble(i) => *i == model.nibble_cursor,
+            Self::SetCodeLanguage(language) => *language == model.code_language,
+            Self::SetOverflow(overflow) => *overflow == model.encoding.options.overflow,
+            Self::SetFractionBits(n) => model.fixed_point.map_or(true, |f| f.fraction_bits == *n),
+            Self::SetPositEs(es) => model.posit.map_or(true, |p| p.es == *es),
+            Self::SetDecimalEncoding(encoding) => model.decimal.map_or(true, |d| d == *encoding),
+            Self::ShowWalkthroughStep(i) => {
+                model.walkthrough.as_ref().map_or(true, |w| w.step == *i)
+            }
+            Self::CloseWalkthrough => model.walkthrough.is_none(),
+            Self::StopQuiz => model.quiz.is_none(),
+            Self::StartRecording => model.recording.is_some(),
+            Self::StopRecording => model.recording.is_none(),
+            Self::RewindReplay | Self::CopyRecording | Self::CloseReplay => model.replay.is_none(),
+            Self::ReplayStep => model.replay.as_ref().map_or(true, Replay::is_finished),
+            Self::PlayReplay => {
+                model.replay_timer.is_some()
+                    || model.replay.as_ref().map_or(true, Replay::is_finished)
+            }
+            Self::PauseReplay => model.replay_timer.is_none(),
+            Self::StopInterpolation => model.interpolation.timer.is_none(),
+            Self::PlaySteps => model.stepping.is_some(),
+            Self::PauseSteps => model.stepping.is_none(),
+            Self::SetRounding(rounding) => *rounding == model.rounding,
+            Self::StepUp => model.encoding.next_up().is_none(),
+            Self::StepDown => model.encoding.next_down().is_none(),
+            Self::UseResult => model.result.is_none(),
+            Self::ClearFlags => model.flags == Flags::default(),
+            Self::HideCopied => model.copied.is_none(),
+            Self::BitFocused(i) => *i == model.bit_cursor,
+            Self::DragOver(i) => model.drag.map_or(true, |value| {
+                model
+                    .encoding
+                    .bit(*i)
+                    .map_or(true, |bit| bit.value == value)
+            }),
+            Self::EndDrag => model.drag.is_none(),
+            Self::Undo => model.undo.is_empty(),
+            Self::Redo => model.redo.is_empty(),
+            _ => false,
         }
     }
 }
 
-// ------ ------
-//     View
-// ------ ------
+/// Side effects of handling a `Msg`, which `update` performs once the model is up to date.
+#[derive(Debug, PartialEq)]
+enum Effect {
+    /// Add a browser history entry for the current encoding, or replace the current one.
+    Navigate {
+        replace: bool,
+    },
+    CopyToClipboard(String),
+    /// Don't re-render, as nothing shown has changed.
+    SkipRender,
+    /// Send `Msg::FlushResize` once the current burst of slider input is over.
+    ScheduleResize,
+    /// Save the current value to be offered for restoring on the next visit.
+    SaveSession,
+    ClearSession,
+    /// Send `Msg::HideCopied` once the confirmation has been seen.
+    ScheduleHideCopied,
+    /// Move the keyboard focus to the button of a bit once it is rendered.
+    FocusBit(usize),
+    /// Start sending `Msg::InterpolationFrame` to animate between the saved values.
+    StartInterpolation,
+    /// Start sending `Msg::SteppingFrame` to play through consecutive bit patterns.
+    StartStepping,
+    /// Start sending `Msg::ReplayStep` to play through the recording.
+    StartReplay,
+    /// Read the file's contents and send them back in `Msg::DataFileRead`.
+    ReadFile(web_sys::File),
+}
 
-// `view` describes what to display.
-fn view(model: &Model) -> Vec<Node<Msg>> {
-    nodes![
-        view_value(model),
-        view_bits(model),
-        div![
-            C!["controls"],
-            div![
-                C!["exponent_slider"],
-                format!(
-                    "Exponent Bits ({}): ",
-                    model.exponent_bits.len().to_string()
-                ),
-                input![
-                    attrs! {
-                        At::Type => "range",
-                        At::Min => "1",
-                        At::Max => BINARY_64_EXPONENT_BITS.to_string(),
-                        At::Value => model.exponent_bits.len().to_string()
-                    },
-                    input_ev(Ev::Input, |i| Msg::SetExpSize(
-                        i.parse().expect("Slider must report number")
-                    )),
-                ],
-            ],
-            div![
-                C!["significand_slider"],
-                format!(
-                    "Significand Bits ({}): ",
-                    model.significand_bits.len().to_string()
-                ),
-                input![
-                    attrs! {
-                        At::Type => "range",
-                        At::Min => "1",
-                        At::Max => BINARY_64_SIGNIFICAND_BITS.to_string(),
-                        At::Value => model.significand_bits.len().to_string()
+// `update` describes how to handle each `Msg`.
+fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    let session = model.session();
+    for effect in handle(msg, model, js_sys::Date::now()) {
+        match effect {
+            Effect::Navigate { replace } => {
+                let url = Url::current().set_hash(model.encoding.to_fragment());
+                if replace {
+                    url.go_and_replace();
+                } else {
+                    url.go_and_push();
+                }
+            }
+            Effect::CopyToClipboard(text) => write_clipboard(&text),
+            Effect::SkipRender => {
+                orders.skip();
+            }
+            Effect::ScheduleResize => {
+                orders.perform_cmd(cmds::timeout(RESIZE_BATCH_MS, || Msg::FlushResize));
+            }
+            Effect::SaveSession => {
+                if let Some(storage) = local_storage() {
+                    let _ = storage.set_item(SNAPSHOT_KEY, &model.encoding.to_fragment());
+                }
+            }
+            Effect::ClearSession => {
+                if let Some(storage) = local_storage() {
+                    let _ = storage.remove_item(SNAPSHOT_KEY);
+                }
+            }
+            Effect::ScheduleHideCopied => {
+                orders.perform_cmd(cmds::timeout(COPIED_MS, || Msg::HideCopied));
+            }
+            Effect::FocusBit(i) => {
+                orders.after_next_render(move |_| {
+                    if let Some(button) = document()
+                        .get_element_by_id(&format!("bit_{}", i))
+                        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+                    {
+                        let _ = button.focus();
+                    }
+                });
+            }
+            Effect::StartInterpolation => {
+                model.interpolation.timer = Some(
+                    orders.stream_with_handle(streams::interval(INTERPOLATION_FRAME_MS, || {
+                        Msg::InterpolationFrame
+                    })),
+                );
+            }
+            Effect::StartStepping => {
+                model.stepping = Some(
+                    orders.stream_with_handle(streams::interval(STEPPING_FRAME_MS, || {
+                        Msg::SteppingFrame
+                    })),
+                );
+            }
+            Effect::StartReplay => {
+                model.replay_timer = Some(
+                    orders
+                        .stream_with_handle(streams::interval(REPLAY_STEP_MS, || Msg::ReplayStep)),
+                );
+            }
+            Effect::ReadFile(file) => {
+                orders.perform_cmd(async move {
+                    let name = file.name();
+                    let bytes = JsFuture::from(file.array_buffer())
+                        .await
+                        .map(|buffer| js_sys::Uint8Array::new(&buffer).to_vec())
+                        .map_err(|_| format!("Could not read {}", name));
+                    Msg::DataFileRead(name, bytes)
+                });
+            }
+        }
+    }
+    // Save as we go rather than as the page closes, which mobile browsers don't announce.
+    if model.session() != session {
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(SESSION_KEY, &model.session());
+        }
+    }
+    set_document_title(&model.encoding);
+    set_theme(model.theme);
+    set_language(model.locale);
+    if let Some(callback) = embed::publish(&model.encoding) {
+        let (value, hex) = (model.encoding.value(), model.encoding.hex());
+        // Call back once the update is over, so that the callback can drive the app in turn.
+        orders.after_next_render(move |_| {
+            let _ = callback.call2(&JsValue::NULL, &JsValue::from(value), &JsValue::from(hex));
+        });
+    }
+}
+
+/// Applies `msg` to the model without touching the DOM, returning what `update` should do
+/// about it. `now` is the time in milliseconds since the Unix epoch.
+fn handle(msg: Msg, model: &mut Model, now: f64) -> Vec<Effect> {
+    if msg.is_noop(model) {
+        return vec![Effect::SkipRender];
+    }
+    if let Some(recording) = &mut model.recording {
+        recording.push(&msg);
+    }
+    let mut effects = Vec::new();
+    let before = model.encoding.clone();
+    let (second_before, rounding_before) = (model.second.clone(), model.rounding);
+    let label = msg.label();
+    let kind = mem::discriminant(&msg);
+    let from_url = matches!(msg, Msg::UrlChanged(_));
+    let coalesce = matches!(msg, Msg::SetLayout(..) | Msg::HexKey(_) | Msg::DragOver(_));
+    let undoing = matches!(msg, Msg::Undo | Msg::Redo);
+    match msg {
+        Msg::SetExpSize(e) => effects = model.queue_layout(e, model.next_layout().1),
+        Msg::SetSigSize(s) => effects = model.queue_layout(model.next_layout().0, s),
+        Msg::FlushResize => {
+            if let Some((e, s)) = model.pending_layout.take() {
+                return handle(Msg::SetLayout(e, s), model, now);
+            }
+        }
+        Msg::SetLayout(e, s) => {
+            // A bias chosen for one exponent width rarely suits another.
+            if e != model.encoding.exponent_bits.len() {
+                model.encoding.options.bias = None;
+            }
+            model.encoding.exponent_bits.resize(e, false);
+            model.encoding.significand_bits.resize(s, false);
+        }
+        Msg::SetBit(t, i, b) => model.encoding.set_bit(t, i, b),
+        Msg::SetField(t, v) => model.encoding.set_field(t, v),
+        Msg::SetPattern(bits) => model.encoding = model.encoding.with_bits(bits),
+        Msg::ToggleBit(i) => {
+            if let Some(bit) = model.encoding.bit(i) {
+                model.encoding.set_bit(bit.field, bit.weight, !bit.value);
+                model.announcement = format!(
+                    "{} bit {} is now {}, giving {}",
+                    bit.field.name(),
+                    bit.weight,
+                    u8::from(!bit.value),
+                    format_encoding(&model.encoding)
+                );
+            }
+        }
+        Msg::SetColor(t, color) => model.colors.set(t, color),
+        Msg::ResetColors => model.colors = FieldColors::new(model.theme),
+        Msg::SetTheme(theme) => {
+            // Colours the user picked are kept, but the defaults follow the theme.
+            if model.colors == FieldColors::new(model.theme) {
+                model.colors = FieldColors::new(theme);
+            }
+            model.theme = theme;
+        }
+        Msg::SetLocale(locale) => model.locale = locale,
+        Msg::ToggleTimeline => {
+            model.timeline = match model.timeline {
+                Some(_) => None,
+                None => Some(Timeline::new(&model.encoding, now)),
+            }
+        }
+        Msg::Scrub(i) => {
+            if let Some(encoding) = model.timeline.as_mut().and_then(|t| t.scrub(i)) {
+                model.encoding = encoding.clone();
+            }
+        }
+        Msg::UrlChanged(subs::UrlChanged(url)) => {
+            if let Some(encoding) = url.hash().and_then(|hash| Encoding::from_fragment(hash)) {
+                model.encoding = encoding;
+            }
+        }
+        Msg::CopyFieldString => {
+            effects.push(Effect::CopyToClipboard(model.encoding.field_string()));
+        }
+        Msg::Paste(text) => {
+            let formats = PasteFormat::detect(&text, model.encoding.len());
+            match formats.len() {
+                0 => {
+                    model.paste = Some(PasteState::Error(format!(
+                        "Could not recognise \"{}\"",
+                        text
+                    )))
+                }
+                1 => paste(model, formats[0], &text),
+                _ => model.paste = Some(PasteState::Ambiguous(text, formats)),
+            }
+        }
+        Msg::PasteAs(format) => {
+            if let Some(PasteState::Ambiguous(text, _)) = model.paste.take() {
+                paste(model, format, &text);
+            }
+        }
+        Msg::DismissPaste => model.paste = None,
+        Msg::ToggleExplainMode => {
+            model.explain_mode = !model.explain_mode;
+            model.explained_bit = None;
+        }
+        Msg::ToggleNibbleGroups => model.group_nibbles = !model.group_nibbles,
+        Msg::ToggleByteOrder => model.little_endian = !model.little_endian,
+        Msg::ExplainBit(b) => model.explained_bit = b,
+        Msg::HoverBit(b) => model.hovered_bit = b,
+        Msg::Load(encoding) => model.encoding = encoding,
+        Msg::SetAllocatorWidth(width) => model.allocator.width = width,
+        Msg::SetAllocatorMin(min) => model.allocator.min = min,
+        Msg::SetAllocatorMax(max) => model.allocator.max = max,
+        Msg::ToggleFixedPoint => {
+            model.fixed_point = match model.fixed_point {
+                Some(_) => None,
+                None => Some(FixedPoint {
+                    fraction_bits: model.encoding.significand_bits.len(),
+                    signed: true,
+                }),
+            }
+        }
+        Msg::StartRecording => {
+            model.recording = Some(Recording::new(
+                &model.encoding,
+                &model.second,
+                model.rounding,
+            ));
+        }
+        Msg::StopRecording => {
+            if let Some(recording) = model.recording.take() {
+                model.replay = Some(Replay::new(recording));
+                model.replay_timer = None;
+                model.recording_error = None;
+            }
+        }
+        Msg::LoadRecording(script) => match Recording::parse(&script) {
+            Ok(recording) => {
+                model.replay = Some(Replay::new(recording));
+                model.replay_timer = None;
+                model.recording_error = None;
+            }
+            Err(e) => model.recording_error = Some(e),
+        },
+        Msg::CopyRecording => {
+            if let Some(replay) = &model.replay {
+                effects.push(Effect::CopyToClipboard(replay.recording.script()));
+            }
+        }
+        Msg::RewindReplay => {
+            if let Some(replay) = &mut model.replay {
+                replay.position = 0;
+                model.encoding = replay.recording.encoding.clone();
+                model.second = replay.recording.second.clone();
+                model.rounding = replay.recording.rounding;
+            }
+        }
+        Msg::ReplayStep => {
+            if let Some(replay) = &mut model.replay {
+                let step = replay.advance();
+                if replay.is_finished() {
+                    model.replay_timer = None;
+                }
+                if let Some(step) = step {
+                    return handle(step, model, now);
+                }
+            }
+        }
+        Msg::PlayReplay => effects.push(Effect::StartReplay),
+        Msg::PauseReplay => model.replay_timer = None,
+        Msg::CloseReplay => {
+            model.replay = None;
+            model.replay_timer = None;
+        }
+        Msg::ToggleStateExport => model.show_state = !model.show_state,
+        Msg::ImportState(text) => {
+            model.state_error = model.load_state(&text).err();
+        }
+        Msg::CopyState => effects.push(Effect::CopyToClipboard(model.state().to_string())),
+        Msg::SetFractionBits(n) => {
+            if let Some(fixed_point) = &mut model.fixed_point {
+                fixed_point.fraction_bits = n;
+            }
+        }
+        Msg::ToggleFixedPointSigned => {
+            if let Some(fixed_point) = &mut model.fixed_point {
+                fixed_point.signed = !fixed_point.signed;
+            }
+        }
+        Msg::TogglePosit => {
+            model.posit = match model.posit {
+                Some(_) => None,
+                None => Some(Posit { es: 2 }),
+            }
+        }
+        Msg::SetPositEs(es) => {
+            if let Some(posit) = &mut model.posit {
+                posit.es = es;
+            }
+        }
+        Msg::ToggleDecimal => {
+            model.decimal = match model.decimal {
+                Some(_) => None,
+                None => Some(DecimalEncoding::Bid),
+            }
+        }
+        Msg::SetDecimalEncoding(encoding) => {
+            if model.decimal.is_some() {
+                model.decimal = Some(encoding);
+            }
+        }
+        Msg::ToggleTwosComplement => model.twos_complement = !model.twos_complement,
+        Msg::SetFromUnsigned(text) => match parse_unsigned(&text, &model.encoding) {
+            Ok(encoding) => {
+                model.encoding = encoding;
+                model.integer_error = None;
+            }
+            Err(e) => model.integer_error = Some(e),
+        },
+        Msg::ToggleReinterpretation(r) => {
+            if let Some(i) = model.reinterpretations.iter().position(|&x| x == r) {
+                model.reinterpretations.remove(i);
+            } else {
+                model.reinterpretations.push(r);
+            }
+        }
+        Msg::SaveInterpolationStart => model.interpolation.start = Some(model.encoding.clone()),
+        Msg::SaveInterpolationEnd => model.interpolation.end = Some(model.encoding.clone()),
+        Msg::PlayInterpolation => {
+            let interpolation = &mut model.interpolation;
+            if let (Some(start), Some(end)) = (&interpolation.start, &interpolation.end) {
+                if start.same_layout(end) {
+                    interpolation.frames.clear();
+                    effects.push(Effect::StartInterpolation);
+                }
+            }
+        }
+        Msg::StopInterpolation => model.interpolation.timer = None,
+        Msg::InterpolationFrame => {
+            let interpolation = &mut model.interpolation;
+            if let (Some(start), Some(end)) = (&interpolation.start, &interpolation.end) {
+                #[allow(clippy::cast_possible_truncation)]
+                let frame = Interpolation::frame(start, end, interpolation.frames.len() as u32);
+                interpolation.frames.push((frame.to_bits(), frame.value()));
+                model.encoding = frame;
+            }
+            if interpolation.frames.len() > INTERPOLATION_FRAMES as usize {
+                interpolation.timer = None;
+            }
+        }
+        Msg::StepPattern => model.encoding = model.encoding.incremented(),
+        Msg::PlaySteps => effects.push(Effect::StartStepping),
+        Msg::PauseSteps => model.stepping = None,
+        Msg::SteppingFrame => {
+            model.encoding = model.encoding.incremented();
+            // Past infinity there are only NaNs, which all look alike, so stop there.
+            if model.encoding.is_infinite() || model.encoding.is_nan() {
+                model.stepping = None;
+            }
+        }
+        Msg::SetFromOrdinal(text) => match parse_ordinal(&text, &model.encoding) {
+            Ok(encoding) => {
+                model.encoding = encoding;
+                model.ordinal_error = None;
+            }
+            Err(e) => model.ordinal_error = Some(e),
+        },
+        Msg::SelectNibble(i) => model.nibble_cursor = i,
+        Msg::HexKey(key) => {
+            let count = model.encoding.nibble_count();
+            if let Some(i) = model.nibble_cursor.filter(|&i| i < count) {
+                model.nibble_cursor = match key.as_str() {
+                    "ArrowLeft" | "Backspace" => Some(i.saturating_sub(1)),
+                    "ArrowRight" => Some((i + 1).min(count - 1)),
+                    "Escape" => None,
+                    _ => match u64::from_str_radix(&key, 16) {
+                        Ok(digit) => {
+                            model.encoding.set_nibble(i, digit);
+                            Some((i + 1).min(count - 1))
+                        }
+                        Err(_) => Some(i),
                     },
-                    input_ev(Ev::Input, |i| Msg::SetSigSize(
-                        i.parse().expect("Slider must report number")
-                    )),
-                ],
-            ],
-        ]
-    ]
+                }
+            }
+        }
+        Msg::Copy(format) => {
+            effects.push(Effect::CopyToClipboard(format.format(&model.encoding)));
+            effects.push(Effect::ScheduleHideCopied);
+            model.copied = Some(format);
+        }
+        Msg::HideCopied => model.copied = None,
+        Msg::BitFocused(i) => model.bit_cursor = i,
+        Msg::StartDrag(i) => {
+            if let Some(bit) = model.encoding.bit(i) {
+                model.encoding.set_bit(bit.field, bit.weight, !bit.value);
+                model.drag = Some(!bit.value);
+            }
+        }
+        Msg::DragOver(i) => {
+            if let (Some(bit), Some(value)) = (model.encoding.bit(i), model.drag) {
+                model.encoding.set_bit(bit.field, bit.weight, value);
+            }
+        }
+        Msg::EndDrag => model.drag = None,
+        Msg::Randomize(seed) => {
+            for (bit, random) in model.encoding.bits_mut().zip(random_bits(seed)) {
+                *bit = random;
+            }
+        }
+        Msg::FillField(t, value) => model.encoding.fill_field(t, value),
+        Msg::Clear => model.encoding = model.encoding.zero(false),
+        Msg::SetSpecial(special) => {
+            if let Some(encoding) = special.in_format(&model.encoding) {
+                model.encoding = encoding;
+            }
+        }
+        Msg::BitKey(key) => {
+            let encoding = &model.encoding;
+            let last = encoding.len() - 1;
+            let i = model.bit_cursor.min(last);
+            let field = |i: usize| {
+                let bit = encoding.bit(i).expect("index within the encoding");
+                encoding.field_range(bit.field)
+            };
+            // Home and End go to the ends of the field, or of the next one over if already
+            // there.
+            model.bit_cursor = match key.as_str() {
+                "ArrowLeft" => i.saturating_sub(1),
+                "ArrowRight" => (i + 1).min(last),
+                "Home" if i == field(i).start => i.checked_sub(1).map_or(0, |j| field(j).start),
+                "Home" => field(i).start,
+                "End" if i + 1 == field(i).end => field((i + 1).min(last)).end - 1,
+                "End" => field(i).end - 1,
+                _ => i,
+            };
+            effects.push(Effect::FocusBit(model.bit_cursor));
+        }
+        Msg::SaveSession => {
+            // Unedited sessions aren't worth offering back, and mustn't replace a saved
+            // session which the user hasn't decided about yet.
+            if model.history.len() > 1 {
+                effects.push(Effect::SaveSession);
+            }
+        }
+        Msg::RestoreSession => {
+            if let Some(encoding) = model.restorable.take() {
+                model.encoding = encoding;
+                effects.push(Effect::ClearSession);
+            }
+        }
+        Msg::DiscardSession => {
+            model.restorable = None;
+            effects.push(Effect::ClearSession);
+        }
+        Msg::ResetToDefaults => {
+            let defaults = Model::new();
+            model.encoding = defaults.encoding;
+            model.second = defaults.second;
+            model.rounding = defaults.rounding;
+            model.theme = defaults.theme;
+            model.locale = defaults.locale;
+            model.colors = defaults.colors;
+        }
+        Msg::SetCodeLanguage(language) => model.code_language = language,
+        Msg::CopyCode => effects.push(Effect::CopyToClipboard(codegen::construction(
+            &model.encoding,
+            model.code_language,
+        ))),
+        Msg::CopyTestVectors => effects.push(Effect::CopyToClipboard(codegen::test_vectors(
+            &model.encoding,
+        ))),
+        Msg::SetTestVectors(text) => model.test_vectors = text,
+        Msg::SetSummands(text) => model.summands = text,
+        Msg::SetTestOperation(operation) => model.test_operation = operation,
+        Msg::ChooseDataFile(file) => effects.push(Effect::ReadFile(file)),
+        Msg::DataFileRead(name, bytes) => {
+            match bytes.and_then(|bytes| DataFile::new(name, bytes)) {
+                Ok(file) => {
+                    model.data_file = Some(file);
+                    model.data_error = None;
+                    show_element(model, 0);
+                }
+                Err(e) => model.data_error = Some(e),
+            }
+        }
+        Msg::SetDataElement(element) => {
+            if let Some(file) = &mut model.data_file {
+                file.element = element;
+                show_element(model, 0);
+            }
+        }
+        Msg::ToggleDataEndianness => {
+            if let Some(file) = &mut model.data_file {
+                file.little_endian = !file.little_endian;
+                let index = file.index;
+                show_element(model, index);
+            }
+        }
+        Msg::ShowElement(i) => show_element(model, i),
+        Msg::NextNan => {
+            if let Some(i) = model.data_file.as_ref().and_then(|f| f.next_nan(f.index)) {
+                show_element(model, i);
+            }
+        }
+        Msg::CloseDataFile => model.data_file = None,
+        Msg::SetOverflow(overflow) => model.encoding.options.overflow = overflow,
+        Msg::ToggleInfinities => {
+            model.encoding.options.infinities = !model.encoding.options.infinities
+        }
+        Msg::ToggleNans => model.encoding.options.nans = !model.encoding.options.nans,
+        Msg::ToggleImplicitBit => {
+            model.encoding.options.implicit_bit = !model.encoding.options.implicit_bit
+        }
+        Msg::SetBias(text) => match text.trim() {
+            "" => {
+                model.encoding.options.bias = None;
+                model.bias_error = None;
+            }
+            text => match text.parse::<u64>() {
+                Ok(bias) if bias <= MAX_BIAS => {
+                    // The standard bias is stored as no override, so presets still match.
+                    model.encoding.options.bias =
+                        Some(bias).filter(|&bias| bias != model.encoding.standard_bias());
+                    model.bias_error = None;
+                }
+                _ => {
+                    model.bias_error = Some(format!(
+                        "The bias must be a whole number up to {}",
+                        MAX_BIAS
+                    ))
+                }
+            },
+        },
+        Msg::SetFromDecimal(text) => match parse_decimal(&text, &model.encoding, model.rounding) {
+            Ok((encoding, flags)) => {
+                model.encoding = encoding;
+                model.flags |= flags;
+                model.decimal_error = None;
+                model.decimal_input = Some(text);
+            }
+            Err(e) => model.decimal_error = Some(e),
+        },
+        Msg::StartWalkthrough(text) => {
+            match Walkthrough::new(&text, &model.encoding, model.rounding) {
+                Ok(walkthrough) => {
+                    model.encoding = walkthrough.result.clone();
+                    model.decimal_error = None;
+                    model.decimal_input = Some(text);
+                    model.walkthrough = Some(walkthrough);
+                }
+                Err(e) => model.decimal_error = Some(e),
+            }
+        }
+        Msg::ShowWalkthroughStep(i) => {
+            if let Some(walkthrough) = &mut model.walkthrough {
+                walkthrough.step = i.min(walkthrough.steps.len() - 1);
+            }
+        }
+        Msg::CloseWalkthrough => model.walkthrough = None,
+        Msg::StartQuiz(seed) => model.quiz = Some(Quiz::new(&model.encoding, random_bits(seed))),
+        Msg::NextQuestion(seed) => {
+            if let Some(quiz) = &mut model.quiz {
+                quiz.next(&model.encoding, random_bits(seed));
+            }
+        }
+        Msg::CheckQuizBits => {
+            if let Some(quiz) = &mut model.quiz {
+                quiz.check_bits(&model.encoding);
+            }
+        }
+        Msg::AnswerQuiz(text) => {
+            if let Some(quiz) = &mut model.quiz {
+                quiz.check_value(&text);
+            }
+        }
+        Msg::RevealAnswer => {
+            if let Some(quiz) = &mut model.quiz {
+                quiz.reveal();
+            }
+        }
+        Msg::StopQuiz => model.quiz = None,
+        Msg::SetFromHex(text) => {
+            match PasteFormat::Hex.parse(&text, &model.encoding, model.rounding) {
+                Ok((encoding, _)) => {
+                    model.encoding = encoding;
+                    model.hex_error = None;
+                }
+                Err(e) => model.hex_error = Some(e),
+            }
+        }
+        Msg::SetFromBinary(text) => {
+            match PasteFormat::Binary.parse(&text, &model.encoding, model.rounding) {
+                Ok((encoding, _)) => {
+                    model.encoding = encoding;
+                    model.binary_error = None;
+                }
+                Err(e) => model.binary_error = Some(e),
+            }
+        }
+        Msg::SetFromHexFloat(text) => {
+            match PasteFormat::HexFloat.parse(&text, &model.encoding, model.rounding) {
+                Ok((encoding, flags)) => {
+                    model.encoding = encoding;
+                    model.flags |= flags;
+                    model.hex_float_error = None;
+                }
+                Err(e) => model.hex_float_error = Some(e),
+            }
+        }
+        Msg::ApplyPreset(preset) => {
+            let format = preset.zero();
+            model.pending_layout = None;
+            model.encoding = match model.encoding.convert(&format, model.rounding) {
+                Some((converted, flags)) => {
+                    model.flags |= flags;
+                    converted
+                }
+                None => format,
+            };
+        }
+        Msg::StepUp => {
+            if let Some(next) = model.encoding.next_up() {
+                model.encoding = next;
+            }
+        }
+        Msg::StepDown => {
+            if let Some(next) = model.encoding.next_down() {
+                model.encoding = next;
+            }
+        }
+        Msg::ApplyUnary(operator) => {
+            let (result, flags) = apply(operator, &model.encoding, model.rounding);
+            model.encoding = result;
+            model.flags |= flags;
+        }
+        Msg::SetRounding(rounding) => model.rounding = rounding,
+        Msg::ToggleSecondBit(i) => model.second = model.second.toggled(i),
+        Msg::SetSecondFromDecimal(text) => {
+            match parse_decimal(&text, &model.encoding, model.rounding) {
+                Ok((second, flags)) => {
+                    model.second = second;
+                    model.flags |= flags;
+                    model.second_error = None;
+                }
+                Err(e) => model.second_error = Some(e),
+            }
+        }
+        Msg::SwapOperands => mem::swap(&mut model.encoding, &mut model.second),
+        Msg::Calculate(operator) => {
+            model.operator = Some(operator);
+            model.calculate();
+        }
+        Msg::UseResult => {
+            if let Some((result, _)) = &model.result {
+                model.encoding = result.clone();
+            }
+        }
+        Msg::ClearFlags => model.flags = Flags::default(),
+        Msg::Undo => {
+            if let Some(encoding) = model.undo.pop() {
+                model.redo.push(mem::replace(&mut model.encoding, encoding));
+            }
+        }
+        Msg::Redo => {
+            if let Some(encoding) = model.redo.pop() {
+                model.undo.push(mem::replace(&mut model.encoding, encoding));
+            }
+        }
+    }
+    // The second operand follows the current value into any new format.
+    if !model.second.same_layout(&model.encoding) || model.second.options != model.encoding.options
+    {
+        model.second = match model.second.convert(&model.encoding, model.rounding) {
+            Some((second, flags)) => {
+                model.flags |= flags;
+                second
+            }
+            None => model.encoding.zero(false),
+        };
+    }
+    if model.encoding != before
+        || model.second != second_before
+        || model.rounding != rounding_before
+    {
+        model.calculate();
+    }
+    model.mark_flipped(&before);
+    // Animation frames and navigation of recorded state aren't worth a browser history entry.
+    if model.encoding != before && !from_url && label.is_some() {
+        // Dragging a slider or typing out a pattern produces a stream of edits which should
+        // only take up a single entry in the browser history.
+        effects.push(Effect::Navigate {
+            replace: coalesce && model.last_pushed == Some(kind),
+        });
+        model.last_pushed = Some(kind);
+    }
+    if model.encoding != before {
+        model.ghost = Some(before.value());
+        model.ghost_parity = !model.ghost_parity;
+    }
+    if let Some(label) = label {
+        if let Some(timeline) = &mut model.timeline {
+            timeline.push(label.clone(), &model.encoding, now);
+        }
+        if model.encoding != before {
+            model.record(label);
+            if !undoing {
+                model.undo.push(before);
+                if model.undo.len() > HISTORY_LEN {
+                    model.undo.remove(0);
+                }
+                model.redo.clear();
+            }
+        }
+    }
+    effects
 }
 
-fn view_bits(model: &Model) -> Node<Msg> {
-    div![
-        C!["bits"],
-        iter::once(&model.sign_bit)
-            .zip(iter::repeat(BitType::Sign))
-            .chain(
-                model
-                    .exponent_bits
-                    .iter()
-                    .zip(iter::repeat(BitType::Exponent))
-            )
-            .chain(
-                model
-                    .significand_bits
-                    .iter()
-                    .zip(iter::repeat(BitType::Significand))
-            )
-            .enumerate()
-            .map(|(i, (&b, t))| button![
-                C!["bit"],
-                style! {St::BackgroundColor => t.color() },
-                if b { "1" } else { "0" },
-                ev(Ev::Click, move |_| Msg::ToggleBit(i))
-            ]),
-    ]
+/// An endless stream of bits from SplitMix64, which is plenty to scatter a seed across the
+/// widest formats.
+fn random_bits(seed: u64) -> impl Iterator<Item = bool> {
+    iter::successors(Some(seed), |state| {
+        Some(state.wrapping_add(0x9E37_79B9_7F4A_7C15))
+    })
+    .skip(1)
+    .flat_map(|state| {
+        let z = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        let z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        let word = z ^ (z >> 31);
+        (0..64).map(move |i| (word >> i) & 1 == 1)
+    })
 }
 
-fn view_value(model: &Model) -> Node<Msg> {
-    div![id!["result"], C!["value"], {
-        let value = model.value();
-        let abs_val = value.abs();
-        if abs_val == 0.0 || (1.0e-10..1.0e10).contains(&abs_val) {
-            format!("{:?}", value)
-        } else {
-            format!("{:e}", value)
+/// Loads element `i` of the data file into the editor, if there is one.
+fn show_element(model: &mut Model, i: usize) {
+    if let Some(file) = &mut model.data_file {
+        if let Some(encoding) = file.get(i) {
+            file.index = i;
+            model.encoding = encoding;
         }
-    }]
+    }
+}
+
+fn paste(model: &mut Model, format: PasteFormat, text: &str) {
+    match format.parse(text, &model.encoding, model.rounding) {
+        Ok((encoding, flags)) => {
+            model.encoding = encoding;
+            model.flags |= flags;
+            model.paste = None;
+        }
+        Err(e) => model.paste = Some(PasteState::Error(e)),
+    }
+}
+
+/// Whether `target` is a text field, which handles pasting and undo itself.
+fn is_text_field(target: Option<web_sys::EventTarget>) -> bool {
+    target.map_or(false, |target| {
+        target.has_type::<web_sys::HtmlInputElement>()
+            || target.has_type::<web_sys::HtmlTextAreaElement>()
+    })
+}
+
+/// The browser's local storage, unless it is disabled.
+fn local_storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// Applies the theme's class to the root element, whose background lies outside the app.
+fn set_theme(theme: Theme) {
+    if let Some(root) = document().document_element() {
+        root.set_class_name(theme.class());
+    }
+}
+
+/// Tells the browser and screen readers which language the page is in.
+fn set_language(locale: Locale) {
+    if let Some(root) = document().document_element() {
+        let _ = root.set_attribute("lang", locale.tag());
+    }
+}
+
+/// Shows the value in the tab bar so that several open tabs can be told apart.
+fn set_document_title(encoding: &Encoding) {
+    document().set_title(&format!(
+        "{} ({})",
+        format_value(encoding.value()),
+        encoding.hex()
+    ));
 }
 
 // ------ ------
 //     Start
 // ------ ------
 
+/// Links the web app manifest and registers the service worker which caches the app for
+/// offline use.
+fn register_offline_support() {
+    let document = document();
+    if let (Ok(link), Some(head)) = (document.create_element("link"), document.head()) {
+        if link.set_attribute("rel", "manifest").is_ok()
+            && link.set_attribute("href", "/manifest.webmanifest").is_ok()
+        {
+            let _ = head.append_child(&link);
+        }
+    }
+
+    let navigator = window().navigator();
+    // Service workers are only exposed in secure contexts.
+    if js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        let _ = navigator.service_worker().register("/sw.js");
+    }
+}
+
 // (This function is invoked by `init` function in `index.html`.)
 #[wasm_bindgen(start)]
 pub fn start() {
+    register_offline_support();
     // Mount the `app` to the element with the `id` "app".
-    App::start("app", init, update, view);
+    embed::register(App::start("app", init, update, view));
 }
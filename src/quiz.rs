@@ -0,0 +1,210 @@
+//! Practice questions on the current format: encoding a value in bits, or reading the value of a
+//! bit pattern. Every answer is a small, exactly representable value, so that it has a single
+//! encoding and a short decimal form.
+
+use crate::{
+    encoding::{BitType, Encoding, RoundingMode},
+    i18n::{Line, Sentence},
+    text::{binary_fraction, format_encoding, parse_decimal},
+};
+
+/// Exponents questions are drawn from, before clamping to the format's normal range.
+const EXPONENTS: (i32, i32) = (-4, 6);
+
+/// Fraction bits after the leading one which may be set, keeping values easy to work out.
+const FRACTION_BITS: usize = 3;
+
+/// What a question asks for.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Kind {
+    /// Set the bit row to encode a given value.
+    BuildBits,
+    /// Type the value a given bit pattern encodes.
+    ReadBits,
+}
+
+/// Where the current question stands.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Status {
+    Unanswered,
+    Wrong,
+    Right,
+    Revealed,
+}
+
+/// A practice session: the current question and the score so far.
+#[derive(Clone, PartialEq)]
+pub struct Quiz {
+    pub kind: Kind,
+    /// The encoding which answers the question.
+    pub answer: Encoding,
+    pub status: Status,
+    /// Answers checked for the current question.
+    pub attempts: u32,
+    /// Questions answered right without revealing the answer.
+    pub solved: u32,
+    pub asked: u32,
+}
+
+impl Quiz {
+    /// Starts a session with a question in the format of `format`.
+    pub fn new(format: &Encoding, random: impl Iterator<Item = bool>) -> Self {
+        let (kind, answer) = question(format, random);
+        Self {
+            kind,
+            answer,
+            status: Status::Unanswered,
+            attempts: 0,
+            solved: 0,
+            asked: 1,
+        }
+    }
+
+    /// Moves on to a new question in the format of `format`, keeping the score.
+    pub fn next(&mut self, format: &Encoding, random: impl Iterator<Item = bool>) {
+        let (kind, answer) = question(format, random);
+        *self = Self {
+            kind,
+            answer,
+            status: Status::Unanswered,
+            attempts: 0,
+            asked: self.asked + 1,
+            ..*self
+        };
+    }
+
+    /// Whether the question has been settled, either way, so that answers no longer count.
+    pub fn is_over(&self) -> bool {
+        matches!(self.status, Status::Right | Status::Revealed)
+    }
+
+    /// Checks an encoding built in the bit row against the answer.
+    pub fn check_bits(&mut self, encoding: &Encoding) {
+        let right = encoding.same_layout(&self.answer) && encoding.bits().eq(self.answer.bits());
+        self.record(right);
+    }
+
+    /// Checks a typed value against the answer, which it must equal exactly.
+    pub fn check_value(&mut self, text: &str) {
+        let right = parse_decimal(text, &self.answer, RoundingMode::TiesToEven)
+            .map_or(false, |(encoding, flags)| {
+                !flags.inexact && encoding.bits().eq(self.answer.bits())
+            });
+        self.record(right);
+    }
+
+    fn record(&mut self, right: bool) {
+        if self.is_over() {
+            return;
+        }
+        self.attempts += 1;
+        if right {
+            self.status = Status::Right;
+            self.solved += 1;
+        } else {
+            self.status = Status::Wrong;
+        }
+    }
+
+    pub fn reveal(&mut self) {
+        if !self.is_over() {
+            self.status = Status::Revealed;
+        }
+    }
+
+    /// How the answer's fields make its value, a line at a time.
+    pub fn explanation(&self) -> Vec<Line> {
+        let answer = &self.answer;
+        let stored_exponent = answer.field(BitType::Exponent);
+        let bias = answer.bias();
+        let (significand, scale) = answer.scaled_significand();
+        #[allow(clippy::cast_sign_loss)]
+        let fraction_len = (answer.precision() - 1) as usize;
+        let leading = u8::from(answer.leading_bit());
+        let fraction: String = answer.significand_bits[usize::from(!answer.options.implicit_bit)..]
+            .iter()
+            .map(|&b| if b { '1' } else { '0' })
+            .collect();
+        let exponent = scale + answer.precision() - 1;
+        let digits = binary_fraction(&significand, fraction_len);
+        let significand = if digits.contains('.') {
+            digits.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &digits
+        };
+        vec![
+            Line::new(
+                if answer.sign_bit {
+                    Sentence::AnswerNegative
+                } else {
+                    Sentence::AnswerPositive
+                },
+                Vec::new(),
+            ),
+            if stored_exponent == 0 {
+                Line::new(
+                    Sentence::AnswerSubnormal,
+                    vec![bias.to_string(), exponent.to_string()],
+                )
+            } else {
+                Line::new(
+                    Sentence::AnswerScaled,
+                    vec![
+                        stored_exponent.to_string(),
+                        stored_exponent.to_string(),
+                        bias.to_string(),
+                        exponent.to_string(),
+                    ],
+                )
+            },
+            Line::new(
+                Sentence::AnswerSignificand,
+                vec![leading.to_string(), fraction, significand.to_owned()],
+            ),
+            Line::new(
+                Sentence::AnswerTogether,
+                vec![
+                    if answer.sign_bit { "\u{2212}" } else { "" }.to_owned(),
+                    significand.to_owned(),
+                    exponent.to_string(),
+                    format_encoding(answer),
+                ],
+            ),
+        ]
+    }
+}
+
+/// A question in the format of `format`, drawing its choices from `random`.
+fn question(format: &Encoding, mut random: impl Iterator<Item = bool>) -> (Kind, Encoding) {
+    let mut next = || random.next().unwrap_or(false);
+    let kind = if next() {
+        Kind::BuildBits
+    } else {
+        Kind::ReadBits
+    };
+    let (low, high) = (
+        EXPONENTS.0.max(format.emin()),
+        EXPONENTS.1.min(format.emax()),
+    );
+    #[allow(clippy::cast_sign_loss)]
+    let span = (high - low + 1).max(1) as u64;
+    let pick = (0..8).fold(0, |acc, _| (acc << 1) | u64::from(next()));
+    #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+    let exponent = low + (pick % span) as i32;
+    let mut answer = match format.power_of_two(exponent) {
+        Some(power) => power,
+        // Without normal values there is no power of two to build on.
+        None => return (kind, format.max_finite(next())),
+    };
+    answer.sign_bit = next();
+    let first_fraction_bit = usize::from(!format.options.implicit_bit);
+    for bit in answer
+        .significand_bits
+        .iter_mut()
+        .skip(first_fraction_bit)
+        .take(FRACTION_BITS)
+    {
+        *bit = next();
+    }
+    (kind, answer)
+}
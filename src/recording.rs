@@ -0,0 +1,239 @@
+//! Recordings of edits which can be replayed a step at a time, so that a demonstration can be
+//! prepared before a lecture. A recording is saved as text: the starting state as `key=value`
+//! lines, then one edit per line, named after its `Msg` and followed by its arguments.
+
+use crate::{
+    arithmetic::{Operator, UnaryOperator},
+    encoding::{
+        BitType, Encoding, FormatPreset, Overflow, RoundingMode, SpecialValue, MAX_EXPONENT_BITS,
+        MAX_SIGNIFICAND_BITS,
+    },
+    Msg,
+};
+
+/// A sequence of edits and the state they start from.
+#[derive(Clone)]
+pub struct Recording {
+    pub encoding: Encoding,
+    pub second: Encoding,
+    pub rounding: RoundingMode,
+    /// The saved line of each edit, which `decode` turns back into its `Msg`.
+    pub steps: Vec<String>,
+}
+
+impl Recording {
+    /// An empty recording starting from the given state.
+    pub fn new(encoding: &Encoding, second: &Encoding, rounding: RoundingMode) -> Self {
+        Self {
+            encoding: encoding.clone(),
+            second: second.clone(),
+            rounding,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds `msg` if it is an edit which can be saved.
+    pub fn push(&mut self, msg: &Msg) {
+        self.steps.extend(encode(msg));
+    }
+
+    /// The recording as text, which `parse` reads back.
+    pub fn script(&self) -> String {
+        let mut lines = vec![
+            format!("encoding={}", self.encoding.to_fragment()),
+            format!("second={}", self.second.to_fragment()),
+            format!("rounding={}", self.rounding.name()),
+        ];
+        lines.extend(self.steps.iter().cloned());
+        lines.join("\n")
+    }
+
+    /// Reads a recording saved by `script`. The second operand and rounding direction may be
+    /// left out, but every other line must be understood.
+    pub fn parse(script: &str) -> Result<Self, String> {
+        let mut encoding = None;
+        let mut second = None;
+        let mut rounding = RoundingMode::TiesToEven;
+        let mut steps = Vec::new();
+        for (number, line) in script.lines().enumerate() {
+            let line = line.trim();
+            let error = || format!("Line {} isn't a step: {}", number + 1, line);
+            let mut parts = line.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some(""), None) => {}
+                (Some("encoding"), Some(fragment)) => {
+                    encoding = Some(Encoding::from_fragment(fragment).ok_or_else(error)?);
+                }
+                (Some("second"), Some(fragment)) => {
+                    second = Some(Encoding::from_fragment(fragment).ok_or_else(error)?);
+                }
+                (Some("rounding"), Some(name)) => {
+                    rounding =
+                        find(&RoundingMode::ALL, RoundingMode::name, name).ok_or_else(error)?;
+                }
+                _ => {
+                    decode(line).ok_or_else(error)?;
+                    steps.push(line.to_owned());
+                }
+            }
+        }
+        let encoding = encoding.ok_or_else(|| "The starting encoding is missing".to_owned())?;
+        let second = second
+            .filter(|second| second.same_layout(&encoding) && second.options == encoding.options)
+            .unwrap_or_else(|| encoding.zero(false));
+        Ok(Self {
+            encoding,
+            second,
+            rounding,
+            steps,
+        })
+    }
+}
+
+/// A recording being replayed.
+pub struct Replay {
+    pub recording: Recording,
+    /// Number of steps replayed so far.
+    pub position: usize,
+}
+
+impl Replay {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            position: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.recording.steps.len()
+    }
+
+    /// The next step to replay, moving past it.
+    pub fn advance(&mut self) -> Option<Msg> {
+        let msg = decode(self.recording.steps.get(self.position)?)?;
+        self.position += 1;
+        Some(msg)
+    }
+}
+
+/// The line saving `msg`, or `None` if it isn't an edit of the value, the format or the
+/// operands, or depends on more than its arguments, such as a file. Clicks and drags are saved
+/// as the toggles they make.
+fn encode(msg: &Msg) -> Option<String> {
+    let line = match msg {
+        Msg::SetLayout(e, s) => format!("SetLayout {} {}", e, s),
+        Msg::SetBit(t, i, b) => format!("SetBit {} {} {}", t.name(), i, u8::from(*b)),
+        Msg::SetField(t, v) => format!("SetField {} {}", t.name(), v),
+        Msg::SetPattern(bits) => format!("SetPattern {:X}", bits),
+        Msg::ToggleBit(i) | Msg::StartDrag(i) | Msg::DragOver(i) => format!("ToggleBit {}", i),
+        Msg::Load(encoding) => format!("Load {}", encoding.to_fragment()),
+        Msg::Randomize(seed) => format!("Randomize {}", seed),
+        Msg::StepPattern => "StepPattern".to_owned(),
+        Msg::FillField(t, b) => format!("FillField {} {}", t.name(), u8::from(*b)),
+        Msg::Clear => "Clear".to_owned(),
+        Msg::SetSpecial(special) => format!("SetSpecial {}", special.name()),
+        Msg::SetOverflow(overflow) => format!("SetOverflow {}", overflow.name()),
+        Msg::ToggleInfinities => "ToggleInfinities".to_owned(),
+        Msg::ToggleNans => "ToggleNans".to_owned(),
+        Msg::ToggleImplicitBit => "ToggleImplicitBit".to_owned(),
+        Msg::SetBias(text) => format!("SetBias {}", text.trim()),
+        Msg::SetFromDecimal(text) => format!("SetFromDecimal {}", text.trim()),
+        Msg::SetFromHex(text) => format!("SetFromHex {}", text.trim()),
+        Msg::SetFromBinary(text) => format!("SetFromBinary {}", text.trim()),
+        Msg::SetFromHexFloat(text) => format!("SetFromHexFloat {}", text.trim()),
+        Msg::SetFromUnsigned(text) => format!("SetFromUnsigned {}", text.trim()),
+        Msg::SetFromOrdinal(text) => format!("SetFromOrdinal {}", text.trim()),
+        Msg::ApplyPreset(preset) => format!("ApplyPreset {}", preset.name()),
+        Msg::StepUp => "StepUp".to_owned(),
+        Msg::StepDown => "StepDown".to_owned(),
+        Msg::ApplyUnary(operator) => format!("ApplyUnary {}", operator.name()),
+        Msg::SetRounding(rounding) => format!("SetRounding {}", rounding.name()),
+        Msg::ToggleSecondBit(i) => format!("ToggleSecondBit {}", i),
+        Msg::SetSecondFromDecimal(text) => format!("SetSecondFromDecimal {}", text.trim()),
+        Msg::SwapOperands => "SwapOperands".to_owned(),
+        Msg::Calculate(operator) => format!("Calculate {}", operator.symbol()),
+        Msg::UseResult => "UseResult".to_owned(),
+        Msg::ClearFlags => "ClearFlags".to_owned(),
+        Msg::Undo => "Undo".to_owned(),
+        Msg::Redo => "Redo".to_owned(),
+        _ => return None,
+    };
+    // Arguments may be empty, as for the standard bias.
+    Some(line.trim_end().to_owned())
+}
+
+/// The edit saved in `line` by `encode`.
+fn decode(line: &str) -> Option<Msg> {
+    let mut parts = line.splitn(2, ' ');
+    let name = parts.next()?;
+    let rest = parts.next().unwrap_or("").trim();
+    let mut words = rest.split_whitespace();
+    let mut number = || words.next().and_then(|word| word.parse().ok());
+    let msg = match name {
+        "SetLayout" => {
+            let (e, s) = (number()?, number()?);
+            // Only the layouts the sliders offer, which all have a standard bias.
+            if !(1..=MAX_EXPONENT_BITS).contains(&e) || !(1..=MAX_SIGNIFICAND_BITS).contains(&s) {
+                return None;
+            }
+            Msg::SetLayout(e, s)
+        }
+        "SetBit" | "SetField" | "FillField" => {
+            let mut words = rest.split_whitespace();
+            let t = find(&BitType::ALL, BitType::name, words.next()?)?;
+            let first = words.next()?;
+            match name {
+                "SetBit" => Msg::SetBit(t, first.parse().ok()?, bit(words.next()?)?),
+                "SetField" => Msg::SetField(t, first.parse().ok()?),
+                _ => Msg::FillField(t, bit(first)?),
+            }
+        }
+        "SetPattern" => Msg::SetPattern(u64::from_str_radix(rest, 16).ok()?),
+        "ToggleBit" => Msg::ToggleBit(number()?),
+        "Load" => Msg::Load(Encoding::from_fragment(rest)?),
+        "Randomize" => Msg::Randomize(rest.parse().ok()?),
+        "StepPattern" => Msg::StepPattern,
+        "Clear" => Msg::Clear,
+        "SetSpecial" => Msg::SetSpecial(find(&SpecialValue::ALL, SpecialValue::name, rest)?),
+        "SetOverflow" => Msg::SetOverflow(find(&Overflow::ALL, Overflow::name, rest)?),
+        "ToggleInfinities" => Msg::ToggleInfinities,
+        "ToggleNans" => Msg::ToggleNans,
+        "ToggleImplicitBit" => Msg::ToggleImplicitBit,
+        "SetBias" => Msg::SetBias(rest.to_owned()),
+        "SetFromDecimal" => Msg::SetFromDecimal(rest.to_owned()),
+        "SetFromHex" => Msg::SetFromHex(rest.to_owned()),
+        "SetFromBinary" => Msg::SetFromBinary(rest.to_owned()),
+        "SetFromHexFloat" => Msg::SetFromHexFloat(rest.to_owned()),
+        "SetFromUnsigned" => Msg::SetFromUnsigned(rest.to_owned()),
+        "SetFromOrdinal" => Msg::SetFromOrdinal(rest.to_owned()),
+        "ApplyPreset" => Msg::ApplyPreset(find(&FormatPreset::ALL, FormatPreset::name, rest)?),
+        "StepUp" => Msg::StepUp,
+        "StepDown" => Msg::StepDown,
+        "ApplyUnary" => Msg::ApplyUnary(find(&UnaryOperator::ALL, UnaryOperator::name, rest)?),
+        "SetRounding" => Msg::SetRounding(find(&RoundingMode::ALL, RoundingMode::name, rest)?),
+        "ToggleSecondBit" => Msg::ToggleSecondBit(number()?),
+        "SetSecondFromDecimal" => Msg::SetSecondFromDecimal(rest.to_owned()),
+        "SwapOperands" => Msg::SwapOperands,
+        "Calculate" => Msg::Calculate(find(&Operator::ALL, Operator::symbol, rest)?),
+        "UseResult" => Msg::UseResult,
+        "ClearFlags" => Msg::ClearFlags,
+        "Undo" => Msg::Undo,
+        "Redo" => Msg::Redo,
+        _ => return None,
+    };
+    Some(msg)
+}
+
+/// The item of `all` called `name`.
+fn find<T: Copy>(all: &[T], to_name: fn(T) -> &'static str, name: &str) -> Option<T> {
+    all.iter().copied().find(|&item| to_name(item) == name)
+}
+
+fn bit(word: &str) -> Option<bool> {
+    match word {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
@@ -0,0 +1,181 @@
+//! Naive and Kahan compensated summation side by side, checked against the exact sum of the
+//! same values, which is worked out with integers.
+
+use crate::{
+    arithmetic::{calculate, Operator},
+    bignum::BigUint,
+    encoding::{Encoding, Flags, RoundingMode},
+    text::format_scaled,
+};
+use std::cmp::Ordering;
+
+/// The state of both sums after adding one more value.
+pub struct Step {
+    pub input: Encoding,
+    /// The running sum of plain floating point additions.
+    pub naive: Encoding,
+    pub kahan: Encoding,
+    /// What Kahan's sum has lost so far, negated, to be taken off the next input.
+    pub compensation: Encoding,
+}
+
+/// Both sums of a list of values in one format.
+pub struct Summation {
+    pub steps: Vec<Step>,
+    /// The exact sum of the inputs, or `None` if one of them isn't finite.
+    pub exact: Option<Exact>,
+}
+
+impl Summation {
+    /// Sums `inputs`, which must share a format, rounding each operation in the direction of
+    /// `rounding`. `None` if an operation is invalid in a format without NaNs.
+    pub fn new(inputs: &[Encoding], rounding: RoundingMode) -> Option<Self> {
+        let zero = inputs.first()?.zero(false);
+        let operate =
+            |operator, a: &Encoding, b: &Encoding| calculate(operator, a, b, rounding).map(|r| r.0);
+        let (mut naive, mut kahan, mut compensation) = (zero.clone(), zero.clone(), zero);
+        let mut steps = Vec::new();
+        for input in inputs {
+            naive = operate(Operator::Add, &naive, input)?;
+            // y = x − c, t = s + y, c = (t − s) − y, s = t
+            let y = operate(Operator::Subtract, input, &compensation)?;
+            let t = operate(Operator::Add, &kahan, &y)?;
+            compensation = operate(
+                Operator::Subtract,
+                &operate(Operator::Subtract, &t, &kahan)?,
+                &y,
+            )?;
+            kahan = t;
+            steps.push(Step {
+                input: input.clone(),
+                naive: naive.clone(),
+                kahan: kahan.clone(),
+                compensation: compensation.clone(),
+            });
+        }
+        let exact = inputs
+            .iter()
+            .map(Exact::of)
+            .try_fold(Exact::zero(), |sum, x| Some(sum.add(&x?)));
+        Some(Self { steps, exact })
+    }
+}
+
+/// A signed binary fraction, `±significand × 2^scale`.
+#[derive(Clone, PartialEq)]
+pub struct Exact {
+    pub negative: bool,
+    pub significand: BigUint,
+    pub scale: i32,
+}
+
+impl Exact {
+    pub fn zero() -> Self {
+        Self {
+            negative: false,
+            significand: BigUint::from_u64(0),
+            scale: 0,
+        }
+    }
+
+    /// The value of `encoding`, or `None` if it isn't finite.
+    pub fn of(encoding: &Encoding) -> Option<Self> {
+        if encoding.is_nan() || encoding.is_infinite() {
+            return None;
+        }
+        let (significand, scale) = encoding.scaled_significand();
+        Some(Self {
+            negative: encoding.sign_bit,
+            significand,
+            scale,
+        })
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    pub fn add(&self, other: &Self) -> Self {
+        let scale = self.scale.min(other.scale);
+        let a = self.significand.shl((self.scale - scale) as usize);
+        let b = other.significand.shl((other.scale - scale) as usize);
+        let (negative, significand) = if self.negative == other.negative {
+            (self.negative, a.add(&b))
+        } else {
+            match a.cmp(&b) {
+                Ordering::Less => (other.negative, b.sub(&a)),
+                _ => (self.negative, a.sub(&b)),
+            }
+        };
+        Self {
+            negative: negative && !significand.is_zero(),
+            significand,
+            scale,
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&Self {
+            negative: !other.negative,
+            ..other.clone()
+        })
+    }
+
+    /// The value rounded into the format of `format`, as the best any summation could do.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn rounded(&self, format: &Encoding, rounding: RoundingMode) -> (Encoding, Flags) {
+        let one = BigUint::from_u64(1);
+        let (numerator, denominator) = if self.scale >= 0 {
+            (self.significand.shl(self.scale as usize), one)
+        } else {
+            (self.significand.clone(), one.shl(-self.scale as usize))
+        };
+        format.round_ratio(self.negative, &numerator, &denominator, rounding)
+    }
+
+    /// The exact decimal value if it has no more than `max_digits` digits, and otherwise six
+    /// significant digits in scientific notation.
+    #[allow(
+        clippy::cast_sign_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap
+    )]
+    pub fn to_decimal(&self, max_digits: usize) -> String {
+        let sign = if self.negative { "-" } else { "" };
+        if self.significand.is_zero() {
+            return "0".to_owned();
+        }
+        if self.scale >= 0 {
+            let integer = self.significand.shl(self.scale as usize).to_string();
+            if integer.len() <= max_digits {
+                return format!("{}{}", sign, integer);
+            }
+        } else {
+            // n / 2^k is n × 5^k / 10^k, with k digits after the point.
+            let fraction_len = -self.scale as usize;
+            if fraction_len <= max_digits {
+                let mut scaled = self.significand.clone();
+                for _ in 0..fraction_len {
+                    scaled.mul_small(5);
+                }
+                let digits = format!("{:01$}", scaled, fraction_len + 1);
+                if digits.len() <= max_digits + 1 {
+                    let (integer, fraction) = digits.split_at(digits.len() - fraction_len);
+                    let fraction = fraction.trim_end_matches('0');
+                    return format!(
+                        "{}{}{}{}",
+                        sign,
+                        integer,
+                        if fraction.is_empty() { "" } else { "." },
+                        fraction
+                    );
+                }
+            }
+        }
+        // Keep the significand within range of `f64` by moving its low bits into the scale.
+        let excess = self.significand.bit_len().saturating_sub(64);
+        let significand = self.significand.shr(excess).to_f64();
+        format!(
+            "{}{}",
+            sign,
+            format_scaled(significand, self.scale + excess as i32)
+        )
+    }
+}
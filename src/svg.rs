@@ -0,0 +1,132 @@
+//! The bit row drawn as a standalone SVG image, so that accurate diagrams can go on slides.
+
+use crate::{
+    encoding::{BitType, Encoding},
+    text::format_encoding,
+};
+use std::fmt::Write;
+
+/// Size of the square drawn for each bit.
+const CELL: usize = 20;
+
+/// Space between fields, and around the whole diagram.
+const GAP: usize = 8;
+
+const FONT_SIZE: usize = 13;
+
+/// Rough width of a character of a label, to keep labels from running into each other.
+const CHAR_WIDTH: usize = 7;
+
+/// Colours of the diagram, which follow the page's.
+pub struct Palette<'a> {
+    pub sign: &'a str,
+    pub exponent: &'a str,
+    pub significand: &'a str,
+    pub background: &'a str,
+    pub foreground: &'a str,
+    /// Digits drawn on the fields.
+    pub on_field: &'a str,
+}
+
+impl Palette<'_> {
+    fn fill(&self, t: BitType) -> &str {
+        match t {
+            BitType::Sign => self.sign,
+            BitType::Exponent => self.exponent,
+            BitType::Significand => self.significand,
+        }
+    }
+}
+
+/// The bits of `encoding` coloured by field, with the value and pattern above and each field
+/// labelled below. A label which would run into the one before drops to the next line.
+pub fn bit_layout(encoding: &Encoding, palette: &Palette<'_>) -> String {
+    let fields = [
+        (BitType::Sign, 0..1),
+        (BitType::Exponent, 1..1 + encoding.exponent_bits.len()),
+        (
+            BitType::Significand,
+            1 + encoding.exponent_bits.len()..encoding.len(),
+        ),
+    ];
+    let x = |i: usize| {
+        let field_gaps = fields.iter().filter(|(_, range)| range.start <= i).count() - 1;
+        GAP + i * CELL + field_gaps * GAP
+    };
+    let top = GAP + FONT_SIZE + GAP;
+    let mut body = String::new();
+    let mut labels = String::new();
+    let mut label_rows: Vec<usize> = Vec::new();
+    for (t, range) in &fields {
+        for i in range.clone() {
+            let bit = encoding.bit(i).map_or(false, |bit| bit.value);
+            let _ = write!(
+                body,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\" stroke=\"{}\"/>\
+                 <text x=\"{}\" y=\"{}\" fill=\"{}\" text-anchor=\"middle\" \
+                 font-family=\"monospace\">{}</text>",
+                x(i),
+                top,
+                CELL,
+                CELL,
+                palette.fill(*t),
+                palette.background,
+                x(i) + CELL / 2,
+                top + CELL / 2 + FONT_SIZE / 3,
+                palette.on_field,
+                u8::from(bit),
+            );
+        }
+        let label = match t {
+            BitType::Sign => t.name().to_owned(),
+            _ => format!("{} ({})", t.name(), range.len()),
+        };
+        let (start, end) = (x(range.start), x(range.end - 1) + CELL);
+        let row = label_rows
+            .iter()
+            .position(|&free_from| free_from <= start)
+            .unwrap_or(label_rows.len());
+        let free_from = start + label.chars().count() * CHAR_WIDTH + GAP;
+        if row == label_rows.len() {
+            label_rows.push(free_from);
+        } else {
+            label_rows[row] = free_from;
+        }
+        let y = top + CELL + GAP + (row + 1) * (FONT_SIZE + GAP / 2);
+        let _ = write!(
+            labels,
+            "<path d=\"M{} {}v{}h{}v-{}\" fill=\"none\" stroke=\"{}\"/>\
+             <text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+            start,
+            top + CELL + GAP / 2,
+            GAP / 2,
+            end - start,
+            GAP / 2,
+            palette.foreground,
+            start,
+            y,
+            palette.foreground,
+            label,
+        );
+    }
+    let caption = format!("{} = {}", format_encoding(encoding), encoding.hex());
+    let width =
+        (x(encoding.len() - 1) + CELL + GAP).max(caption.chars().count() * CHAR_WIDTH + 2 * GAP);
+    let height = top + CELL + GAP + label_rows.len() * (FONT_SIZE + GAP / 2) + GAP;
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"{f}\">\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\
+         <text x=\"{g}\" y=\"{y}\" fill=\"{fg}\">{caption}</text>{body}{labels}</svg>",
+        w = width,
+        h = height,
+        f = FONT_SIZE,
+        bg = palette.background,
+        g = GAP,
+        y = GAP + FONT_SIZE,
+        fg = palette.foreground,
+        caption = caption,
+        body = body,
+        labels = labels,
+    )
+}
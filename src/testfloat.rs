@@ -0,0 +1,148 @@
+//! Reading test vectors in the format produced by Berkeley `TestFloat`'s `testfloat_gen` and
+//! checking them where the expected result can be worked out.
+
+use crate::{
+    arithmetic::{calculate, Operator},
+    encoding::{Encoding, Flags, RoundingMode},
+};
+
+/// The operations whose test vectors can be imported.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Sqrt,
+}
+
+impl Operation {
+    pub const ALL: [Self; 5] = [Self::Add, Self::Sub, Self::Mul, Self::Div, Self::Sqrt];
+
+    /// The name `TestFloat` uses for the operation, e.g. `div` as in `f64_div`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+            Self::Sqrt => "sqrt",
+        }
+    }
+
+    fn is_unary(self) -> bool {
+        self == Self::Sqrt
+    }
+
+    /// The app's operator for the operation, or `None` for square roots, which it can't take.
+    fn operator(self) -> Option<Operator> {
+        match self {
+            Self::Add => Some(Operator::Add),
+            Self::Sub => Some(Operator::Subtract),
+            Self::Mul => Some(Operator::Multiply),
+            Self::Div => Some(Operator::Divide),
+            Self::Sqrt => None,
+        }
+    }
+}
+
+/// A single test vector.
+#[derive(Clone)]
+pub struct TestCase {
+    pub a: Encoding,
+    /// The second operand, or `None` for unary operations.
+    pub b: Option<Encoding>,
+    pub result: Encoding,
+    pub flags: Flags,
+}
+
+/// Whether a test case's expected result agrees with the app's.
+pub enum Verdict {
+    Pass,
+    /// The app's result and flags.
+    Fail(Encoding, Flags),
+    /// The app can't evaluate this case.
+    Unchecked,
+}
+
+/// Parses a line of `testfloat_gen` output, e.g. `3FF0000000000000 0000000000000000
+/// 7FF0000000000000 08` for `f64_div`, as a test of `operation` in the layout of `current`.
+pub fn parse_line(
+    line: &str,
+    operation: Operation,
+    current: &Encoding,
+) -> Result<TestCase, String> {
+    let width = current.len();
+    let encoding = |token: &str| -> Result<Encoding, String> {
+        if !token.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("\"{}\" is not a hex pattern", token));
+        }
+        current
+            .with_hex(token)
+            .ok_or_else(|| format!("{} does not fit in {} bits", token, width))
+    };
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let operands = if operation.is_unary() { 1 } else { 2 };
+    if tokens.len() != operands + 2 {
+        return Err(format!(
+            "Expected {} fields for {} but found {}",
+            operands + 2,
+            operation.name(),
+            tokens.len()
+        ));
+    }
+    let flags = u8::from_str_radix(tokens[operands + 1], 16)
+        .map_err(|_| format!("\"{}\" is not a set of flags", tokens[operands + 1]))?;
+    Ok(TestCase {
+        a: encoding(tokens[0])?,
+        b: if operation.is_unary() {
+            None
+        } else {
+            Some(encoding(tokens[1])?)
+        },
+        result: encoding(tokens[operands])?,
+        flags: Flags::from_bits(flags),
+    })
+}
+
+/// The app's result of `operation`, rounded to nearest with ties to even as `testfloat_gen`
+/// does by default. Square roots are only worked out where they follow from the class of the
+/// operand, for negative numbers. `None` for every other square root and for NaN operands,
+/// whose propagation varies between implementations.
+pub fn evaluate(
+    operation: Operation,
+    a: &Encoding,
+    b: Option<&Encoding>,
+) -> Option<(Encoding, Flags)> {
+    if a.is_nan() || b.map_or(false, Encoding::is_nan) {
+        return None;
+    }
+    match (operation.operator(), b) {
+        (Some(operator), Some(b)) => calculate(operator, a, b, RoundingMode::TiesToEven),
+        (None, None) if a.sign_bit && !a.is_zero() => a.default_nan().map(|nan| {
+            (
+                nan,
+                Flags {
+                    invalid: true,
+                    ..Flags::default()
+                },
+            )
+        }),
+        _ => None,
+    }
+}
+
+/// Checks `case` against the app's own result where there is one. Like `TestFloat` itself, any
+/// NaN result matches any other, as the default NaN differs between implementations.
+pub fn check(operation: Operation, case: &TestCase) -> Verdict {
+    match evaluate(operation, &case.a, case.b.as_ref()) {
+        Some((result, flags))
+            if (result == case.result || (result.is_nan() && case.result.is_nan()))
+                && flags == case.flags =>
+        {
+            Verdict::Pass
+        }
+        Some((result, flags)) => Verdict::Fail(result, flags),
+        None => Verdict::Unchecked,
+    }
+}
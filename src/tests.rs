@@ -0,0 +1,1900 @@
+//! End to end tests of `Msg` handling, run in a browser with `wasm-pack test`.
+
+use super::*;
+use bignum::BigUint;
+use decimal::{DecimalEncoding, DecimalFormat, Decoded};
+use encoding::{Class, NonCanonical, Options};
+use i18n::{Button, Line, Locale, Sentence, Text};
+use interpretation::{PositField, Unrepresentable};
+use quiz::Status;
+use std::{cmp::Ordering, iter};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Handles each of `msgs` in turn, returning the effects of the last one.
+fn send(model: &mut Model, msgs: impl IntoIterator<Item = Msg>) -> Vec<Effect> {
+    let mut effects = Vec::new();
+    for (i, msg) in msgs.into_iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let now = i as f64;
+        effects = handle(msg, model, now);
+    }
+    effects
+}
+
+#[wasm_bindgen_test]
+fn toggling_a_bit_records_and_navigates() {
+    let mut model = Model::new();
+    let effects = send(&mut model, vec![Msg::ToggleBit(2)]);
+    assert_eq!(model.encoding.to_bits(), 0x2000_0000_0000_0000);
+    assert_eq!(model.encoding.value(), 2_f64.powi(-511));
+    assert_eq!(effects, vec![Effect::Navigate { replace: false }]);
+    assert_eq!(model.history.len(), 2);
+    assert_eq!(model.history[1].label, "Toggled bit 2");
+    assert_eq!(model.flipped.iter().filter(|&&b| b).count(), 1);
+    assert_eq!(model.ghost, Some(0.0));
+}
+
+#[wasm_bindgen_test]
+fn field_scoped_messages_set_bits_fields_and_patterns() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(5, 10),
+            Msg::SetField(BitType::Exponent, 15),
+            Msg::SetBit(BitType::Significand, 9, true),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 1.5);
+    assert_eq!(model.history[3].label, "Significand bit 9 set to 1");
+
+    send(&mut model, vec![Msg::SetPattern(0xC000)]);
+    assert_eq!(model.encoding.value(), -2.0);
+    assert_eq!(model.encoding.field(BitType::Exponent), 16);
+
+    send(&mut model, vec![Msg::ToggleBit(0)]);
+    assert_eq!(model.encoding.value(), 2.0);
+}
+
+#[wasm_bindgen_test]
+fn typing_hex_digits_overwrites_and_advances() {
+    let mut model = Model::new();
+    let keys = vec!["3", "f", "ArrowRight", "Backspace", "F"];
+    send(
+        &mut model,
+        iter::once(Msg::SelectNibble(Some(0)))
+            .chain(keys.into_iter().map(|k| Msg::HexKey(k.to_owned()))),
+    );
+    assert_eq!(model.encoding.hex(), "0x3FF0000000000000");
+    assert_eq!(model.nibble_cursor, Some(3));
+    assert_eq!(model.history.len(), 4);
+
+    let effects = send(&mut model, vec![Msg::HexKey("1".to_owned())]);
+    assert_eq!(effects, vec![Effect::Navigate { replace: true }]);
+    assert_eq!(model.encoding.hex(), "0x3FF1000000000000");
+}
+
+#[wasm_bindgen_test]
+fn sessions_are_saved_only_after_edits_and_restored_on_request() {
+    let mut model = Model::new();
+    assert!(send(&mut model, vec![Msg::SaveSession]).is_empty());
+    send(&mut model, vec![Msg::ToggleBit(1)]);
+    assert_eq!(
+        send(&mut model, vec![Msg::SaveSession]),
+        vec![Effect::SaveSession]
+    );
+
+    let mut model = Model::new();
+    model.restorable = Encoding::from_fragment("11-52-4000000000000000");
+    let effects = send(&mut model, vec![Msg::RestoreSession]);
+    assert_eq!(model.encoding.value(), 2.0);
+    assert!(model.restorable.is_none());
+    assert!(effects.contains(&Effect::ClearSession));
+}
+
+#[wasm_bindgen_test]
+fn sessions_are_restored_until_reset() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::ToggleBit(1),
+            Msg::ToggleSecondBit(2),
+            Msg::SetRounding(RoundingMode::TowardZero),
+            Msg::SetColor(BitType::Sign, "#000000".to_owned()),
+            Msg::SetLocale(Locale::French),
+        ],
+    );
+    let mut restored = Model::new();
+    restored.restore_session(&model.session());
+    assert_eq!(restored.session(), model.session());
+    assert_eq!(restored.encoding, model.encoding);
+    assert_eq!(restored.history.len(), 1);
+
+    // Sessions saved by earlier versions are just the fragment of the encoding.
+    let mut restored = Model::new();
+    restored.restore_session("5-10-3C00");
+    assert_eq!(restored.encoding.value(), 1.0);
+    assert!(restored.second.same_layout(&restored.encoding));
+
+    send(&mut model, vec![Msg::ResetToDefaults]);
+    assert_eq!(model.session(), Model::new().session());
+    assert_eq!(model.history.last().unwrap().label, "Reset to defaults");
+}
+
+#[wasm_bindgen_test]
+fn repeated_resizes_replace_the_browser_history_entry() {
+    let mut model = Model::new();
+    assert_eq!(
+        send(&mut model, vec![Msg::SetLayout(8, 52)]),
+        vec![Effect::Navigate { replace: false }]
+    );
+    assert_eq!(
+        send(&mut model, vec![Msg::SetLayout(7, 52)]),
+        vec![Effect::Navigate { replace: true }]
+    );
+    assert_eq!(
+        send(&mut model, vec![Msg::ToggleBit(0), Msg::SetLayout(6, 52)]),
+        vec![Effect::Navigate { replace: false }]
+    );
+    assert_eq!(model.encoding.exponent_bits.len(), 6);
+}
+
+#[wasm_bindgen_test]
+fn slider_input_is_batched() {
+    let mut model = Model::new();
+    assert_eq!(
+        send(&mut model, vec![Msg::SetExpSize(10)]),
+        vec![Effect::SkipRender, Effect::ScheduleResize]
+    );
+    assert_eq!(
+        send(&mut model, vec![Msg::SetExpSize(9), Msg::SetSigSize(40)]),
+        vec![Effect::SkipRender]
+    );
+    assert_eq!(model.encoding.exponent_bits.len(), 11);
+
+    assert_eq!(
+        send(&mut model, vec![Msg::FlushResize]),
+        vec![Effect::Navigate { replace: false }]
+    );
+    assert_eq!(model.encoding.exponent_bits.len(), 9);
+    assert_eq!(model.encoding.significand_bits.len(), 40);
+    assert_eq!(model.history.len(), 2);
+    assert_eq!(model.history[1].label, "Format set to 9/40");
+}
+
+#[wasm_bindgen_test]
+fn messages_which_change_nothing_skip_rendering() {
+    let mut model = Model::new();
+    for msg in vec![
+        Msg::SetLayout(11, 52),
+        Msg::SetExpSize(11),
+        Msg::FlushResize,
+        Msg::HoverBit(None),
+        Msg::DismissPaste,
+        Msg::Scrub(0),
+    ] {
+        assert_eq!(send(&mut model, vec![msg]), vec![Effect::SkipRender]);
+    }
+    assert_eq!(model.history.len(), 1);
+}
+
+#[wasm_bindgen_test]
+fn browser_navigation_loads_without_navigating() {
+    let mut model = Model::new();
+    let url: Url = "/#5-10-3C00".parse().expect("valid URL");
+    let effects = send(&mut model, vec![Msg::UrlChanged(subs::UrlChanged(url))]);
+    assert!(effects.is_empty());
+    assert_eq!(model.encoding.value(), 1.0);
+    assert_eq!(model.history.len(), 2);
+}
+
+#[wasm_bindgen_test]
+fn shared_links_set_the_initial_state() {
+    let encoding = Encoding::from_fragment("5-10-3C00-S").expect("valid fragment");
+    let mut model = Model::new();
+    model.start_from(encoding.clone());
+    assert_eq!(model.encoding, encoding);
+    assert_eq!(model.history.len(), 1);
+    assert_eq!(model.history[0].encoding, encoding);
+    assert!(model.second.same_layout(&encoding));
+    assert_eq!(model.second.options, encoding.options);
+}
+
+#[wasm_bindgen_test]
+fn messages_without_labels_are_not_recorded() {
+    let mut model = Model::new();
+    let effects = send(
+        &mut model,
+        vec![
+            Msg::HoverBit(Some(3)),
+            Msg::ToggleExplainMode,
+            Msg::ExplainBit(Some(3)),
+        ],
+    );
+    assert!(effects.is_empty());
+    assert_eq!(model.hovered_bit, Some(3));
+    assert_eq!(model.explained_bit, Some(3));
+    assert_eq!(model.history.len(), 1);
+}
+
+#[wasm_bindgen_test]
+fn copying_goes_through_the_clipboard_effect() {
+    let mut model = Model::new();
+    let effects = send(
+        &mut model,
+        vec![Msg::SetLayout(5, 10), Msg::CopyFieldString],
+    );
+    assert_eq!(
+        effects,
+        vec![Effect::CopyToClipboard("0 | 00000 | 0000000000".to_owned())]
+    );
+}
+
+#[wasm_bindgen_test]
+fn generated_code_is_copied_in_the_chosen_language() {
+    let mut model = Model::new();
+    let effects = send(
+        &mut model,
+        vec![
+            Msg::SetPattern(0x3FF0_0000_0000_0000),
+            Msg::SetCodeLanguage(Language::C),
+            Msg::CopyCode,
+        ],
+    );
+    match effects.as_slice() {
+        [Effect::CopyToClipboard(code)] => {
+            assert!(code.contains("uint64_t exponent = 0x3FF;"));
+            assert!(code.contains("(sign << 63) | (exponent << 52) | significand"));
+        }
+        _ => panic!("expected a copy, got {:?}", effects),
+    }
+}
+
+#[wasm_bindgen_test]
+fn imported_test_vectors_are_checked_where_possible() {
+    let current = Model::new().encoding;
+    let check = |operation, line| {
+        let case = testfloat::parse_line(line, operation, &current).expect("valid vector");
+        testfloat::check(operation, &case)
+    };
+    assert!(matches!(
+        check(
+            Operation::Div,
+            "3FF0000000000000 8000000000000000 FFF0000000000000 08"
+        ),
+        testfloat::Verdict::Pass
+    ));
+    assert!(matches!(
+        check(
+            Operation::Mul,
+            "0000000000000000 FFF0000000000000 7FF0000000000000 00"
+        ),
+        testfloat::Verdict::Fail(_, _)
+    ));
+    // Ordinary arithmetic is rounded by the app, so 0.1 + 0.2 passes and a sum rounded the
+    // wrong way fails.
+    assert!(matches!(
+        check(
+            Operation::Add,
+            "3FB999999999999A 3FC999999999999A 3FD3333333333334 01"
+        ),
+        testfloat::Verdict::Pass
+    ));
+    assert!(matches!(
+        check(
+            Operation::Add,
+            "3FF0000000000000 3CA0000000000000 3FF0000000000001 01"
+        ),
+        testfloat::Verdict::Fail(_, _)
+    ));
+    assert!(matches!(
+        check(Operation::Sqrt, "4010000000000000 4000000000000000 00"),
+        testfloat::Verdict::Unchecked
+    ));
+    assert!(testfloat::parse_line("3FF0000000000000 00", Operation::Add, &current).is_err());
+}
+
+#[wasm_bindgen_test]
+fn pasting_applies_unambiguous_text() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::Paste("0x3FF0000000000000".to_owned())],
+    );
+    assert_eq!(model.encoding.value(), 1.0);
+    assert!(model.paste.is_none());
+}
+
+#[wasm_bindgen_test]
+fn pasting_asks_about_ambiguous_text() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::Paste("4000000000000000".to_owned())]);
+    assert!(matches!(model.paste, Some(PasteState::Ambiguous(_, _))));
+    assert_eq!(model.encoding.value(), 0.0);
+
+    send(&mut model, vec![Msg::PasteAs(PasteFormat::Hex)]);
+    assert_eq!(model.encoding.value(), 2.0);
+    assert!(model.paste.is_none());
+}
+
+#[wasm_bindgen_test]
+fn pasting_rejects_unrecognised_text() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::Paste("not a number".to_owned())]);
+    assert!(matches!(model.paste, Some(PasteState::Error(_))));
+    send(&mut model, vec![Msg::DismissPaste]);
+    assert!(model.paste.is_none());
+}
+
+#[wasm_bindgen_test]
+fn scrubbing_the_timeline_restores_recorded_state() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::ToggleTimeline, Msg::ToggleBit(1), Msg::ToggleBit(63)],
+    );
+    let effects = send(&mut model, vec![Msg::Scrub(1)]);
+    assert!(effects.is_empty());
+    assert_eq!(model.encoding.value(), 2.0);
+    let timeline = model.timeline.as_ref().expect("timeline enabled");
+    assert_eq!(timeline.entries.len(), 3);
+    assert_eq!(timeline.cursor, 1);
+}
+
+#[wasm_bindgen_test]
+fn typed_integers_set_the_raw_bits() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(5, 10),
+            Msg::SetFromUnsigned("15360".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 1.0);
+    assert!(model.integer_error.is_none());
+
+    send(&mut model, vec![Msg::SetFromUnsigned("65536".to_owned())]);
+    assert_eq!(model.encoding.value(), 1.0);
+    assert!(model.integer_error.is_some());
+}
+
+#[wasm_bindgen_test]
+fn wide_encodings_read_as_big_integers() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetFromDecimal("-2".to_owned())]);
+    let (negative, magnitude) = model.encoding.to_big_signed_bits();
+    assert!(negative);
+    assert_eq!(
+        magnitude.to_u64(),
+        Some(model.encoding.to_signed_bits().unsigned_abs())
+    );
+
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(15, 112),
+            Msg::SetFromDecimal("-2".to_owned()),
+        ],
+    );
+    let (negative, magnitude) = model.encoding.to_big_signed_bits();
+    assert!(negative);
+    // -2 is 0xC000 followed by 28 zero digits, so 2^128 minus it is 0x4000 followed by them.
+    assert_eq!(magnitude, BigUint::from_u64(1).shl(126));
+    assert_eq!(model.encoding.to_big_bits(), BigUint::from_u64(3).shl(126));
+}
+
+#[wasm_bindgen_test]
+fn typed_ordinals_select_finite_values() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(5, 10),
+            Msg::SetFromOrdinal("\u{2212}31,743".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.value(), -65504.0);
+    assert!(model.ordinal_error.is_none());
+
+    send(&mut model, vec![Msg::SetFromOrdinal("31,744".to_owned())]);
+    assert_eq!(model.encoding.value(), -65504.0);
+    assert!(model.ordinal_error.is_some());
+
+    // Neither the most negative integer nor a format wider than 64 bits has an ordinal.
+    for (e, s) in &[(5, 10), (15, 112)] {
+        model.ordinal_error = None;
+        send(
+            &mut model,
+            vec![
+                Msg::SetLayout(*e, *s),
+                Msg::SetFromOrdinal("-9223372036854775808".to_owned()),
+            ],
+        );
+        assert!(model.ordinal_error.is_some());
+    }
+}
+
+#[wasm_bindgen_test]
+fn interpolation_steps_from_start_to_end() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(5, 10),
+            Msg::SaveInterpolationStart,
+            Msg::SetFromUnsigned("15360".to_owned()),
+            Msg::SaveInterpolationEnd,
+        ],
+    );
+    assert_eq!(
+        send(&mut model, vec![Msg::PlayInterpolation]),
+        vec![Effect::StartInterpolation]
+    );
+    for _ in 0..=INTERPOLATION_FRAMES {
+        assert!(send(&mut model, vec![Msg::InterpolationFrame]).is_empty());
+    }
+    assert_eq!(model.encoding.value(), 1.0);
+    assert_eq!(
+        model.interpolation.frames.len(),
+        INTERPOLATION_FRAMES as usize + 1
+    );
+}
+
+#[wasm_bindgen_test]
+fn overflow_behaviour_is_kept_by_edits() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetOverflow(Overflow::Saturate),
+            Msg::SetPattern(0x7FF0_0000_0000_0000),
+            Msg::SetFromOrdinal("1".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.options.overflow, Overflow::Saturate);
+    assert_eq!(model.encoding.overflowed(false).value(), f64::MAX);
+    assert!(Encoding::from_fragment(&model.encoding.to_fragment()) == Some(model.encoding));
+}
+
+#[wasm_bindgen_test]
+fn formats_without_infinities_decode_the_top_binade() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(4, 3),
+            Msg::ToggleInfinities,
+            Msg::SetPattern(0x7E),
+        ],
+    );
+    // FP8 E4M3: the only NaN is all ones, so the top binade holds ordinary values up to 448.
+    assert_eq!(model.encoding.value(), 448.0);
+    assert!(model.encoding.max_finite(false) == model.encoding);
+    assert!(model.encoding.infinity(false).is_none());
+    send(&mut model, vec![Msg::SetPattern(0x7F)]);
+    assert!(model.encoding.is_nan());
+    send(&mut model, vec![Msg::ToggleNans]);
+    assert_eq!(model.encoding.value(), 480.0);
+    assert!(Encoding::from_fragment(&model.encoding.to_fragment()) == Some(model.encoding));
+}
+
+#[wasm_bindgen_test]
+fn explicit_leading_bits_are_decoded() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(8, 24),
+            Msg::ToggleImplicitBit,
+            Msg::SetPattern(0x7F80_0000),
+        ],
+    );
+    // The same precision as binary32, with the exponent of 1.0 above a leading bit in bit 23.
+    assert_eq!(model.encoding.precision(), 24);
+    assert_eq!(model.encoding.value(), 1.0);
+    assert!(model.encoding.power_of_two(0) == Some(model.encoding.clone()));
+    // An unnormal: the exponent of 1.0 with the leading bit clear.
+    send(&mut model, vec![Msg::SetPattern(0x7F40_0000)]);
+    assert_eq!(model.encoding.value(), 0.5);
+}
+
+#[wasm_bindgen_test]
+fn decimals_are_rounded_to_the_format() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetFromDecimal("0.1".to_owned())]);
+    assert_eq!(model.encoding.to_bits(), 0x3FB9_9999_9999_999A);
+    // Halfway between 1.0009765625 and 1.001953125 in binary16, so it rounds to the even one.
+    // Going through f64 first would give the same answer here, but not in general.
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(5, 10),
+            Msg::SetFromDecimal("1.00146484375".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x3C02);
+    send(&mut model, vec![Msg::SetFromDecimal("1e5".to_owned())]);
+    assert!(model.encoding.is_infinite());
+    send(&mut model, vec![Msg::SetFromDecimal("0x10".to_owned())]);
+    assert!(model.decimal_error.is_some());
+    assert!(model.encoding.is_infinite());
+    // Zero stays exactly zero however far its exponent is out of range.
+    for text in &["0e100000", "-0e-100000"] {
+        send(
+            &mut model,
+            vec![Msg::ClearFlags, Msg::SetFromDecimal((*text).to_owned())],
+        );
+        assert!(model.encoding.is_zero());
+        assert_eq!(model.encoding.sign_bit, text.starts_with('-'));
+        assert_eq!(model.flags, Flags::default());
+    }
+}
+
+#[wasm_bindgen_test]
+fn hex_patterns_set_every_bit() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::SetFromHex("0x4000_0000_0000_0000".to_owned())],
+    );
+    assert_eq!(model.encoding.value(), 2.0);
+    send(
+        &mut model,
+        vec![Msg::SetLayout(5, 10), Msg::SetFromHex("10000".to_owned())],
+    );
+    assert!(model.hex_error.is_some());
+    send(&mut model, vec![Msg::SetFromHex("bc00".to_owned())]);
+    assert!(model.hex_error.is_none());
+    assert_eq!(model.encoding.value(), -1.0);
+}
+
+#[wasm_bindgen_test]
+fn presets_keep_the_nearest_value() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("0.1".to_owned()),
+            Msg::ToggleNans,
+            Msg::ApplyPreset(FormatPreset::Binary32),
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x3DCC_CCCD);
+    assert!(model.encoding.options == Options::default());
+    assert_eq!(
+        model.history.last().unwrap().label,
+        "Format set to binary32"
+    );
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("1e30".to_owned()),
+            Msg::ApplyPreset(FormatPreset::Binary16),
+        ],
+    );
+    assert!(model.encoding.is_infinite());
+    send(&mut model, vec![Msg::ApplyPreset(FormatPreset::Bfloat16)]);
+    assert!(model.encoding.is_infinite());
+    assert_eq!(model.encoding.exponent_bits.len(), 8);
+}
+
+#[wasm_bindgen_test]
+fn formats_wider_than_binary64_are_evaluated_exactly() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(15, 112),
+            Msg::SetFromDecimal("0.1".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.hex(), "0x3FFB999999999999999999999999999A");
+    assert_eq!(model.encoding.value(), 0.1);
+    assert_eq!(
+        text::format_encoding(&model.encoding),
+        "0.100000000000000000000000000000000005"
+    );
+    // Beyond the range of f64, but not of binary128.
+    send(&mut model, vec![Msg::SetFromDecimal("1e4000".to_owned())]);
+    assert!(!model.encoding.is_infinite());
+    assert_eq!(
+        text::format_encoding(&model.encoding),
+        "1.00000000000000000000000000000000004e4000"
+    );
+    let fragment = model.encoding.to_fragment();
+    assert!(Encoding::from_fragment(&fragment) == Some(model.encoding.clone()));
+}
+
+#[wasm_bindgen_test]
+fn exact_decimals_have_every_digit() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetFromDecimal("0.1".to_owned())]);
+    assert_eq!(
+        text::exact_decimal(&model.encoding, 100).as_deref(),
+        Some("0.1000000000000000055511151231257827021181583404541015625")
+    );
+    send(&mut model, vec![Msg::SetFromDecimal("-1e22".to_owned())]);
+    assert_eq!(
+        text::exact_decimal(&model.encoding, 100).as_deref(),
+        Some("-10000000000000000000000")
+    );
+    // The smallest subnormal has 1074 digits after the point.
+    send(&mut model, vec![Msg::SetPattern(1)]);
+    assert!(text::exact_decimal(&model.encoding, 1000).is_none());
+}
+
+#[wasm_bindgen_test]
+fn stepping_visits_adjacent_values() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetLayout(8, 23), Msg::StepUp]);
+    assert_eq!(model.encoding.value(), f64::from(f32::from_bits(1)));
+    send(&mut model, vec![Msg::StepDown, Msg::StepDown]);
+    assert_eq!(model.encoding.value(), -f64::from(f32::from_bits(1)));
+    send(
+        &mut model,
+        vec![Msg::SetFromDecimal("3.4028235e38".to_owned()), Msg::StepUp],
+    );
+    assert!(model.encoding.is_infinite());
+    // Nothing lies beyond infinity, so the step does nothing and isn't recorded.
+    let steps = model.history.len();
+    send(&mut model, vec![Msg::StepUp]);
+    assert_eq!(model.history.len(), steps);
+    send(&mut model, vec![Msg::StepDown]);
+    assert_eq!(model.encoding.value(), f64::from(f32::MAX));
+}
+
+#[wasm_bindgen_test]
+fn stepping_stops_at_the_ends_of_formats_without_infinities_or_nans() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(3, 2),
+            Msg::ToggleInfinities,
+            Msg::ToggleNans,
+            Msg::SetPattern(0x1F),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 28.0);
+    assert!(model.encoding.next_up().is_none());
+    let steps = model.history.len();
+    send(&mut model, vec![Msg::StepUp]);
+    assert_eq!(model.history.len(), steps);
+    assert_eq!(model.encoding.to_bits(), 0x1F);
+    send(&mut model, vec![Msg::SetPattern(0x3F), Msg::StepDown]);
+    assert_eq!(model.encoding.to_bits(), 0x3F);
+    send(&mut model, vec![Msg::StepUp]);
+    assert_eq!(model.encoding.value(), -24.0);
+}
+
+#[wasm_bindgen_test]
+fn decimals_round_in_the_chosen_direction() {
+    let mut model = Model::new();
+    let halfway = || Msg::SetFromDecimal("-1.00146484375".to_owned());
+    send(&mut model, vec![Msg::SetLayout(5, 10), halfway()]);
+    assert_eq!(model.encoding.to_bits(), 0xBC02);
+    send(
+        &mut model,
+        vec![Msg::SetRounding(RoundingMode::TowardPositive), halfway()],
+    );
+    assert_eq!(model.encoding.to_bits(), 0xBC01);
+    // Rounding toward zero never overflows to infinity.
+    send(
+        &mut model,
+        vec![
+            Msg::SetRounding(RoundingMode::TowardZero),
+            Msg::SetFromDecimal("1e6".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x7BFF);
+}
+
+#[wasm_bindgen_test]
+fn the_second_operand_follows_the_format() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("0.3".to_owned()),
+            Msg::SetSecondFromDecimal("0.1".to_owned()),
+            Msg::ToggleSecondBit(63),
+        ],
+    );
+    assert_eq!(model.second.to_bits(), 0x3FB9_9999_9999_999B);
+    // Editing b leaves a and its history alone.
+    assert_eq!(model.history.len(), 2);
+    send(&mut model, vec![Msg::ApplyPreset(FormatPreset::Binary32)]);
+    assert_eq!(model.second.to_bits(), 0x3DCC_CCCD);
+    send(&mut model, vec![Msg::SwapOperands]);
+    assert_eq!(model.encoding.to_bits(), 0x3DCC_CCCD);
+    assert_eq!(model.second.to_bits(), 0x3E99_999A);
+}
+
+#[wasm_bindgen_test]
+fn operations_round_in_the_format() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetFromDecimal("1".to_owned()),
+            Msg::SetSecondFromDecimal("3".to_owned()),
+            Msg::Calculate(Operator::Divide),
+        ],
+    );
+    let (result, flags) = model.result.clone().unwrap();
+    assert_eq!(result.to_bits(), 0x3555);
+    assert!(flags.inexact);
+    // The result follows the operands.
+    send(
+        &mut model,
+        vec![Msg::SetRounding(RoundingMode::TowardPositive)],
+    );
+    assert_eq!(model.result.as_ref().unwrap().0.to_bits(), 0x3556);
+    send(
+        &mut model,
+        vec![Msg::SetSecondFromDecimal("0.5".to_owned())],
+    );
+    let (result, flags) = model.result.clone().unwrap();
+    assert_eq!(result.to_bits(), 0x4000);
+    assert!(!flags.inexact);
+    send(&mut model, vec![Msg::UseResult]);
+    assert_eq!(model.encoding.value(), 2.0);
+    assert_eq!(
+        model.history.last().unwrap().label,
+        "Set to the calculated result"
+    );
+}
+
+#[wasm_bindgen_test]
+fn flags_accumulate_until_cleared() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("1e300".to_owned()),
+            Msg::ApplyPreset(FormatPreset::Binary32),
+        ],
+    );
+    assert_eq!(model.flags.raised(), vec!["overflow", "inexact"]);
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("1".to_owned()),
+            Msg::Calculate(Operator::Divide),
+        ],
+    );
+    assert_eq!(
+        model.flags.raised(),
+        vec!["divideByZero", "overflow", "inexact"]
+    );
+    send(&mut model, vec![Msg::ClearFlags]);
+    assert!(model.flags.raised().is_empty());
+    assert_eq!(
+        send(&mut model, vec![Msg::ClearFlags]),
+        vec![Effect::SkipRender]
+    );
+}
+
+#[wasm_bindgen_test]
+fn undo_restores_bits_lost_to_resizing() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::ToggleBit(63), Msg::SetSigSize(10), Msg::FlushResize],
+    );
+    assert_eq!(model.encoding.to_bits(), 0);
+    send(&mut model, vec![Msg::Undo]);
+    assert_eq!(model.encoding.to_bits(), 1);
+    assert_eq!(model.encoding.significand_bits.len(), 52);
+    send(&mut model, vec![Msg::Undo]);
+    assert_eq!(model.encoding.to_bits(), 0);
+    assert_eq!(send(&mut model, vec![Msg::Undo]), vec![Effect::SkipRender]);
+    send(&mut model, vec![Msg::Redo, Msg::Redo]);
+    assert_eq!(model.encoding.significand_bits.len(), 10);
+    // A new edit abandons whatever was undone.
+    send(&mut model, vec![Msg::Undo, Msg::ToggleBit(0)]);
+    assert_eq!(send(&mut model, vec![Msg::Redo]), vec![Effect::SkipRender]);
+}
+
+#[wasm_bindgen_test]
+fn copied_text_pastes_back() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::ApplyPreset(FormatPreset::Binary16), Msg::StepUp],
+    );
+    for &format in &PasteFormat::COPYABLE {
+        let effects = send(&mut model, vec![Msg::Copy(format)]);
+        assert_eq!(model.copied, Some(format));
+        assert!(effects.contains(&Effect::ScheduleHideCopied));
+        let text = match &effects[0] {
+            Effect::CopyToClipboard(text) => text.clone(),
+            effect => panic!("expected a copy, not {:?}", effect),
+        };
+        assert!(PasteFormat::detect(&text, 16).contains(&format));
+        let (pasted, _) = format
+            .parse(&text, &model.encoding, model.rounding)
+            .expect("copied text parses");
+        assert_eq!(pasted, model.encoding);
+    }
+    send(&mut model, vec![Msg::HideCopied]);
+    assert!(model.copied.is_none());
+}
+
+#[wasm_bindgen_test]
+fn arrow_keys_move_between_bits() {
+    let mut model = Model::new();
+    let press = |model: &mut Model, key: &str| {
+        let effects = send(model, vec![Msg::BitKey(key.to_owned())]);
+        assert_eq!(effects, vec![Effect::FocusBit(model.bit_cursor)]);
+        model.bit_cursor
+    };
+    assert_eq!(press(&mut model, "ArrowLeft"), 0);
+    assert_eq!(press(&mut model, "ArrowRight"), 1);
+    assert_eq!(press(&mut model, "End"), 11);
+    assert_eq!(press(&mut model, "End"), 63);
+    assert_eq!(press(&mut model, "ArrowRight"), 63);
+    assert_eq!(press(&mut model, "Home"), 12);
+    assert_eq!(press(&mut model, "Home"), 1);
+    assert_eq!(press(&mut model, "Home"), 0);
+    send(&mut model, vec![Msg::BitFocused(5)]);
+    assert_eq!(press(&mut model, "Home"), 1);
+    assert_eq!(model.history.len(), 1);
+}
+
+#[wasm_bindgen_test]
+fn dragging_sets_bits_like_the_first() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::StartDrag(12),
+            Msg::DragOver(13),
+            Msg::DragOver(14),
+            Msg::HoverBit(None),
+            Msg::DragOver(13),
+            Msg::EndDrag,
+            Msg::DragOver(15),
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x000E_0000_0000_0000);
+    assert!(model.drag.is_none());
+
+    // Starting on a set bit clears bits instead, in a single browser history entry.
+    let effects = send(
+        &mut model,
+        vec![Msg::StartDrag(13), Msg::DragOver(14), Msg::DragOver(12)],
+    );
+    assert_eq!(effects, vec![Effect::Navigate { replace: true }]);
+    assert_eq!(
+        send(&mut model, vec![Msg::DragOver(15)]),
+        vec![Effect::SkipRender]
+    );
+    assert_eq!(model.encoding.to_bits(), 0);
+}
+
+#[wasm_bindgen_test]
+fn fields_are_filled_cleared_and_randomized() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::FillField(BitType::Exponent, true)]);
+    assert!(model.encoding.is_infinite());
+    send(&mut model, vec![Msg::FillField(BitType::Significand, true)]);
+    assert!(model.encoding.is_nan());
+    send(&mut model, vec![Msg::FillField(BitType::Exponent, false)]);
+    assert_eq!(model.encoding.to_bits(), 0x000F_FFFF_FFFF_FFFF);
+    send(&mut model, vec![Msg::Clear]);
+    assert_eq!(model.encoding.to_bits(), 0);
+
+    // The same seed always gives the same pattern, even past 64 bits.
+    send(&mut model, vec![Msg::SetLayout(15, 112), Msg::Randomize(1)]);
+    let random = model.encoding.clone();
+    assert!(random.bits().filter(|&b| b).count() > 32);
+    send(&mut model, vec![Msg::Clear, Msg::Randomize(1)]);
+    assert_eq!(model.encoding, random);
+    assert_eq!(model.history.last().unwrap().label, "Randomized");
+}
+
+#[wasm_bindgen_test]
+fn special_values_follow_the_format() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetSpecial(SpecialValue::MaxFinite),
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x7BFF);
+    send(
+        &mut model,
+        vec![Msg::SetSpecial(SpecialValue::NegativeInfinity)],
+    );
+    assert_eq!(model.encoding.to_bits(), 0xFC00);
+    send(&mut model, vec![Msg::SetSpecial(SpecialValue::MinNormal)]);
+    assert_eq!(model.encoding.to_bits(), 0x0400);
+    send(&mut model, vec![Msg::SetSpecial(SpecialValue::One)]);
+    assert_eq!(model.encoding.to_bits(), 0x3C00);
+    assert_eq!(model.history.last().unwrap().label, "Set to 1.0");
+
+    // Without infinities there is nothing to set, so nothing changes.
+    send(
+        &mut model,
+        vec![
+            Msg::ToggleInfinities,
+            Msg::SetSpecial(SpecialValue::PositiveInfinity),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn nans_keep_their_payload() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary32),
+            Msg::SetFromHex("7F800001".to_owned()),
+        ],
+    );
+    assert!(model.encoding.is_signaling_nan());
+    assert_eq!(model.encoding.nan_payload().unwrap().to_u64(), Some(1));
+    assert_eq!(model.encoding.value().to_bits(), 0x7FF0_0000_2000_0000);
+    send(&mut model, vec![Msg::ToggleBit(9)]);
+    assert!(!model.encoding.is_signaling_nan());
+    assert_eq!(model.encoding.value().to_bits(), 0x7FF8_0000_2000_0000);
+}
+
+#[wasm_bindgen_test]
+fn a_lone_explicit_leading_bit_leaves_nans_without_a_payload() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetLayout(2, 1),
+            Msg::ToggleImplicitBit,
+            Msg::SetPattern(0b110),
+        ],
+    );
+    assert!(model.encoding.is_nan());
+    assert!(!model.encoding.is_signaling_nan());
+    assert_eq!(model.encoding.value().to_bits(), 0x7FF8_0000_0000_0000);
+}
+
+#[wasm_bindgen_test]
+fn zeros_and_nans_are_classified() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary32),
+            Msg::SetFromHex("80000000".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.class(), Class::NegativeZero);
+    send(&mut model, vec![Msg::SetFromHex("00000001".to_owned())]);
+    assert_eq!(model.encoding.class(), Class::PositiveSubnormal);
+    send(&mut model, vec![Msg::SetFromHex("FF800000".to_owned())]);
+    assert_eq!(model.encoding.class(), Class::NegativeInfinity);
+    send(&mut model, vec![Msg::SetFromHex("7F800001".to_owned())]);
+    assert_eq!(model.encoding.class(), Class::SignalingNan);
+    send(&mut model, vec![Msg::SetFromHex("FFC00000".to_owned())]);
+    assert_eq!(model.encoding.class(), Class::QuietNan);
+    assert_eq!(model.encoding.class().fpclassify(), "FP_NAN");
+}
+
+#[wasm_bindgen_test]
+fn bytes_are_padded_to_whole_bytes() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::SetLayout(5, 6), Msg::SetFromHex("7FF".to_owned())],
+    );
+    assert_eq!(model.encoding.bytes(), vec![0x07, 0xFF]);
+    assert!(model.little_endian);
+    send(&mut model, vec![Msg::ToggleByteOrder]);
+    assert!(!model.little_endian);
+}
+
+#[wasm_bindgen_test]
+fn fractions_are_in_lowest_terms() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetFromDecimal("0.1".to_owned())]);
+    let (numerator, power) = model.encoding.reduced_ratio();
+    assert_eq!(numerator.to_u64(), Some(3_602_879_701_896_397));
+    assert_eq!(power, 55);
+    send(&mut model, vec![Msg::SetFromDecimal("1024".to_owned())]);
+    let (numerator, power) = model.encoding.reduced_ratio();
+    assert_eq!(numerator.to_u64(), Some(1024));
+    assert_eq!(power, 0);
+}
+
+#[wasm_bindgen_test]
+fn hex_floats_round_trip() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::SetFromHexFloat("0x1.999999999999ap-4".to_owned())],
+    );
+    assert_eq!(model.encoding.value(), 0.1);
+    assert_eq!(
+        PasteFormat::HexFloat.format(&model.encoding),
+        "0x1.999999999999ap-4"
+    );
+    send(
+        &mut model,
+        vec![Msg::SetFromHexFloat("0x1p-1080".to_owned())],
+    );
+    assert!(model.encoding.is_zero());
+    assert!(model.flags.underflow);
+    send(
+        &mut model,
+        vec![
+            Msg::ClearFlags,
+            Msg::SetFromHexFloat("0x0p300000".to_owned()),
+        ],
+    );
+    assert!(model.encoding.is_zero());
+    assert_eq!(model.flags, Flags::default());
+    send(&mut model, vec![Msg::SetFromHexFloat("0x1.g".to_owned())]);
+    assert!(model.hex_float_error.is_some());
+}
+
+#[wasm_bindgen_test]
+fn stepping_plays_through_bit_patterns_until_infinity() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetFromHex("03FF".to_owned()),
+            Msg::StepPattern,
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x0400);
+    assert_eq!(
+        model.history.last().unwrap().label,
+        "Added one to the raw bits"
+    );
+
+    send(&mut model, vec![Msg::SetFromHex("7BFE".to_owned())]);
+    let effects = send(&mut model, vec![Msg::PlaySteps]);
+    assert_eq!(effects, vec![Effect::StartStepping]);
+    let history_len = model.history.len();
+    send(&mut model, vec![Msg::SteppingFrame, Msg::SteppingFrame]);
+    assert!(model.encoding.is_infinite());
+    assert!(model.stepping.is_none());
+    assert_eq!(model.history.len(), history_len);
+}
+
+#[wasm_bindgen_test]
+fn ulps_grow_with_the_exponent() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary32),
+            Msg::SetFromDecimal("1".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.ulp_exponent(), -23);
+    assert_eq!(model.encoding.binade_size_exponent(), 23);
+    send(&mut model, vec![Msg::SetFromDecimal("16777216".to_owned())]);
+    assert_eq!(model.encoding.ulp_exponent(), 1);
+    send(&mut model, vec![Msg::SetFromHex("00000001".to_owned())]);
+    assert_eq!(model.encoding.ulp_exponent(), -149);
+    send(&mut model, vec![Msg::SetFromDecimal("inf".to_owned())]);
+    assert_eq!(model.encoding.ulp_exponent(), 104);
+}
+
+#[wasm_bindgen_test]
+fn e4m3_trades_infinities_for_range() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("448".to_owned()),
+            Msg::ApplyPreset(FormatPreset::E4M3),
+        ],
+    );
+    assert_eq!(model.encoding.to_bits(), 0x7E);
+    assert!(!model.encoding.options.infinities);
+    // The largest finite value is the largest value, as there is no infinity above it.
+    assert!(model.encoding.next_up().is_none());
+    send(&mut model, vec![Msg::SetFromHex("7F".to_owned())]);
+    assert!(model.encoding.is_nan());
+
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("448".to_owned()),
+            Msg::ApplyPreset(FormatPreset::E5M2),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 448.0);
+    assert_eq!(model.encoding.max_finite(false).value(), 57344.0);
+}
+
+#[wasm_bindgen_test]
+fn posits_read_the_same_bits() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::TogglePosit,
+            Msg::SetFromHex("5000".to_owned()),
+        ],
+    );
+    let posit = model.posit.expect("posit shown");
+    assert_eq!(posit.es, 2);
+    assert_eq!(posit.value(&model.encoding), 4.0);
+    send(&mut model, vec![Msg::SetFromHex("B000".to_owned())]);
+    assert_eq!(posit.value(&model.encoding), -4.0);
+    send(
+        &mut model,
+        vec![Msg::SetPositEs(0), Msg::SetFromHex("6000".to_owned())],
+    );
+    let posit = model.posit.expect("posit shown");
+    assert_eq!(posit.value(&model.encoding), 2.0);
+    assert_eq!(
+        posit.fields(&model.encoding)[1..5]
+            .iter()
+            .map(|&(_, field)| field)
+            .collect::<Vec<_>>(),
+        vec![
+            PositField::Regime,
+            PositField::Regime,
+            PositField::Regime,
+            PositField::Fraction
+        ]
+    );
+    send(&mut model, vec![Msg::SetFromHex("8000".to_owned())]);
+    assert!(posit.value(&model.encoding).is_nan());
+}
+
+#[wasm_bindgen_test]
+fn decimal_formats_read_the_same_bits() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary32),
+            Msg::ToggleDecimal,
+            Msg::SetFromHex("32800001".to_owned()),
+        ],
+    );
+    assert_eq!(model.decimal, Some(DecimalEncoding::Bid));
+    let format = DecimalFormat::with_width(32).expect("decimal32");
+    let bits: Vec<bool> = model.encoding.bits().collect();
+    assert_eq!(format.decode(&bits, DecimalEncoding::Bid).to_string(), "1");
+    // Without the `11` prefix, BID's exponent comes straight after the sign.
+    let fields = format.fields(&bits, DecimalEncoding::Bid);
+    assert_eq!(
+        (fields[1].1, fields[8].1, fields[9].1),
+        ("Exponent", "Exponent", "Coefficient")
+    );
+
+    // With it, the coefficient gains an implicit 100 at the top: 0x18967F becomes 9,999,999.
+    send(&mut model, vec![Msg::SetFromHex("6CB8967F".to_owned())]);
+    let bits: Vec<bool> = model.encoding.bits().collect();
+    assert_eq!(
+        format.decode(&bits, DecimalEncoding::Bid),
+        Decoded::Finite {
+            sign: false,
+            coefficient: 9_999_999,
+            exponent: 0,
+        }
+    );
+    let fields = format.fields(&bits, DecimalEncoding::Bid);
+    assert_eq!(
+        (fields[2].1, fields[3].1, fields[10].1, fields[11].1),
+        ("Combination", "Exponent", "Exponent", "Coefficient")
+    );
+
+    send(
+        &mut model,
+        vec![
+            Msg::SetDecimalEncoding(DecimalEncoding::Dpd),
+            Msg::SetFromHex("2230009E".to_owned()),
+        ],
+    );
+    assert_eq!(model.decimal, Some(DecimalEncoding::Dpd));
+    let bits: Vec<bool> = model.encoding.bits().collect();
+    assert_eq!(
+        format.decode(&bits, DecimalEncoding::Dpd).to_string(),
+        "9.90"
+    );
+    // The combination field holds the leading digit and two declets hold the other six.
+    assert_eq!(format.declets(&bits), [0, 990]);
+}
+
+#[wasm_bindgen_test]
+fn overridden_biases_shift_every_value() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetFromHex("3C00".to_owned()),
+            Msg::SetBias("16".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 0.5);
+    assert_eq!(model.encoding.emax(), 14);
+    assert_eq!(
+        Encoding::from_fragment(&model.encoding.to_fragment()),
+        Some(model.encoding.clone())
+    );
+
+    send(&mut model, vec![Msg::SetBias("x".to_owned())]);
+    assert!(model.bias_error.is_some());
+    assert_eq!(model.encoding.bias(), 16);
+    // Typing the standard bias drops the override, so the preset matches again.
+    send(&mut model, vec![Msg::SetBias("15".to_owned())]);
+    assert_eq!(model.encoding.options.bias, None);
+    assert_eq!(model.encoding.value(), 1.0);
+
+    send(
+        &mut model,
+        vec![Msg::SetBias("3".to_owned()), Msg::SetLayout(6, 10)],
+    );
+    assert_eq!(model.encoding.bias(), 31);
+}
+
+#[wasm_bindgen_test]
+fn large_biases_give_values_down_to_the_smallest_f64() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetBias("1100".to_owned()),
+            Msg::SetField(BitType::Exponent, 30),
+        ],
+    );
+    // 2^-1070, whose significand is scaled by 2^-1122, beyond the range of f64.
+    assert_eq!(model.encoding.value().to_bits(), 1 << 4);
+}
+
+#[wasm_bindgen_test]
+fn x87_extended_flags_non_canonical_encodings() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::SetFromDecimal("1".to_owned()),
+            Msg::ApplyPreset(FormatPreset::X87Extended),
+        ],
+    );
+    assert_eq!(model.encoding.hex(), "0x3FFF8000000000000000");
+    assert_eq!(model.encoding.precision(), 64);
+    assert_eq!(model.encoding.non_canonical(), None);
+
+    // A set leading bit with a zero exponent reads as the smallest normal exponent.
+    send(
+        &mut model,
+        vec![Msg::SetFromHex("00008000000000000000".to_owned())],
+    );
+    assert_eq!(
+        model.encoding.non_canonical(),
+        Some(NonCanonical::PseudoDenormal)
+    );
+    let min_normal = model.encoding.min_normal().unwrap();
+    assert_eq!(model.encoding.compare(&min_normal), Some(Ordering::Equal));
+
+    send(
+        &mut model,
+        vec![Msg::SetFromHex("3FFF4000000000000000".to_owned())],
+    );
+    assert_eq!(model.encoding.non_canonical(), Some(NonCanonical::Unnormal));
+    assert_eq!(model.encoding.value(), 0.5);
+
+    send(
+        &mut model,
+        vec![Msg::SetFromHex("7FFF0000000000000000".to_owned())],
+    );
+    assert_eq!(
+        model.encoding.non_canonical(),
+        Some(NonCanonical::PseudoInfinity)
+    );
+    assert!(model.encoding.is_nan());
+}
+
+#[wasm_bindgen_test]
+fn themes_recolour_default_fields_and_persist() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetTheme(Theme::Dark)]);
+    assert_eq!(
+        model.colors.get(BitType::Sign),
+        Theme::Dark.color(BitType::Sign)
+    );
+    let mut restored = Model::new();
+    restored.restore_session(&model.session());
+    assert_eq!(restored.theme, Theme::Dark);
+
+    // A colour the user picked survives a change of theme.
+    send(
+        &mut model,
+        vec![
+            Msg::SetColor(BitType::Sign, "#000000".to_owned()),
+            Msg::SetTheme(Theme::HighContrast),
+        ],
+    );
+    assert_eq!(model.colors.get(BitType::Sign), "#000000");
+    send(&mut model, vec![Msg::ResetColors]);
+    assert_eq!(
+        model.colors.get(BitType::Exponent),
+        Theme::HighContrast.color(BitType::Exponent)
+    );
+}
+
+#[wasm_bindgen_test]
+fn toggled_bits_are_announced() {
+    let mut model = Model::new();
+    assert!(model.announcement.is_empty());
+    send(&mut model, vec![Msg::ToggleBit(2)]);
+    assert!(model
+        .announcement
+        .starts_with("Exponent bit 9 is now 1, giving "));
+    send(&mut model, vec![Msg::ToggleBit(0)]);
+    assert!(model
+        .announcement
+        .starts_with("Sign bit 0 is now 1, giving -"));
+}
+
+#[wasm_bindgen_test]
+fn locales_translate_and_persist() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetLocale(Locale::Spanish)]);
+    assert_eq!(model.locale.text(Text::Undo), "Deshacer");
+    assert_eq!(
+        model.locale.text(Text::Class(model.encoding.class())),
+        "cero positivo"
+    );
+    assert_eq!(
+        i18n::fill(
+            model.locale.text(Text::Heading(i18n::Heading::Tradeoff)),
+            &["64"]
+        ),
+        "Rango frente a precisión con 64 bits"
+    );
+    assert_eq!(model.locale.text(Button::Play), "Reproducir");
+    assert_eq!(
+        Line::new(Sentence::StepOf, vec!["2".to_owned(), "5".to_owned()]).text(model.locale),
+        "Paso 2 de 5"
+    );
+    let mut restored = Model::new();
+    restored.restore_session(&model.session());
+    assert_eq!(restored.locale, Locale::Spanish);
+    assert_eq!(Locale::from_tag("fr-CA"), Some(Locale::French));
+    assert_eq!(Locale::from_tag("de"), None);
+}
+
+#[wasm_bindgen_test]
+fn logarithms_are_exact_for_powers() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetFromDecimal("0.125".to_owned())]);
+    assert_eq!(model.encoding.exact_log2(), Some(-3));
+    assert_eq!(model.encoding.exact_log10(), None);
+    send(&mut model, vec![Msg::SetFromDecimal("1000".to_owned())]);
+    assert_eq!(model.encoding.exact_log2(), None);
+    assert_eq!(model.encoding.exact_log10(), Some(3));
+    assert!((model.encoding.log2() - 1000_f64.log2()).abs() < 1e-12);
+    // 0.1 is only close to a power of ten.
+    send(&mut model, vec![Msg::SetFromDecimal("0.1".to_owned())]);
+    assert_eq!(model.encoding.exact_log10(), None);
+    send(
+        &mut model,
+        vec![Msg::SetSpecial(SpecialValue::MinSubnormal)],
+    );
+    assert_eq!(model.encoding.exact_log2(), Some(-1074));
+}
+
+#[wasm_bindgen_test]
+fn typed_binary_strings_set_every_bit() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetFromBinary("0 01111 1000000000".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 1.5);
+    assert_eq!(
+        model.history.last().unwrap().label,
+        "Set to 0 01111 1000000000"
+    );
+
+    send(
+        &mut model,
+        vec![Msg::SetFromBinary("0_01111_10".to_owned())],
+    );
+    assert_eq!(
+        model.binary_error.as_deref(),
+        Some("Expected 16 bits but found 8")
+    );
+    assert_eq!(model.encoding.value(), 1.5);
+    // The field string shown in the input reads back as the same bits.
+    let shown = model.encoding.field_string();
+    send(&mut model, vec![Msg::SetFromBinary(shown)]);
+    assert_eq!(model.binary_error, None);
+    assert_eq!(model.encoding.value(), 1.5);
+}
+
+#[wasm_bindgen_test]
+fn typed_decimals_show_their_rounding_error() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetFromDecimal("0.1".to_owned())]);
+    assert!(model.flags.inexact);
+    assert_eq!(
+        text::decimal_rounding_error("0.1", &model.encoding, 100).as_deref(),
+        Some("0.0000000000000000055511151231257827021181583404541015625")
+    );
+    // Too few digits to write the error out.
+    assert_eq!(
+        text::decimal_rounding_error("0.1", &model.encoding, 10),
+        None
+    );
+    send(&mut model, vec![Msg::SetFromDecimal("-0.5".to_owned())]);
+    assert_eq!(
+        text::decimal_rounding_error("-0.5", &model.encoding, 100).as_deref(),
+        Some("0")
+    );
+}
+
+#[wasm_bindgen_test]
+fn characteristics_follow_the_layout() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::ApplyPreset(FormatPreset::Binary16)]);
+    let encoding = &model.encoding;
+    assert_eq!((encoding.emin(), encoding.emax()), (-14, 15));
+    assert_eq!(encoding.precision(), 11);
+    // Everything below the all ones exponent, of either sign.
+    assert_eq!(encoding.finite_count(), BigUint::from_u64(2 * 31 * 1024));
+    send(&mut model, vec![Msg::SetLayout(4, 3)]);
+    assert_eq!((model.encoding.emin(), model.encoding.emax()), (-6, 7));
+    assert_eq!(model.encoding.finite_count(), BigUint::from_u64(2 * 15 * 8));
+    send(&mut model, vec![Msg::ToggleInfinities, Msg::ToggleNans]);
+    assert_eq!(model.encoding.finite_count(), BigUint::from_u64(256));
+}
+
+#[wasm_bindgen_test]
+fn twos_complement_encodings_are_compared() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::ToggleTwosComplement,
+            Msg::SetFromDecimal("-2".to_owned()),
+        ],
+    );
+    assert!(model.twos_complement);
+    let bits: String = interpretation::twos_complement(&model.encoding)
+        .expect("-2 is an integer")
+        .iter()
+        .map(|&b| if b { '1' } else { '0' })
+        .collect();
+    assert_eq!(bits, "1111111111111110");
+    send(&mut model, vec![Msg::SetFromDecimal("0.5".to_owned())]);
+    assert_eq!(
+        interpretation::twos_complement(&model.encoding),
+        Err(Unrepresentable::Fractional)
+    );
+    // The largest binary16 value is 65504, beyond i16.
+    send(&mut model, vec![Msg::SetFromDecimal("40000".to_owned())]);
+    assert_eq!(
+        interpretation::twos_complement(&model.encoding),
+        Err(Unrepresentable::OutOfRange)
+    );
+    send(&mut model, vec![Msg::SetFromDecimal("-32768".to_owned())]);
+    assert!(interpretation::twos_complement(&model.encoding).is_ok());
+    send(&mut model, vec![Msg::SetFromDecimal("inf".to_owned())]);
+    assert_eq!(
+        interpretation::twos_complement(&model.encoding),
+        Err(Unrepresentable::NotFinite)
+    );
+}
+
+#[wasm_bindgen_test]
+fn decimal_conversions_are_worked_through() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::StartWalkthrough("-6.625".to_owned()),
+        ],
+    );
+    assert_eq!(model.encoding.value(), -6.625);
+    let walkthrough = model.walkthrough.as_ref().expect("walkthrough started");
+    let titles: Vec<_> = walkthrough
+        .steps
+        .iter()
+        .map(|step| Locale::English.text(step.title))
+        .collect();
+    assert_eq!(
+        titles,
+        [
+            "Sign",
+            "Split",
+            "Integer part",
+            "Fraction part",
+            "Normalize",
+            "Bias the exponent",
+            "Round"
+        ]
+    );
+    assert!(walkthrough.steps[3]
+        .lines
+        .iter()
+        .any(|line| line.text(Locale::English) == "0.625 \u{d7} 2 = 1.25 \u{2192} 1"));
+    // Only the sign bit is settled by the first step.
+    assert_eq!(walkthrough.settled(0), Ordering::Equal);
+    assert_eq!(walkthrough.settled(1), Ordering::Greater);
+    send(&mut model, vec![Msg::ShowWalkthroughStep(100)]);
+    let walkthrough = model.walkthrough.as_ref().expect("walkthrough shown");
+    assert_eq!(walkthrough.step, 6);
+    assert!((0..16).all(|i| walkthrough.settled(i) != Ordering::Greater));
+    send(&mut model, vec![Msg::CloseWalkthrough]);
+    assert!(model.walkthrough.is_none());
+}
+
+#[wasm_bindgen_test]
+fn quiz_answers_are_checked_and_scored() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![Msg::ApplyPreset(FormatPreset::Binary16), Msg::StartQuiz(1)],
+    );
+    let quiz = model.quiz.clone().expect("quiz started");
+    assert_eq!(quiz.status, Status::Unanswered);
+    assert!(quiz.answer.same_layout(&model.encoding));
+    // A wrong answer counts as an attempt, and the right one then solves it.
+    send(&mut model, vec![Msg::Load(quiz.answer.toggled(0))]);
+    send(
+        &mut model,
+        vec![
+            Msg::CheckQuizBits,
+            Msg::AnswerQuiz("12345".to_owned()),
+            Msg::AnswerQuiz(format_encoding(&quiz.answer)),
+        ],
+    );
+    let quiz = model.quiz.clone().expect("quiz kept");
+    assert_eq!(quiz.status, Status::Right);
+    assert_eq!((quiz.attempts, quiz.solved), (3, 1));
+    // Answers after a question is settled don't count.
+    send(&mut model, vec![Msg::CheckQuizBits]);
+    assert_eq!(model.quiz.as_ref().map(|quiz| quiz.attempts), Some(3));
+    send(&mut model, vec![Msg::NextQuestion(2), Msg::RevealAnswer]);
+    let quiz = model.quiz.clone().expect("quiz kept");
+    assert_eq!(quiz.status, Status::Revealed);
+    assert_eq!((quiz.asked, quiz.solved), (2, 1));
+    send(
+        &mut model,
+        vec![Msg::Load(quiz.answer.clone()), Msg::CheckQuizBits],
+    );
+    assert_eq!(model.quiz.as_ref().map(|quiz| quiz.solved), Some(1));
+    send(&mut model, vec![Msg::StopQuiz]);
+    assert!(model.quiz.is_none());
+}
+
+#[wasm_bindgen_test]
+fn operations_move_the_sign_and_exponent() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetFromDecimal("-1.5".to_owned()),
+            Msg::ApplyUnary(UnaryOperator::Negate),
+            Msg::ApplyUnary(UnaryOperator::Double),
+        ],
+    );
+    assert_eq!(model.encoding.value(), 3.0);
+    send(&mut model, vec![Msg::ApplyUnary(UnaryOperator::Negate)]);
+    send(&mut model, vec![Msg::ApplyUnary(UnaryOperator::Abs)]);
+    assert_eq!(model.encoding.value(), 3.0);
+    // Halving the smallest normal value shifts its significand into a subnormal, exactly.
+    let min_normal = model
+        .encoding
+        .min_normal()
+        .expect("binary16 has normal values");
+    send(
+        &mut model,
+        vec![
+            Msg::ClearFlags,
+            Msg::Load(min_normal),
+            Msg::ApplyUnary(UnaryOperator::Halve),
+        ],
+    );
+    assert_eq!(model.encoding.field(BitType::Exponent), 0);
+    assert_eq!(model.encoding.value(), 2_f64.powi(-15));
+    assert_eq!(model.flags, Flags::default());
+    // Half the smallest subnormal ties between it and zero, and rounds to the even zero.
+    let min_subnormal = model.encoding.min_subnormal();
+    send(
+        &mut model,
+        vec![
+            Msg::Load(min_subnormal),
+            Msg::ApplyUnary(UnaryOperator::Halve),
+        ],
+    );
+    assert!(model.encoding.is_zero());
+    assert!(model.flags.underflow && model.flags.inexact);
+    let max = model.encoding.max_finite(false);
+    send(
+        &mut model,
+        vec![
+            Msg::ClearFlags,
+            Msg::Load(max),
+            Msg::ApplyUnary(UnaryOperator::Double),
+        ],
+    );
+    assert!(model.encoding.is_infinite());
+    assert!(model.flags.overflow && model.flags.inexact);
+    // The history names each operation.
+    assert!(model.history.iter().any(|entry| entry.label == "Halved"));
+}
+
+#[wasm_bindgen_test]
+fn recordings_replay_their_edits() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::StartRecording,
+            Msg::StartDrag(1),
+            Msg::EndDrag,
+            Msg::SetFromDecimal("2.5".to_owned()),
+            Msg::ToggleExplainMode,
+            Msg::StopRecording,
+        ],
+    );
+    let replay = model.replay.as_ref().expect("recording kept for replay");
+    // The drag is saved as the toggle it made, and panel toggles aren't saved at all.
+    assert_eq!(
+        replay.recording.steps,
+        ["ToggleBit 1", "SetFromDecimal 2.5"]
+    );
+    let script = replay.recording.script();
+    send(&mut model, vec![Msg::RewindReplay]);
+    assert!(model.encoding.is_zero());
+    send(&mut model, vec![Msg::ReplayStep]);
+    assert_eq!(model.encoding.value(), 2.0);
+    send(&mut model, vec![Msg::ReplayStep]);
+    assert_eq!(model.encoding.value(), 2.5);
+    assert_eq!(
+        model.history.last().map(|step| step.label.as_str()),
+        Some("Set to 2.5")
+    );
+    assert_eq!(
+        send(&mut model, vec![Msg::ReplayStep]),
+        vec![Effect::SkipRender]
+    );
+    // The script reads back as the same recording, starting over.
+    send(
+        &mut model,
+        vec![
+            Msg::CloseReplay,
+            Msg::Clear,
+            Msg::LoadRecording(script.clone()),
+        ],
+    );
+    let replay = model.replay.as_ref().expect("script loaded");
+    assert_eq!((replay.position, replay.recording.script()), (0, script));
+    for step in &["Wiggle 3", "SetLayout 0 5", "SetLayout 70 300"] {
+        model.recording_error = None;
+        send(
+            &mut model,
+            vec![Msg::LoadRecording(format!("encoding=5-10-0000\n{}", step))],
+        );
+        assert!(model.recording_error.is_some(), "{} was loaded", step);
+    }
+}
+
+#[wasm_bindgen_test]
+fn state_is_exported_and_imported_as_json() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::X87Extended),
+            Msg::SetFromDecimal("-0.1".to_owned()),
+            Msg::SetSecondFromDecimal("3".to_owned()),
+            Msg::Calculate(Operator::Multiply),
+            Msg::SetRounding(RoundingMode::TowardZero),
+            Msg::SetTheme(Theme::Dark),
+            Msg::ToggleNibbleGroups,
+        ],
+    );
+    let json = model.state().to_string();
+    let (encoding, second) = (model.encoding.clone(), model.second.clone());
+    let mut copy = Model::new();
+    send(&mut copy, vec![Msg::ImportState(json)]);
+    assert_eq!(copy.state_error, None);
+    assert!(copy.encoding == encoding && copy.second == second);
+    assert_eq!(copy.operator, Some(Operator::Multiply));
+    assert_eq!(copy.rounding, RoundingMode::TowardZero);
+    assert_eq!(copy.theme, Theme::Dark);
+    assert!(copy.group_nibbles);
+    assert_eq!(
+        copy.history.last().map(|step| step.label.as_str()),
+        Some("Imported state")
+    );
+    // Only the format and bits are needed, and a bad setting leaves everything as it was.
+    send(
+        &mut copy,
+        vec![Msg::ImportState(
+            r#"{"format": {"exponent_bits": 5, "significand_bits": 10}, "bits": "0x3C00"}"#
+                .to_owned(),
+        )],
+    );
+    assert_eq!(copy.encoding.value(), 1.0);
+    assert_eq!(copy.rounding, RoundingMode::TowardZero);
+    send(
+        &mut copy,
+        vec![Msg::ImportState(
+            r#"{"format": {"exponent_bits": 5, "significand_bits": 10}, "bits": "0x4000",
+                "rounding": "sideways"}"#
+                .to_owned(),
+        )],
+    );
+    assert_eq!(
+        copy.state_error.as_deref(),
+        Some("The rounding isn't valid")
+    );
+    assert_eq!(copy.encoding.value(), 1.0);
+    send(&mut copy, vec![Msg::ImportState("{".to_owned())]);
+    assert!(copy.state_error.is_some());
+}
+
+#[wasm_bindgen_test]
+fn bit_layout_is_drawn_as_svg() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetFromDecimal("-2".to_owned()),
+            Msg::SetColor(BitType::Sign, "#123456".to_owned()),
+        ],
+    );
+    let svg = svg::bit_layout(&model.encoding, &model.colors.palette(model.theme));
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.contains("-2.0 = 0xC000"));
+    // A square per bit, plus the background.
+    assert_eq!(svg.matches("<rect").count(), 17);
+    assert_eq!(svg.matches("fill=\"#123456\"").count(), 1);
+    assert!(svg.contains("Exponent (5)") && svg.contains("Significand (10)"));
+}
+
+#[wasm_bindgen_test]
+fn small_formats_enumerate_their_finite_values() {
+    let mut model = Model::new();
+    send(&mut model, vec![Msg::SetLayout(4, 3)]);
+    let values: Vec<f64> = model
+        .encoding
+        .nonnegative_finite()
+        .map(|value| value.value())
+        .collect();
+    // Fifteen binades of eight, the all ones exponent being reserved.
+    assert_eq!(values.len(), 120);
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(values[1], 2_f64.powi(-9));
+    assert_eq!(values.last(), Some(&240.0));
+    send(&mut model, vec![Msg::ToggleInfinities, Msg::ToggleNans]);
+    assert_eq!(model.encoding.nonnegative_finite().count(), 128);
+}
+
+#[wasm_bindgen_test]
+fn kahan_summation_recovers_lost_bits() {
+    let mut model = Model::new();
+    send(
+        &mut model,
+        vec![
+            Msg::ApplyPreset(FormatPreset::Binary16),
+            Msg::SetSummands("1 0.0003 0.0003, 0.0003\n0.0003 -0.5".to_owned()),
+        ],
+    );
+    let inputs: Vec<Encoding> = model
+        .summands
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|text| !text.is_empty())
+        .map(|text| {
+            parse_decimal(text, &model.encoding, model.rounding)
+                .unwrap()
+                .0
+        })
+        .collect();
+    let summation = summation::Summation::new(&inputs, model.rounding).unwrap();
+    let last = summation.steps.last().unwrap();
+    // Each 0.0003 is under half an ulp of 1, so the naive sum never moves.
+    assert_eq!(last.naive.value(), 0.5);
+    assert_eq!(last.kahan.value(), 0.500_976_562_5);
+    let exact = summation.exact.unwrap();
+    assert_eq!(exact.to_decimal(40), "0.5011997222900390625");
+    assert_eq!(
+        exact.rounded(&model.encoding, model.rounding).0.to_bits(),
+        last.kahan.to_bits()
+    );
+}
+
+#[wasm_bindgen_test]
+fn hard_cases_have_long_runs() {
+    let one = FormatPreset::Binary64.zero().with_bits(1_f64.to_bits());
+    let e = dilemma::Function::Exp.evaluate(&one).unwrap();
+    let significand = e.bits[..53]
+        .iter()
+        .fold(0_u64, |acc, &bit| acc << 1 | u64::from(bit));
+    assert_eq!((e.exponent, e.bits[53]), (1, false));
+    assert_eq!(
+        significand & ((1 << 52) - 1),
+        std::f64::consts::E.to_bits() & ((1 << 52) - 1)
+    );
+    let runs: Vec<(bool, usize)> = dilemma::HARD_CASES
+        .iter()
+        .map(|case| {
+            let precision = case.input().precision() as usize;
+            let result = case.result();
+            (result.bits[precision], result.run(precision))
+        })
+        .collect();
+    assert_eq!(runs, [(true, 57), (false, 64), (true, 27), (true, 33)]);
+    let mut model = Model::new();
+    let case = &dilemma::HARD_CASES[1];
+    send(&mut model, vec![Msg::Load(case.input())]);
+    assert_eq!(model.encoding.to_bits(), case.bits);
+}
+
+#[wasm_bindgen_test]
+fn data_file_elements_are_browsed() {
+    let mut model = Model::new();
+    let bytes = [1.5_f32, 2.0, f32::NAN, -0.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes().to_vec())
+        .collect();
+    send(
+        &mut model,
+        vec![Msg::DataFileRead("data.bin".to_owned(), Ok(bytes))],
+    );
+    assert_eq!(model.encoding.exponent_bits.len(), 8);
+    assert_eq!(model.encoding.value(), 1.5);
+    send(&mut model, vec![Msg::ShowElement(1)]);
+    assert_eq!(model.encoding.value(), 2.0);
+    send(&mut model, vec![Msg::NextNan]);
+    assert!(model.encoding.is_nan());
+    send(&mut model, vec![Msg::SetDataElement(ElementType::F64)]);
+    assert_eq!(model.encoding.exponent_bits.len(), 11);
+    assert_eq!(model.data_file.as_ref().map(DataFile::len), Some(2));
+}
+
+/// Not a pass/fail test: logs how long rendering takes so that changes to the render path
+/// can be compared. Run with `wasm-pack test --headless --firefox -- --nocapture`.
+#[wasm_bindgen_test]
+fn benchmark_render() {
+    const RENDERS: u32 = 200;
+    let mut model = Model::new();
+    for &preset in &FormatPreset::ALL {
+        handle(Msg::ApplyPreset(preset), &mut model, 0.0);
+        // Every bit flipped, so the animation classes are rendered too.
+        model.flipped = vec![true; model.encoding.len()];
+        let start = js_sys::Date::now();
+        for _ in 0..RENDERS {
+            view(&model);
+        }
+        console_log!(
+            "{}: {:.3} ms per render",
+            preset.name(),
+            (js_sys::Date::now() - start) / f64::from(RENDERS)
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! Converting values and encodings to and from text.
+
+use crate::{
+    bignum::BigUint,
+    encoding::{BitType, Encoding, Flags, RoundingMode, BINARY_64_EXPONENT_BITS},
+};
+use std::{cmp::Ordering, convert::TryFrom};
+
+/// The formats recognised when pasting into the app, all of which it can copy too.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PasteFormat {
+    Decimal,
+    Hex,
+    Binary,
+    HexFloat,
+}
+
+impl PasteFormat {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Decimal => "Decimal",
+            Self::Hex => "Hex pattern",
+            Self::Binary => "Binary string",
+            Self::HexFloat => "Hex float",
+        }
+    }
+
+    /// The formats in the order they are offered for copying.
+    pub const COPYABLE: [Self; 4] = [Self::Hex, Self::Binary, Self::Decimal, Self::HexFloat];
+
+    /// The encoding as text in this format, which pastes back as the same encoding.
+    pub fn format(self, encoding: &Encoding) -> String {
+        match self {
+            Self::Decimal => format_encoding(encoding),
+            Self::Hex => encoding.hex(),
+            Self::Binary => encoding.bits().map(|b| if b { '1' } else { '0' }).collect(),
+            Self::HexFloat => format_hex_float(encoding),
+        }
+    }
+
+    /// All formats `text` could be in for an encoding `width` bits wide.
+    pub fn detect(text: &str, width: usize) -> Vec<Self> {
+        let text = text.trim();
+        let unsigned = text.trim_start_matches(|c| c == '+' || c == '-');
+        if unsigned.starts_with("0x") || unsigned.starts_with("0X") {
+            return if unsigned.contains(|c| c == 'p' || c == 'P') {
+                vec![Self::HexFloat]
+            } else if unsigned.len() == text.len() {
+                vec![Self::Hex]
+            } else {
+                // A sign is meaningless on a raw bit pattern.
+                Vec::new()
+            };
+        }
+        if text.starts_with("0b") || text.starts_with("0B") || text.contains('|') {
+            return vec![Self::Binary];
+        }
+
+        let digits: String = text
+            .chars()
+            .filter(|&c| !c.is_whitespace() && c != '_')
+            .collect();
+        let mut formats = Vec::new();
+        if digits.len() == width && digits.chars().all(|c| c == '0' || c == '1') {
+            formats.push(Self::Binary);
+        }
+        if digits.len() == (width + 3) / 4 && digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            formats.push(Self::Hex);
+        }
+        if text.parse::<f64>().is_ok() {
+            formats.push(Self::Decimal);
+        }
+        formats
+    }
+
+    /// Parses `text` as this format into an encoding with the same layout as `current`,
+    /// rounding decimals in the direction of `rounding`, along with the flags that raises.
+    pub fn parse(
+        self,
+        text: &str,
+        current: &Encoding,
+        rounding: RoundingMode,
+    ) -> Result<(Encoding, Flags), String> {
+        let width = current.len();
+        let digits = |prefixes: &[&str]| -> String {
+            let text = text.trim();
+            let text = prefixes
+                .iter()
+                .find_map(|&p| text.strip_prefix(p))
+                .unwrap_or(text);
+            text.chars()
+                .filter(|&c| !c.is_whitespace() && c != '_' && c != '|')
+                .collect()
+        };
+        match self {
+            Self::Hex => {
+                let digits = digits(&["0x", "0X"]);
+                if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(format!("\"{}\" is not a hex pattern", digits));
+                }
+                current
+                    .with_hex(&digits)
+                    .map(|encoding| (encoding, Flags::default()))
+                    .ok_or_else(|| format!("0x{} does not fit in {} bits", digits, width))
+            }
+            Self::Binary => {
+                let digits = digits(&["0b", "0B"]);
+                if digits.len() != width {
+                    return Err(format!(
+                        "Expected {} bits but found {}",
+                        width,
+                        digits.len()
+                    ));
+                }
+                if !digits.chars().all(|c| c == '0' || c == '1') {
+                    return Err(format!("\"{}\" is not a binary string", digits));
+                }
+                let mut encoding = current.clone();
+                for (bit, digit) in encoding.bits_mut().zip(digits.chars()) {
+                    *bit = digit == '1';
+                }
+                Ok((encoding, Flags::default()))
+            }
+            Self::Decimal => parse_decimal(text, current, rounding),
+            Self::HexFloat => parse_hex_float(text, current, rounding),
+        }
+    }
+}
+
+/// Decimal exponents beyond which every supported format overflows or rounds to zero, so that
+/// exact arithmetic on even larger powers of ten is never needed. binary256 reaches about
+/// 10^78913.
+const DECIMAL_EXPONENT_LIMIT: i64 = 80_000;
+
+/// Parses a decimal number such as `0.1`, `-1.5e-3` or `inf` and rounds it in the direction of
+/// `rounding` in the format of `current`. The rounding is done exactly rather than through
+/// `f64`, which would round twice.
+pub fn parse_decimal(
+    text: &str,
+    current: &Encoding,
+    rounding: RoundingMode,
+) -> Result<(Encoding, Flags), String> {
+    match decimal_ratio(text)? {
+        Decimal::Infinity(sign_bit) => current
+            .infinity(sign_bit)
+            .map(|infinity| (infinity, Flags::default()))
+            .ok_or_else(|| "This format has no infinities".to_owned()),
+        Decimal::Nan => current
+            .default_nan()
+            .map(|nan| (nan, Flags::default()))
+            .ok_or_else(|| "This format has no NaNs".to_owned()),
+        Decimal::Finite(sign_bit, numerator, fraction_len) => Ok(current.round_ratio(
+            sign_bit,
+            &numerator,
+            &BigUint::pow10(fraction_len),
+            rounding,
+        )),
+    }
+}
+
+/// A typed decimal number, with the magnitude of a finite one as an integer and the number of
+/// decimal places to move the point left by.
+pub enum Decimal {
+    Finite(bool, BigUint, u32),
+    Infinity(bool),
+    Nan,
+}
+
+/// Parses a decimal number exactly, clamping exponents to `DECIMAL_EXPONENT_LIMIT`.
+pub fn decimal_ratio(text: &str) -> Result<Decimal, String> {
+    let invalid = || format!("\"{}\" is not a decimal number", text.trim());
+    let trimmed = text.trim().to_ascii_lowercase();
+    let (sign_bit, unsigned) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(&trimmed)),
+    };
+    match unsigned {
+        "inf" | "infinity" => return Ok(Decimal::Infinity(sign_bit)),
+        "nan" => return Ok(Decimal::Nan),
+        _ => {}
+    }
+
+    let (mantissa, exponent) = match unsigned.find('e') {
+        Some(i) => (
+            &unsigned[..i],
+            unsigned[i + 1..].parse::<i64>().map_err(|_| invalid())?,
+        ),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let digits = format!("{}{}", integer, fraction);
+    let digits = digits.trim_start_matches('0');
+    // Zero is zero whatever the exponent, so it mustn't be clamped to the smallest magnitude.
+    if digits.is_empty() {
+        return Ok(Decimal::Finite(sign_bit, BigUint::from_u64(0), 0));
+    }
+    #[allow(clippy::cast_possible_wrap)]
+    let (digit_count, fraction_len) = (digits.len() as i64, fraction.len() as i64);
+    let mut exponent = exponent.saturating_sub(fraction_len);
+
+    // The value is below 10^magnitude and at least a tenth of that.
+    let magnitude = exponent.saturating_add(digit_count);
+    let mut numerator = BigUint::from_u64(0);
+    if magnitude > DECIMAL_EXPONENT_LIMIT {
+        numerator = BigUint::from_u64(1);
+        exponent = DECIMAL_EXPONENT_LIMIT;
+    } else if magnitude < -DECIMAL_EXPONENT_LIMIT {
+        numerator = BigUint::from_u64(1);
+        exponent = -DECIMAL_EXPONENT_LIMIT - 1;
+    } else {
+        for digit in digits.chars().filter_map(|c| c.to_digit(10)) {
+            numerator.mul_small(10);
+            numerator.add_small(digit);
+        }
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let power = exponent.unsigned_abs() as u32;
+    if exponent >= 0 {
+        numerator.mul_pow10(power);
+        Ok(Decimal::Finite(sign_bit, numerator, 0))
+    } else {
+        Ok(Decimal::Finite(sign_bit, numerator, power))
+    }
+}
+
+/// How far the value stored for a typed decimal is from the decimal itself, `stored − typed`,
+/// exactly: `0.0000000000000000055511151231257827021181583404541015625` for 0.1 in binary64.
+/// It always terminates, as both have denominators dividing a power of ten. `None` if either
+/// isn't finite or the difference would be longer than `max_digits`.
+pub fn decimal_rounding_error(text: &str, stored: &Encoding, max_digits: usize) -> Option<String> {
+    let (typed_sign, typed_numerator, m) = match decimal_ratio(text).ok()? {
+        Decimal::Finite(sign_bit, numerator, fraction_len) => (sign_bit, numerator, fraction_len),
+        _ => return None,
+    };
+    if stored.is_nan() || stored.is_infinite() {
+        return None;
+    }
+    let (stored_numerator, stored_denominator) = stored.exact_ratio();
+    let k = stored_denominator.bit_len() - 1;
+    // Both over 10^m × 2^k, which is 10^(m + k) / 5^k, so scaling the difference by 5^k
+    // gives its digits with m + k of them after the point.
+    let fraction_len = m as usize + k;
+    if fraction_len > max_digits {
+        return None;
+    }
+    let typed = typed_numerator.shl(k);
+    let mut stored_scaled = stored_numerator;
+    stored_scaled.mul_pow10(m);
+    let (negative, difference) = match (stored.sign_bit, typed_sign) {
+        (a, b) if a != b => (a, stored_scaled.add(&typed)),
+        (sign_bit, _) if stored_scaled >= typed => (sign_bit, stored_scaled.sub(&typed)),
+        (sign_bit, _) => (!sign_bit, typed.sub(&stored_scaled)),
+    };
+    if difference.is_zero() {
+        return Some("0".to_owned());
+    }
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let integer_len = ((difference.bit_len() as f64 - k as f64) * 2_f64.log10()).ceil() as usize;
+    if integer_len + fraction_len > max_digits {
+        return None;
+    }
+    let mut scaled = difference;
+    for _ in 0..k {
+        scaled.mul_small(5);
+    }
+    let digits = format!("{:01$}", scaled, fraction_len + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - fraction_len);
+    let fraction = fraction.trim_end_matches('0');
+    Some(format!(
+        "{}{}{}{}",
+        if negative { "-" } else { "" },
+        integer,
+        if fraction.is_empty() { "" } else { "." },
+        fraction
+    ))
+}
+
+/// Binary exponents beyond which every supported format overflows or rounds to zero, as
+/// `DECIMAL_EXPONENT_LIMIT` is for decimal ones. binary256 reaches about 2^262144.
+const BINARY_EXPONENT_LIMIT: i64 = 270_000;
+
+/// Parses a hex float as written by C's `%a`, such as `0x1.999999999999ap-4`, and rounds it in
+/// the direction of `rounding` in the format of `current`. The exponent is optional, as are the
+/// digits on either side of the point, though not both.
+pub fn parse_hex_float(
+    text: &str,
+    current: &Encoding,
+    rounding: RoundingMode,
+) -> Result<(Encoding, Flags), String> {
+    let invalid = || format!("\"{}\" is not a hex float", text.trim());
+    let trimmed = text.trim().to_ascii_lowercase();
+    let (sign_bit, unsigned) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(&trimmed)),
+    };
+    if matches!(unsigned, "inf" | "infinity" | "nan") {
+        return parse_decimal(text, current, rounding);
+    }
+    let unsigned = unsigned.strip_prefix("0x").ok_or_else(invalid)?;
+    let (mantissa, exponent) = match unsigned.find('p') {
+        Some(i) => (
+            &unsigned[..i],
+            unsigned[i + 1..].parse::<i64>().map_err(|_| invalid())?,
+        ),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_hexdigit())
+    {
+        return Err(invalid());
+    }
+    let digits = format!("{}{}", integer, fraction);
+    let digits = digits.trim_start_matches('0');
+    // As in `decimal_ratio`, zero mustn't be clamped to the smallest magnitude.
+    if digits.is_empty() {
+        let zero = BigUint::from_u64(0);
+        return Ok(current.round_ratio(sign_bit, &zero, &BigUint::from_u64(1), rounding));
+    }
+    #[allow(clippy::cast_possible_wrap)]
+    let (digit_count, fraction_len) = (digits.len() as i64, fraction.len() as i64);
+    let mut exponent = exponent.saturating_sub(4 * fraction_len);
+
+    // The value is below 2^magnitude.
+    let magnitude = exponent.saturating_add(4 * digit_count);
+    let mut numerator = BigUint::from_u64(0);
+    if magnitude > BINARY_EXPONENT_LIMIT {
+        numerator = BigUint::from_u64(1);
+        exponent = BINARY_EXPONENT_LIMIT;
+    } else if magnitude < -BINARY_EXPONENT_LIMIT {
+        numerator = BigUint::from_u64(1);
+        exponent = -BINARY_EXPONENT_LIMIT - 1;
+    } else {
+        for digit in digits.chars().filter_map(|c| c.to_digit(16)) {
+            numerator.mul_small(16);
+            numerator.add_small(digit);
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let shift = exponent.unsigned_abs() as usize;
+    let (numerator, denominator) = if exponent >= 0 {
+        (numerator.shl(shift), BigUint::from_u64(1))
+    } else {
+        (numerator, BigUint::from_u64(1).shl(shift))
+    };
+    Ok(current.round_ratio(sign_bit, &numerator, &denominator, rounding))
+}
+
+/// Formats the value exactly as C's `%a` does, e.g. `0x1.999999999999ap-4` for 0.1 in
+/// binary64: the leading bit, the rest of the significand in hex without trailing zeros, and
+/// the unbiased exponent. Subnormals keep a leading 0 and the smallest normal exponent.
+pub fn format_hex_float(encoding: &Encoding) -> String {
+    let sign = if encoding.sign_bit { "-" } else { "" };
+    if encoding.is_nan() {
+        return format!("{}nan", sign);
+    }
+    if encoding.is_infinite() {
+        return format!("{}inf", sign);
+    }
+    if encoding.is_zero() {
+        return format!("{}0x0p+0", sign);
+    }
+    let fraction = &encoding.significand_bits[usize::from(!encoding.options.implicit_bit)..];
+    let digits: String = fraction
+        .chunks(4)
+        .map(|chunk| {
+            let digit = (0..4).fold(0, |acc, i| {
+                (acc << 1) | u32::from(chunk.get(i).copied().unwrap_or(false))
+            });
+            std::char::from_digit(digit, 16).unwrap_or('0')
+        })
+        .collect();
+    let digits = digits.trim_end_matches('0');
+    let stored_exponent = encoding.field(BitType::Exponent);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let exponent = stored_exponent.max(1) as i64 - encoding.bias() as i64;
+    format!(
+        "{}0x{}{}{}p{:+}",
+        sign,
+        u8::from(encoding.leading_bit()),
+        if digits.is_empty() { "" } else { "." },
+        digits,
+        exponent
+    )
+}
+
+/// Parses a typed unsigned integer as the raw bits of an encoding with the same layout as
+/// `current`.
+pub fn parse_unsigned(text: &str, current: &Encoding) -> Result<Encoding, String> {
+    let width = current.len();
+    match text.trim().parse::<u64>() {
+        Ok(bits) if width >= 64 || bits >> width == 0 => Ok(current.with_bits(bits)),
+        Ok(_) => Err(format!("{} does not fit in {} bits", text, width)),
+        Err(e) => Err(format!("{}: {}", text, e)),
+    }
+}
+
+/// Parses a typed ordinal, as shown by `group_digits`, into the finite value of `current`'s
+/// layout with that ordinal.
+pub fn parse_ordinal(text: &str, current: &Encoding) -> Result<Encoding, String> {
+    if !current.fits_u64() {
+        return Err("Only formats of at most 64 bits have ordinals".to_owned());
+    }
+    let max = current.max_finite(false).to_ordinal();
+    let digits: String = text
+        .chars()
+        .filter(|&c| !c.is_whitespace() && c != ',' && c != '_')
+        .map(|c| if c == '\u{2212}' { '-' } else { c })
+        .collect();
+    match digits.parse::<i64>() {
+        Ok(ordinal) if ordinal.checked_abs().map_or(false, |abs| abs <= max) => {
+            Ok(current.with_ordinal(ordinal))
+        }
+        Ok(_) => Err(format!(
+            "Finite values are numbered from \u{2212}{0} to {0}",
+            group_digits(max)
+        )),
+        Err(e) => Err(format!("{}: {}", text, e)),
+    }
+}
+
+/// Formats an integer with commas between groups of three digits.
+pub fn group_digits(n: impl Into<i128>) -> String {
+    let n = n.into();
+    let digits = n.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if n < 0 {
+        format!("\u{2212}{}", grouped)
+    } else {
+        grouped
+    }
+}
+
+/// Formats `m \u{d7} 2^e` in scientific notation, even beyond the range of `f64`.
+pub fn format_scaled(m: f64, e: i32) -> String {
+    let log = m.log10() + f64::from(e) * 2_f64.log10();
+    let mut exponent = log.floor();
+    let mut mantissa = 10_f64.powf(log - exponent);
+    // Avoid printing e.g. 10.00000e307 due to rounding.
+    if mantissa >= 9.999_995 {
+        mantissa /= 10.0;
+        exponent += 1.0;
+    }
+    format!("{:.5}e{}", mantissa, exponent)
+}
+
+/// Formats the value of an encoding like `format_value`, but with as many significant digits
+/// as the precision calls for, computed exactly, for formats too wide for `f64`.
+pub fn format_encoding(encoding: &Encoding) -> String {
+    if encoding.precision() <= 53 && encoding.exponent_bits.len() <= BINARY_64_EXPONENT_BITS {
+        return format_value(encoding.value());
+    }
+    if encoding.is_nan() || encoding.is_infinite() || encoding.is_zero() {
+        return format_value(encoding.value());
+    }
+    // Enough digits to tell every value of the format apart.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let digits = (f64::from(encoding.precision()) * 2_f64.log10()).ceil() as usize + 1;
+    let (numerator, denominator) = encoding.exact_ratio();
+    let (significand, exponent) = decimal_digits(&numerator, &denominator, digits);
+    let significand = significand.trim_end_matches('0');
+    let sign = if encoding.sign_bit { "-" } else { "" };
+    match usize::try_from(exponent) {
+        // Positional notation over the same range as `format_value`.
+        Ok(e) if e < 10 => {
+            let integer_len = e + 1;
+            let padded = format!("{:0<1$}", significand, integer_len);
+            let (integer, fraction) = padded.split_at(integer_len);
+            let fraction = if fraction.is_empty() { "0" } else { fraction };
+            format!("{}{}.{}", sign, integer, fraction)
+        }
+        Err(_) if exponent >= -10 => {
+            let zeros = "0".repeat(usize::try_from(-exponent - 1).unwrap_or(0));
+            format!("{}0.{}{}", sign, zeros, significand)
+        }
+        _ => {
+            let (first, rest) = significand.split_at(1);
+            let rest = if rest.is_empty() {
+                String::new()
+            } else {
+                format!(".{}", rest)
+            };
+            format!("{}{}{}e{}", sign, first, rest, exponent)
+        }
+    }
+}
+
+/// The exact decimal expansion of a finite value, e.g.
+/// `0.1000000000000000055511151231257827021181583404541015625` for binary64 0.1. It always
+/// terminates, as `1 / 2^k` is `5^k / 10^k`. `None` if it would be longer than `max_digits`.
+pub fn exact_decimal(encoding: &Encoding, max_digits: usize) -> Option<String> {
+    let (mut numerator, denominator) = encoding.exact_ratio();
+    // The denominator is a power of two, so drop common factors of two to find the fewest
+    // fraction digits.
+    let mut fraction_len = denominator.bit_len() - 1;
+    while fraction_len > 0 && !numerator.bit(0) {
+        numerator = numerator.shr(1);
+        fraction_len -= 1;
+    }
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let integer_len =
+        (numerator.bit_len().saturating_sub(fraction_len) as f64 * 2_f64.log10()).ceil() as usize;
+    if integer_len + fraction_len > max_digits {
+        return None;
+    }
+    let sign = if encoding.sign_bit { "-" } else { "" };
+    Some(format!(
+        "{}{}",
+        sign,
+        binary_fraction(&numerator, fraction_len)
+    ))
+}
+
+/// `numerator / 2^fraction_len` in decimal, with `fraction_len` digits after the point.
+pub fn binary_fraction(numerator: &BigUint, fraction_len: usize) -> String {
+    let mut scaled = numerator.clone();
+    for _ in 0..fraction_len {
+        scaled.mul_small(5);
+    }
+    let digits = format!("{:01$}", scaled, fraction_len + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - fraction_len);
+    if fraction.is_empty() {
+        integer.to_owned()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// The first `digits` significant decimal digits of `numerator / denominator`, rounded to
+/// nearest with ties to even, and the decimal exponent of the first of them.
+// Exponents are at most 19 bits wide, so bit lengths are far too small to lose precision.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss
+)]
+fn decimal_digits(numerator: &BigUint, denominator: &BigUint, digits: usize) -> (String, i64) {
+    let low = BigUint::pow10(digits as u32 - 1);
+    let high = BigUint::pow10(digits as u32);
+    // A guess from the bit lengths, corrected below, for the exponent of the first digit.
+    let log2 = numerator.bit_len() as f64 - denominator.bit_len() as f64;
+    let mut exponent = (log2 * 2_f64.log10()).floor() as i64;
+    loop {
+        let shift = exponent - (digits as i64 - 1);
+        let power = BigUint::pow10(shift.unsigned_abs() as u32);
+        let (dividend, divisor) = if shift >= 0 {
+            (numerator.clone(), denominator.mul(&power))
+        } else {
+            (numerator.mul(&power), denominator.clone())
+        };
+        let (mut quotient, remainder) = dividend.div_rem(&divisor);
+        if quotient >= high {
+            exponent += 1;
+            continue;
+        }
+        if quotient < low {
+            exponent -= 1;
+            continue;
+        }
+        let round_up = match remainder.shl(1).cmp(&divisor) {
+            Ordering::Greater => true,
+            Ordering::Equal => quotient.bit(0),
+            Ordering::Less => false,
+        };
+        if round_up {
+            quotient.add_small(1);
+            if quotient == high {
+                return (low.to_string(), exponent + 1);
+            }
+        }
+        return (quotient.to_string(), exponent);
+    }
+}
+
+/// Formats a value for display, switching to scientific notation for extreme magnitudes.
+pub fn format_value(value: f64) -> String {
+    let abs_val = value.abs();
+    if abs_val == 0.0 || (1.0e-10..1.0e10).contains(&abs_val) {
+        format!("{:?}", value)
+    } else {
+        format!("{:e}", value)
+    }
+}
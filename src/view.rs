@@ -0,0 +1,3783 @@
+//! Rendering the model. Everything shown is derived from the model on each render.
+
+use crate::{
+    arithmetic::{calculate, Operator, UnaryOperator},
+    codegen::{self, Language},
+    datafile::ElementType,
+    decimal::{DecimalEncoding, DecimalFormat, Decoded},
+    dilemma::HARD_CASES,
+    encoding::{
+        divide_by_zero, Bit, BitType, Encoding, Flags, FormatPreset, Overflow, RoundingMode,
+        SpecialValue, MAX_BIAS, MAX_EXPONENT_BITS, MAX_SIGNIFICAND_BITS,
+    },
+    i18n::{fill, Button, Heading, Label, Locale, Sentence, Text},
+    interpretation::{twos_complement, FixedPoint, Reinterpretation, MAX_POSIT_ES},
+    quiz::{Kind, Status},
+    summation::{Exact, Summation},
+    svg,
+    testfloat::{self, Operation, Verdict},
+    text::{
+        binary_fraction, decimal_rounding_error, exact_decimal, format_encoding, format_hex_float,
+        format_scaled, format_value, group_digits, parse_decimal, PasteFormat,
+    },
+    walkthrough::Walkthrough,
+    Model, Msg, PasteState, Theme,
+};
+use seed::{prelude::*, *};
+use std::{cmp::Ordering, iter, ops::Range};
+
+// `view` describes what to display.
+pub fn view(model: &Model) -> Vec<Node<Msg>> {
+    let text = |text| model.locale.text(text);
+    nodes![
+        view_restore(model),
+        view_value(model),
+        view_decimal_entry(model),
+        view_walkthrough(model),
+        view_quiz(model),
+        view_exact_decimal(model),
+        view_exact_fraction(model),
+        view_logarithms(model),
+        view_bits(model),
+        view_bit_tools(model),
+        view_special_values(model),
+        view_operations(model),
+        view_copy(model),
+        view_fields(model),
+        view_formula(model),
+        view_neighbours(model),
+        view_stepping(model),
+        view_number_line(model),
+        view_hex_entry(model),
+        view_bytes(model),
+        view_comparison(model),
+        view_flags(model),
+        view_explanation(model),
+        view_signed_zero(model),
+        view_division_by_zero(model),
+        view_invalid_operations(model),
+        view_characteristics(model),
+        view_thresholds(model),
+        view_constants(model),
+        view_ulp_of_one(model),
+        view_format_comparison(model),
+        view_range_plot(model),
+        view_density(model),
+        view_tradeoff(model),
+        view_allocator(model),
+        view_fixed_point(model),
+        view_posit(model),
+        view_decimal(model),
+        view_twos_complement(model),
+        view_integers(model),
+        view_reinterpretations(model),
+        view_interpolation(model),
+        view_interpolation_comparison(model),
+        view_sortable_bits(model),
+        view_ordinal(model),
+        view_codegen(model),
+        view_test_vectors(model),
+        view_testfloat(model),
+        view_summation(model),
+        view_dilemma(model),
+        view_data_file(model),
+        view_paste(model),
+        div![
+            C!["copy"],
+            button![
+                text(Text::CopyBits),
+                ev(Ev::Click, |_| Msg::CopyFieldString)
+            ],
+            a![
+                attrs! {
+                    At::Href => format!(
+                        "data:image/svg+xml;charset=utf-8,{}",
+                        String::from(js_sys::encode_uri_component(&svg::bit_layout(
+                            &model.encoding,
+                            &model.colors.palette(model.theme)
+                        )))
+                    ),
+                    At::Download => "bits.svg",
+                    At::Title => text(Text::Label(Label::SvgTitle))
+                },
+                text(Text::Button(Button::DownloadSvg))
+            ],
+        ],
+        div![
+            C!["controls"],
+            div![
+                C!["presets"],
+                format!("{}: ", text(Text::Presets)),
+                FormatPreset::ALL.iter().map(|&preset| button![
+                    C![IF!(is_preset(model, preset) => "current")],
+                    preset.name(),
+                    ev(Ev::Click, move |_| Msg::ApplyPreset(preset)),
+                ]),
+            ],
+            div![
+                C!["exponent_slider"],
+                format!(
+                    "{} ({}): ",
+                    text(Text::ExponentBits),
+                    model.encoding.exponent_bits.len()
+                ),
+                input![
+                    attrs! {
+                        At::Type => "range",
+                        At::Min => "1",
+                        At::Max => MAX_EXPONENT_BITS.to_string(),
+                        At::Value => model.encoding.exponent_bits.len().to_string()
+                    },
+                    input_ev(Ev::Input, |i| Msg::SetExpSize(
+                        i.parse().expect("Slider must report number")
+                    )),
+                ],
+            ],
+            div![
+                C!["significand_slider"],
+                format!(
+                    "{} ({}): ",
+                    text(Text::SignificandBits),
+                    model.encoding.significand_bits.len()
+                ),
+                input![
+                    attrs! {
+                        At::Type => "range",
+                        At::Min => "1",
+                        At::Max => MAX_SIGNIFICAND_BITS.to_string(),
+                        At::Value => model.encoding.significand_bits.len().to_string()
+                    },
+                    input_ev(Ev::Input, |i| Msg::SetSigSize(
+                        i.parse().expect("Slider must report number")
+                    )),
+                ],
+            ],
+            div![
+                C!["rounding"],
+                RoundingMode::ALL.iter().map(|&rounding| label![
+                    input![
+                        attrs! {
+                            At::Type => "radio",
+                            At::Name => "rounding",
+                            At::Checked => (rounding == model.rounding).as_at_value()
+                        },
+                        ev(Ev::Change, move |_| Msg::SetRounding(rounding)),
+                    ],
+                    rounding.name(),
+                ]),
+            ],
+            div![
+                C!["overflow"],
+                Overflow::ALL.iter().map(|&overflow| label![
+                    input![
+                        attrs! {
+                            At::Type => "radio",
+                            At::Name => "overflow",
+                            At::Checked => (overflow == model.encoding.options.overflow).as_at_value()
+                        },
+                        ev(Ev::Change, move |_| Msg::SetOverflow(overflow)),
+                    ],
+                    text(Text::Overflow(overflow)),
+                ]),
+                label![
+                    input![
+                        attrs! {
+                            At::Type => "checkbox",
+                            At::Checked => model.encoding.options.infinities.as_at_value()
+                        },
+                        ev(Ev::Change, |_| Msg::ToggleInfinities),
+                    ],
+                    text(Text::Infinities),
+                ],
+                label![
+                    input![
+                        attrs! {
+                            At::Type => "checkbox",
+                            At::Checked => model.encoding.options.nans.as_at_value()
+                        },
+                        ev(Ev::Change, |_| Msg::ToggleNans),
+                    ],
+                    text(Text::Nans),
+                ],
+                label![
+                    input![
+                        attrs! {
+                            At::Type => "checkbox",
+                            At::Checked => model.encoding.options.implicit_bit.as_at_value()
+                        },
+                        ev(Ev::Change, |_| Msg::ToggleImplicitBit),
+                    ],
+                    text(Text::ImplicitLeadingBit),
+                ],
+                label![
+                    format!("{}: ", text(Text::Bias)),
+                    input![
+                        C!["bias"],
+                        attrs! {
+                            At::Type => "number",
+                            At::Min => "0",
+                            At::Max => MAX_BIAS.to_string(),
+                            At::Value => model.encoding.options.bias.map_or_else(String::new, |b| b.to_string()),
+                            At::Placeholder => model.encoding.standard_bias().to_string()
+                        },
+                        input_ev(Ev::Change, Msg::SetBias),
+                    ],
+                ],
+                model.bias_error.as_ref().map(|e| div![C!["error"], e.as_str()]),
+            ],
+        ],
+        view_colors(model),
+        view_history(model),
+        view_timeline(model),
+        view_recording(model),
+        view_state(model),
+    ]
+}
+
+/// The heading of a panel in the chosen language.
+fn heading(model: &Model, heading: Heading) -> &'static str {
+    model.locale.text(Text::Heading(heading))
+}
+
+/// `sentence` in the chosen language, with `values` in place of its `{}`s.
+fn sentence(model: &Model, sentence: Sentence, values: &[&str]) -> String {
+    fill(model.locale.text(sentence), values)
+}
+
+/// Whether the current format is exactly `preset`, options included.
+fn is_preset(model: &Model, preset: FormatPreset) -> bool {
+    let format = preset.zero();
+    model.encoding.same_layout(&format) && model.encoding.options == format.options
+}
+
+fn view_timeline(model: &Model) -> Node<Msg> {
+    div![
+        C!["timeline"],
+        button![
+            C!["timeline_toggle"],
+            model.locale.text(if model.timeline.is_some() {
+                Button::StopTimeTravel
+            } else {
+                Button::TimeTravel
+            }),
+            ev(Ev::Click, |_| Msg::ToggleTimeline)
+        ],
+        model.timeline.as_ref().map(|timeline| {
+            let start = timeline.entries[0].time;
+            div![
+                input![
+                    C!["timeline_slider"],
+                    attrs! {
+                        At::Type => "range",
+                        At::Min => "0",
+                        At::Max => (timeline.entries.len() - 1).to_string(),
+                        At::Value => timeline.cursor.to_string()
+                    },
+                    input_ev(Ev::Input, |i| Msg::Scrub(
+                        i.parse().expect("Slider must report number")
+                    )),
+                ],
+                timeline.entries.iter().enumerate().map(|(i, entry)| div![
+                    C!["timeline_entry", IF!(i == timeline.cursor => "current")],
+                    format!(
+                        "+{:.2}s {}",
+                        (entry.time - start) / 1000.0,
+                        entry.step.label
+                    ),
+                    ev(Ev::Click, move |_| Msg::Scrub(i))
+                ]),
+            ]
+        }),
+    ]
+}
+
+/// Recording edits, and replaying a recording a step at a time or on a timer. The script of
+/// the recording can be copied, edited and pasted back in.
+fn view_recording(model: &Model) -> Node<Msg> {
+    let locale = model.locale;
+    div![
+        C!["recording"],
+        match &model.recording {
+            Some(recording) => nodes![
+                button![
+                    locale.text(Button::StopRecording),
+                    ev(Ev::Click, |_| Msg::StopRecording)
+                ],
+                span![
+                    C!["recording_status"],
+                    if recording.steps.len() == 1 {
+                        sentence(model, Sentence::RecordingOneStep, &[])
+                    } else {
+                        sentence(
+                            model,
+                            Sentence::RecordingSteps,
+                            &[&recording.steps.len().to_string()],
+                        )
+                    },
+                ],
+            ],
+            None => nodes![button![
+                attrs! {At::Title => locale.text(Label::RecordTitle)},
+                locale.text(Button::Record),
+                ev(Ev::Click, |_| Msg::StartRecording)
+            ]],
+        },
+        model.replay.as_ref().map(|replay| {
+            let steps = &replay.recording.steps;
+            div![
+                C!["replay"],
+                div![
+                    C!["replay_controls"],
+                    button![
+                        locale.text(Button::Rewind),
+                        ev(Ev::Click, |_| Msg::RewindReplay)
+                    ],
+                    button![
+                        attrs! {At::Disabled => replay.is_finished().as_at_value()},
+                        locale.text(Button::Step),
+                        ev(Ev::Click, |_| Msg::ReplayStep)
+                    ],
+                    if model.replay_timer.is_some() {
+                        button![
+                            locale.text(Button::Pause),
+                            ev(Ev::Click, |_| Msg::PauseReplay)
+                        ]
+                    } else {
+                        button![
+                            attrs! {At::Disabled => replay.is_finished().as_at_value()},
+                            locale.text(Button::Play),
+                            ev(Ev::Click, |_| Msg::PlayReplay)
+                        ]
+                    },
+                    span![sentence(
+                        model,
+                        Sentence::StepOf,
+                        &[&replay.position.to_string(), &steps.len().to_string()]
+                    )],
+                    button![
+                        locale.text(Button::CopyScript),
+                        ev(Ev::Click, |_| Msg::CopyRecording)
+                    ],
+                    button![
+                        locale.text(Button::Close),
+                        ev(Ev::Click, |_| Msg::CloseReplay)
+                    ],
+                ],
+                ol![steps.iter().enumerate().map(|(i, step)| li![
+                    C![
+                        IF!(i < replay.position => "replayed"),
+                        IF!(i == replay.position => "next")
+                    ],
+                    step.as_str()
+                ])],
+            ]
+        }),
+        label![
+            format!("{}: ", locale.text(Label::LoadRecording)),
+            textarea![
+                attrs! {
+                    At::Rows => 4,
+                    At::Placeholder => "encoding=11-52-0000000000000000\nToggleBit 1"
+                },
+                input_ev(Ev::Change, Msg::LoadRecording),
+            ],
+        ],
+        model
+            .recording_error
+            .as_ref()
+            .map(|e| div![C!["error"], e.as_str()]),
+    ]
+}
+
+/// The whole state as JSON, which can be copied or downloaded, or edited and applied.
+fn view_state(model: &Model) -> Node<Msg> {
+    let locale = model.locale;
+    div![
+        C!["state"],
+        button![
+            locale.text(if model.show_state {
+                Button::HideState
+            } else {
+                Button::ExportState
+            }),
+            ev(Ev::Click, |_| Msg::ToggleStateExport)
+        ],
+        IF!(model.show_state => {
+            let json = model.state().to_string();
+            div![
+                div![
+                    C!["state_controls"],
+                    button![locale.text(Button::Copy), ev(Ev::Click, |_| Msg::CopyState)],
+                    a![
+                        attrs! {
+                            At::Href => format!(
+                                "data:application/json;charset=utf-8,{}",
+                                String::from(js_sys::encode_uri_component(&json))
+                            ),
+                            At::Download => "ieee754.json"
+                        },
+                        locale.text(Button::Download)
+                    ],
+                    span![C!["hint"], locale.text(Label::StateHint)],
+                ],
+                textarea![
+                    attrs! {At::Rows => 16, At::Value => json},
+                    input_ev(Ev::Change, Msg::ImportState),
+                ],
+            ]
+        }),
+        model
+            .state_error
+            .as_ref()
+            .map(|e| div![C!["error"], e.as_str()]),
+    ]
+}
+
+/// Explains signed zeros whenever the current encoding is a zero.
+fn view_signed_zero(model: &Model) -> Node<Msg> {
+    if !model.encoding.is_zero() {
+        return empty![];
+    }
+    let zero = model.encoding.value();
+    let other = -zero;
+    // Demonstrating IEEE equality of the zeros is the point here.
+    #[allow(clippy::float_cmp)]
+    let equal = zero == other;
+    let name = |z: f64| {
+        if z.is_sign_negative() {
+            "\u{2212}0"
+        } else {
+            "+0"
+        }
+    };
+    let sign_bit = model.encoding.sign_bit;
+    div![
+        C!["signed_zero"],
+        h3![fill(heading(model, Heading::SignedZero), &[name(zero)])],
+        p![sentence(
+            model,
+            Sentence::ZerosDiffer,
+            &[&u8::from(model.encoding.sign_bit).to_string()]
+        )],
+        p![sentence(
+            model,
+            Sentence::ZerosCompareEqual,
+            &[name(zero), name(other), &equal.to_string()]
+        )],
+        p![sentence(
+            model,
+            Sentence::Reciprocals,
+            &[
+                name(zero),
+                &format_value(1.0 / zero),
+                name(other),
+                &format_value(1.0 / other),
+                name(zero),
+                &format_value(1_f64.copysign(zero)),
+            ]
+        )],
+        button![
+            model.locale.text(Button::FlipSignBit),
+            ev(Ev::Click, move |_| Msg::SetBit(BitType::Sign, 0, !sign_bit))
+        ],
+    ]
+}
+
+fn view_division_by_zero(model: &Model) -> Node<Msg> {
+    let x = &model.encoding;
+    let zero = x.zero(false);
+    let cases = vec![
+        ("x / +0".to_owned(), divide_by_zero(x, false)),
+        ("x / \u{2212}0".to_owned(), divide_by_zero(x, true)),
+        ("0 / 0".to_owned(), divide_by_zero(&zero, false)),
+    ];
+    div![
+        C!["division_by_zero"],
+        h3![fill(
+            heading(model, Heading::DivisionByZero),
+            &[&format_encoding(x)]
+        )],
+        table![cases.into_iter().map(|(name, case)| {
+            let (result, flags) = match case {
+                Some(case) => case,
+                None => {
+                    return tr![
+                        td![name],
+                        td![
+                            attrs! {At::ColSpan => 4},
+                            model.locale.text(Label::NoResultNoNans)
+                        ],
+                    ]
+                }
+            };
+            let raised = flags.raised();
+            tr![
+                td![name],
+                td![format_encoding(&result)],
+                td![result.hex()],
+                td![if raised.is_empty() {
+                    model.locale.text(Label::NoFlags).to_owned()
+                } else {
+                    raised.join(", ")
+                }],
+                td![button![
+                    model.locale.text(Button::Load),
+                    ev(Ev::Click, move |_| Msg::Load(result))
+                ]],
+            ]
+        })],
+    ]
+}
+
+/// Operations with no meaningful result, and why, for the invalid operation gallery.
+const INVALID_OPERATIONS: [(&str, Sentence); 4] = [
+    (
+        "\u{221e} \u{2212} \u{221e}",
+        Sentence::InfinityMinusInfinity,
+    ),
+    ("0 \u{d7} \u{221e}", Sentence::ZeroTimesInfinity),
+    ("\u{221e} / \u{221e}", Sentence::InfinityOverInfinity),
+    ("\u{221a}(\u{2212}1)", Sentence::SqrtOfNegative),
+];
+
+/// Invalid operations always produce the default NaN and raise the invalid flag.
+fn view_invalid_operations(model: &Model) -> Node<Msg> {
+    let nan = model.encoding.default_nan();
+    let flags = Flags {
+        invalid: true,
+        ..Flags::default()
+    };
+    div![
+        C!["invalid_operations"],
+        h3![heading(model, Heading::InvalidOperations)],
+        p![match &nan {
+            Some(nan) => sentence(
+                model,
+                Sentence::DefaultNan,
+                &[&nan.hex(), &nan.field_string(), &flags.raised().join(", ")]
+            ),
+            None => sentence(
+                model,
+                Sentence::NoNansStillRaise,
+                &[&flags.raised().join(", ")]
+            ),
+        }],
+        INVALID_OPERATIONS.iter().map(|&(operation, reason)| {
+            let nan = nan.clone();
+            div![
+                C!["invalid_operation"],
+                h4![operation],
+                p![model.locale.text(reason)],
+                nan.map(|nan| button![
+                    model.locale.text(Button::LoadResult),
+                    ev(Ev::Click, move |_| Msg::Load(nan))
+                ]),
+            ]
+        }),
+    ]
+}
+
+/// The parameters of the current format as the standard and textbooks tabulate them.
+fn view_characteristics(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let p = encoding.precision();
+    let finite = encoding.finite_count();
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let share = 100.0 * finite.to_f64() / 2_f64.powi(encoding.len() as i32);
+    let row = |name: &str, value: String| tr![td![name], td![value]];
+    let locale = model.locale;
+    div![
+        C!["characteristics"],
+        h3![heading(model, Heading::Characteristics)],
+        table![
+            row("emin", encoding.emin().to_string()),
+            row("emax", encoding.emax().to_string()),
+            row(
+                locale.text(Label::PrecisionP),
+                sentence(
+                    model,
+                    Sentence::PrecisionBits,
+                    &[
+                        &p.to_string(),
+                        &format!("{:.1}", f64::from(p) * 2_f64.log10()),
+                    ]
+                ),
+            ),
+            row(
+                locale.text(Label::MachineEpsilon),
+                format!("2^{} \u{2248} {}", 1 - p, format_scaled(1.0, 1 - p)),
+            ),
+            row(
+                locale.text(Label::LargestFinite),
+                format_encoding(&encoding.max_finite(false)),
+            ),
+            row(
+                locale.text(Label::SmallestNormal),
+                encoding.min_normal().map_or_else(
+                    || locale.text(Label::None).to_owned(),
+                    |min| format_encoding(&min)
+                ),
+            ),
+            row(
+                locale.text(Label::SmallestSubnormal),
+                format_encoding(&encoding.min_subnormal()),
+            ),
+            row(
+                locale.text(Label::FiniteEncodings),
+                sentence(
+                    model,
+                    Sentence::FiniteOf,
+                    &[
+                        &finite.to_string(),
+                        &encoding.len().to_string(),
+                        &format!("{:.3}", share),
+                    ]
+                ),
+            ),
+        ],
+    ]
+}
+
+/// Decimal magnitudes at which conversion into the current format overflows or underflows,
+/// assuming rounding to nearest.
+fn view_thresholds(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let p = encoding.precision();
+    let emin = encoding.emin();
+    let row = |description: &str, threshold: String, load: Option<Encoding>| -> Node<Msg> {
+        tr![
+            td![description],
+            td![threshold],
+            td![load.map(|e| button![
+                model.locale.text(Button::Load),
+                ev(Ev::Click, move |_| Msg::Load(e))
+            ])],
+        ]
+    };
+    let locale = model.locale;
+    let max = encoding.max_finite(false);
+    let overflowed = encoding.overflowed(false);
+    div![
+        C!["thresholds"],
+        h3![heading(model, Heading::Thresholds)],
+        table![
+            row(
+                locale.text(if overflowed.is_infinite() {
+                    Label::OverflowsToInfinity
+                } else if overflowed.is_nan() {
+                    Label::OverflowsToNan
+                } else {
+                    Label::Saturates
+                }),
+                if encoding.min_normal().is_some() {
+                    // Half an ulp above the largest finite value, whose significand may stop
+                    // short of all ones if the top binade holds a reserved encoding.
+                    let (significand, scale) = max.scaled_significand();
+                    format!(
+                        "\u{2248}{}",
+                        format_scaled(significand.to_f64() + 0.5, scale)
+                    )
+                } else {
+                    // Without normal values the largest finite value is subnormal, and half an
+                    // ulp above it is small enough to compute directly.
+                    let ulp = max.value() - max.toggled(max.len() - 1).value();
+                    format_value(max.value() + ulp / 2.0)
+                },
+                Some(overflowed),
+            ),
+            row(
+                locale.text(Label::LargestFinite),
+                format_encoding(&max),
+                Some(max)
+            ),
+            match encoding.min_normal() {
+                Some(min_normal) => row(
+                    locale.text(Label::BecomesSubnormal),
+                    format_encoding(&min_normal),
+                    Some(min_normal),
+                ),
+                None => row(locale.text(Label::NoNormalValues), String::new(), None),
+            },
+            row(
+                locale.text(Label::SmallestSubnormal),
+                format_encoding(&encoding.min_subnormal()),
+                Some(encoding.min_subnormal()),
+            ),
+            row(
+                locale.text(Label::UnderflowsToZero),
+                format!("\u{2248}{}", format_scaled(1.0, emin - p)),
+                Some(encoding.zero(false)),
+            ),
+        ],
+    ]
+}
+
+/// The analogues of the `f64` associated constants for the current format.
+fn view_constants(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let constants = vec![
+        ("MAX", Some(encoding.max_finite(false))),
+        ("MIN", Some(encoding.max_finite(true))),
+        ("MIN_POSITIVE", encoding.min_normal()),
+        (
+            model.locale.text(Label::SmallestSubnormal),
+            Some(encoding.min_subnormal()),
+        ),
+        ("EPSILON", encoding.power_of_two(1 - encoding.precision())),
+        ("INFINITY", encoding.infinity(false)),
+        ("NEG_INFINITY", encoding.infinity(true)),
+        ("NAN", encoding.default_nan()),
+    ];
+    div![
+        C!["constants"],
+        h3![heading(model, Heading::Constants)],
+        table![constants
+            .into_iter()
+            .map(|(name, constant)| match constant {
+                Some(constant) => tr![
+                    td![name],
+                    td![format_encoding(&constant)],
+                    td![constant.hex()],
+                    td![button![
+                        model.locale.text(Button::Load),
+                        ev(Ev::Click, move |_| Msg::Load(constant))
+                    ]],
+                ],
+                None => tr![td![name], td![model.locale.text(Label::NotRepresentable)]],
+            })],
+    ]
+}
+
+/// The spacing of values just above 1.0, i.e. machine epsilon.
+fn view_ulp_of_one(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let one = match encoding.power_of_two(0) {
+        Some(one) if encoding.fits_u64() => one,
+        _ => return empty![],
+    };
+    let next = encoding.with_bits(one.to_bits() + 1);
+    let p = encoding.precision();
+    div![
+        C!["ulp_of_one"],
+        h3![heading(model, Heading::UlpOfOne)],
+        // Worked out exactly, as precisions beyond 53 bits are lost in an `f64` subtraction.
+        p![sentence(
+            model,
+            Sentence::NextAfterOne,
+            &[
+                &format_encoding(&next),
+                &format_scaled(1.0, 1 - p),
+                &(1 - p).to_string(),
+            ]
+        )],
+        p![sentence(
+            model,
+            Sentence::EachBinade,
+            &[
+                &encoding.significand_bits.len().to_string(),
+                &p.to_string(),
+                &(p - 1).to_string(),
+            ]
+        )],
+        p![sentence(
+            model,
+            Sentence::NotSmallest,
+            &[&format_value(encoding.min_subnormal().value())]
+        )],
+        button![
+            model.locale.text(Button::LoadOne),
+            ev(Ev::Click, move |_| Msg::SetPattern(one.to_bits()))
+        ],
+        button![
+            model.locale.text(Button::LoadOnePlusUlp),
+            ev(Ev::Click, move |_| Msg::SetPattern(next.to_bits()))
+        ],
+    ]
+}
+
+/// Compares the precision and range of a custom format against the standard formats.
+fn view_format_comparison(model: &Model) -> Node<Msg> {
+    let (exponent_len, significand_len) = (
+        model.encoding.exponent_bits.len(),
+        model.encoding.significand_bits.len(),
+    );
+    if FormatPreset::ALL
+        .iter()
+        .any(|p| p.layout() == (exponent_len, significand_len))
+    {
+        return empty![];
+    }
+
+    // (name, decimal digits of precision, decades of positive range, largest finite)
+    let stats: Vec<(String, f64, f64, String)> = iter::once((
+        sentence(
+            model,
+            Sentence::Custom,
+            &[&exponent_len.to_string(), &significand_len.to_string()],
+        ),
+        model.encoding.zero(false),
+    ))
+    .chain(
+        FormatPreset::ALL
+            .iter()
+            .map(|p| (p.name().to_owned(), p.zero())),
+    )
+    .map(|(name, format)| {
+        let max = format.max_finite(false);
+        let digits = f64::from(format.precision()) * 2_f64.log10();
+        let decades = max.log10() - format.min_subnormal().log10();
+        (name, digits, decades, format_encoding(&max))
+    })
+    .collect();
+    let max_digits = stats.iter().map(|s| s.1).fold(0.0, f64::max);
+    let max_decades = stats.iter().map(|s| s.2).fold(0.0, f64::max);
+    let bar = |fraction: f64, color: &str| -> Node<Msg> {
+        div![
+            C!["bar"],
+            style! {
+                St::Width => format!("{}%", 100.0 * fraction),
+                St::BackgroundColor => color,
+            },
+        ]
+    };
+    div![
+        C!["format_comparison"],
+        h3![heading(model, Heading::FormatComparison)],
+        table![
+            tr![
+                th![model.locale.text(Label::Format)],
+                th![model.locale.text(Label::PrecisionDigits)],
+                th![model.locale.text(Label::RangeDecades)],
+                th![model.locale.text(Label::LargestFinite)],
+            ],
+            stats.iter().map(|(name, digits, decades, max)| tr![
+                td![name.as_str()],
+                td![
+                    format!("{:.1}", digits),
+                    bar(digits / max_digits, model.colors.get(BitType::Significand))
+                ],
+                td![
+                    format!("{:.1}", decades),
+                    bar(decades / max_decades, model.colors.get(BitType::Exponent))
+                ],
+                td![max.as_str()],
+            ]),
+        ],
+    ]
+}
+
+/// Widest format whose every value is drawn on the density plot.
+const DENSITY_MAX_BITS: usize = 12;
+
+/// Every non-negative finite value of a small format on a linear axis, showing how they crowd
+/// together near zero and spread out towards the largest. Negative values mirror them.
+fn view_density(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let max = encoding.max_finite(false);
+    let max_value = max.value();
+    if encoding.len() > DENSITY_MAX_BITS || !(max_value.is_finite() && max_value > 0.0) {
+        return empty![];
+    }
+    let position = |value: f64| format!("{}%", 100.0 * value / max_value);
+    let magnitude = encoding.value().abs();
+    let values: Vec<Encoding> = encoding.nonnegative_finite().collect();
+    let top_gap = max.next_down().map(|below| max_value - below.value());
+    let color = model.colors.get(BitType::Significand);
+    div![
+        C!["density"],
+        h3![heading(model, Heading::Density)],
+        div![
+            C!["density_track"],
+            values.into_iter().map(|value| {
+                let subnormal = value.field(BitType::Exponent) == 0 && !value.is_zero();
+                div![
+                    C!["density_tick", IF!(subnormal => "subnormal")],
+                    style! {
+                        St::Left => position(value.value()),
+                        St::BackgroundColor => color,
+                    },
+                    attrs! {At::Title => format_encoding(&value)},
+                    ev(Ev::Click, move |_| Msg::Load(value)),
+                ]
+            }),
+            IF!(magnitude <= max_value => div![
+                C!["density_marker"],
+                style! {St::Left => position(magnitude)}
+            ]),
+        ],
+        div![C!["density_axis"], span!["0"], span![format_encoding(&max)],],
+        top_gap.map(|gap| {
+            p![sentence(
+                model,
+                Sentence::DensityGaps,
+                &[
+                    &format_encoding(&encoding.min_subnormal()),
+                    &format_value(gap),
+                ]
+            )]
+        }),
+    ]
+}
+
+/// Log-scale plot of the positive subnormal and normal ranges of each format, with the
+/// current value marked.
+fn view_range_plot(model: &Model) -> Node<Msg> {
+    let (exponent_len, significand_len) = (
+        model.encoding.exponent_bits.len(),
+        model.encoding.significand_bits.len(),
+    );
+    // (name, log10 of smallest subnormal, smallest normal and largest finite)
+    let ranges: Vec<(String, f64, Option<f64>, f64)> = iter::once((
+        sentence(
+            model,
+            Sentence::Current,
+            &[&exponent_len.to_string(), &significand_len.to_string()],
+        ),
+        model.encoding.zero(false),
+    ))
+    .chain(
+        FormatPreset::ALL
+            .iter()
+            .map(|p| (p.name().to_owned(), p.zero())),
+    )
+    .map(|(name, format)| {
+        (
+            name,
+            format.min_subnormal().log10(),
+            format.min_normal().map(|n| n.log10()),
+            format.max_finite(false).log10(),
+        )
+    })
+    .collect();
+    let lo = ranges
+        .iter()
+        .map(|r| r.1)
+        .fold(f64::INFINITY, f64::min)
+        .floor();
+    let hi = ranges.iter().map(|r| r.3).fold(0.0, f64::max).ceil();
+    let position = |log: f64| format!("{}%", 100.0 * (log - lo) / (hi - lo));
+    let segment = |from: f64, to: f64, class: &str, color: &str| -> Node<Msg> {
+        div![
+            C!["range_segment", class],
+            style! {
+                St::Left => position(from),
+                St::Width => format!("{}%", 100.0 * (to - from) / (hi - lo)),
+                St::BackgroundColor => color,
+            },
+        ]
+    };
+    let value = model.encoding.value().abs();
+    let marker = if value.is_finite() && value > 0.0 {
+        Some(value.log10())
+    } else {
+        None
+    };
+    let color = model.colors.get(BitType::Exponent);
+
+    div![
+        C!["range_plot"],
+        h3![heading(model, Heading::RangePlot)],
+        ranges.iter().map(|(name, min_subnormal, min_normal, max)| {
+            let normal_from = min_normal.unwrap_or(*max);
+            div![
+                C!["range_row"],
+                span![C!["range_label"], name.as_str()],
+                div![
+                    C!["range_track"],
+                    segment(*min_subnormal, normal_from, "subnormal", color),
+                    IF!(min_normal.is_some() => segment(normal_from, *max, "normal", color)),
+                    marker.map(|m| div![C!["range_marker"], style! {St::Left => position(m)}]),
+                ],
+            ]
+        }),
+        div![
+            C!["range_row"],
+            span![C!["range_label"]],
+            div![
+                C!["range_track", "range_axis"],
+                (0_i32..)
+                    .map(|i| lo + 50.0 * f64::from(i))
+                    .take_while(|&tick| tick <= hi)
+                    .map(|tick| span![
+                        C!["range_tick"],
+                        style! {St::Left => position(tick)},
+                        format!("1e{}", tick)
+                    ]),
+            ],
+        ],
+    ]
+}
+
+/// Plots range against precision for every split of the current total width.
+fn view_tradeoff(model: &Model) -> Node<Msg> {
+    let exponent_len = model.encoding.exponent_bits.len();
+    let budget = model.encoding.len() - 1;
+    // (exponent bits, significand bits, largest exponent, decimal digits of precision)
+    let splits: Vec<(usize, usize, i32, f64)> = (1..=MAX_EXPONENT_BITS)
+        .filter(|&e| e < budget && budget - e <= MAX_SIGNIFICAND_BITS)
+        .map(|e| {
+            let format = Encoding::from_bits(e, budget - e, 0);
+            (
+                e,
+                budget - e,
+                format.emax().max(0),
+                f64::from(format.precision()) * 2_f64.log10(),
+            )
+        })
+        .collect();
+    let max_emax = splits.iter().map(|s| s.2).max().unwrap_or(0).max(1);
+    let max_digits = splits.iter().map(|s| s.3).fold(1.0, f64::max);
+    let (min_e, max_e) = (
+        splits.first().map_or(1, |s| s.0),
+        splits.last().map_or(1, |s| s.0),
+    );
+
+    div![
+        C!["tradeoff"],
+        h3![fill(
+            heading(model, Heading::Tradeoff),
+            &[&model.encoding.len().to_string()]
+        )],
+        label![
+            format!(
+                "{} ({}): ",
+                model.locale.text(Text::ExponentBits),
+                exponent_len
+            ),
+            input![
+                attrs! {
+                    At::Type => "range",
+                    At::Min => min_e.to_string(),
+                    At::Max => max_e.to_string(),
+                    At::Value => exponent_len.to_string()
+                },
+                input_ev(Ev::Input, move |i| {
+                    let e: usize = i.parse().expect("Slider must report number");
+                    Msg::SetLayout(e, budget - e)
+                }),
+            ],
+        ],
+        div![
+            C!["tradeoff_plot"],
+            splits.iter().map(|&(e, s, emax, digits)| {
+                div![
+                    C!["tradeoff_point", IF!(e == exponent_len => "current")],
+                    style! {
+                        St::Left => format!("{}%", 100.0 * f64::from(emax) / f64::from(max_emax)),
+                        St::Bottom => format!("{}%", 100.0 * digits / max_digits),
+                        St::BackgroundColor => model.colors.get(BitType::Exponent),
+                    },
+                    attrs! {
+                        At::Title => sentence(
+                            model,
+                            Sentence::TradeoffPoint,
+                            &[
+                                &e.to_string(),
+                                &s.to_string(),
+                                &emax.to_string(),
+                                &format!("{:.1}", digits),
+                            ]
+                        )
+                    },
+                    ev(Ev::Click, move |_| Msg::SetLayout(e, s)),
+                ]
+            }),
+            span![
+                C!["tradeoff_x_label"],
+                model.locale.text(Label::LargestExponentAxis)
+            ],
+            span![
+                C!["tradeoff_y_label"],
+                model.locale.text(Label::DecimalDigitsAxis)
+            ],
+        ],
+    ]
+}
+
+/// Enumerates every split of a total width and recommends those covering a range of values.
+fn view_allocator(model: &Model) -> Node<Msg> {
+    let allocator = &model.allocator;
+    let text_input = |value: &str, to_msg: fn(String) -> Msg| -> Node<Msg> {
+        input![attrs! {At::Value => value}, input_ev(Ev::Input, to_msg)]
+    };
+    let width = allocator.width.trim().parse::<usize>().ok();
+    let range = allocator
+        .min
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .zip(allocator.max.trim().parse::<f64>().ok())
+        .map(|(min, max)| (min.abs(), max.abs()));
+
+    // (exponent bits, significand bits, format) for every supported split.
+    let splits: Vec<(usize, usize, Encoding)> = width
+        .filter(|&w| w >= 3)
+        .map(|w| {
+            (1..=MAX_EXPONENT_BITS)
+                .filter(|&e| e + 1 < w && w - 1 - e <= MAX_SIGNIFICAND_BITS)
+                .map(|e| (e, w - 1 - e, Encoding::from_bits(e, w - 1 - e, 0)))
+                .collect()
+        })
+        .unwrap_or_default();
+    // Fully covering formats with the most precision come first, so the first is recommended.
+    let recommended = range.and_then(|(min, max)| {
+        splits
+            .iter()
+            .filter(|(_, _, f)| {
+                f.max_finite(false).value() >= max
+                    && f.min_normal().map_or(false, |n| n.value() <= min)
+            })
+            .map(|&(e, s, _)| (e, s))
+            .max_by_key(|&(_, s)| s)
+    });
+
+    let locale = model.locale;
+    div![
+        C!["allocator"],
+        h3![heading(model, Heading::Allocator)],
+        div![
+            label![
+                format!("{}: ", locale.text(Label::TotalBits)),
+                text_input(&allocator.width, Msg::SetAllocatorWidth)
+            ],
+            label![
+                format!("{}: ", locale.text(Label::SmallestMagnitude)),
+                text_input(&allocator.min, Msg::SetAllocatorMin)
+            ],
+            label![
+                format!("{}: ", locale.text(Label::LargestMagnitude)),
+                text_input(&allocator.max, Msg::SetAllocatorMax)
+            ],
+        ],
+        if splits.is_empty() {
+            p![sentence(
+                model,
+                Sentence::EnterWidth,
+                &[&(1 + MAX_EXPONENT_BITS + MAX_SIGNIFICAND_BITS).to_string()]
+            )]
+        } else {
+            table![
+                tr![
+                    th![locale.text(Label::BitSplit)],
+                    th![locale.text(Label::DecimalDigits)],
+                    th![locale.text(Label::SmallestSubnormal)],
+                    th![locale.text(Label::SmallestNormal)],
+                    th![locale.text(Label::LargestFinite)],
+                    th![locale.text(Label::CoversRange)],
+                    th![],
+                ],
+                splits.iter().map(|(e, s, format)| {
+                    let (e, s) = (*e, *s);
+                    let max = format.max_finite(false).value();
+                    let min_normal = format.min_normal().map(|n| n.value());
+                    let min_subnormal = format.min_subnormal().value();
+                    let coverage = range.map_or("", |(min, wanted_max)| {
+                        locale.text(if max < wanted_max {
+                            Label::CoversNoOverflows
+                        } else if min_normal.map_or(false, |n| n <= min) {
+                            Label::CoversYes
+                        } else if min_subnormal <= min {
+                            Label::CoversWithSubnormals
+                        } else {
+                            Label::CoversNoUnderflows
+                        })
+                    });
+                    tr![
+                        C![IF!(recommended == Some((e, s)) => "recommended")],
+                        td![format!("{}/{}", e, s)],
+                        td![format!(
+                            "{:.1}",
+                            f64::from(format.precision()) * 2_f64.log10()
+                        )],
+                        td![format_value(min_subnormal)],
+                        td![min_normal
+                            .map_or_else(|| locale.text(Label::None).to_owned(), format_value)],
+                        td![format_value(max)],
+                        td![coverage],
+                        td![button![
+                            locale.text(Button::Use),
+                            ev(Ev::Click, move |_| Msg::SetLayout(e, s))
+                        ]],
+                    ]
+                }),
+            ]
+        },
+    ]
+}
+
+/// The same bits read as a fixed-point number, next to the floating point value.
+fn view_fixed_point(model: &Model) -> Node<Msg> {
+    if !model.encoding.fits_u64() {
+        return empty![];
+    }
+    let width = model.encoding.len();
+    div![
+        C!["fixed_point"],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.fixed_point.is_some().as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::ToggleFixedPoint),
+            ],
+            model.locale.text(Label::CompareFixedPoint),
+        ],
+        model.fixed_point.map(|fixed_point| {
+            // The sign bit can't also be a fraction bit.
+            let max_fraction_bits = width - usize::from(fixed_point.signed);
+            let fixed_point = FixedPoint {
+                fraction_bits: fixed_point.fraction_bits.min(max_fraction_bits),
+                ..fixed_point
+            };
+            div![
+                label![
+                    format!(
+                        "{} ({}): ",
+                        model.locale.text(Label::FractionBits),
+                        fixed_point.fraction_bits
+                    ),
+                    input![
+                        attrs! {
+                            At::Type => "range",
+                            At::Min => "0",
+                            At::Max => max_fraction_bits.to_string(),
+                            At::Value => fixed_point.fraction_bits.to_string()
+                        },
+                        input_ev(Ev::Input, |i| Msg::SetFractionBits(
+                            i.parse().expect("Slider must report number")
+                        )),
+                    ],
+                ],
+                label![
+                    input![
+                        attrs! {
+                            At::Type => "checkbox",
+                            At::Checked => fixed_point.signed.as_at_value()
+                        },
+                        ev(Ev::Change, |_| Msg::ToggleFixedPointSigned),
+                    ],
+                    model.locale.text(Label::SignedTwosComplement),
+                ],
+                view_field_bits(
+                    model,
+                    model
+                        .encoding
+                        .bits()
+                        .enumerate()
+                        .map(|(i, bit)| {
+                            let field = if fixed_point.signed && i == 0 {
+                                "Sign"
+                            } else if i < width - fixed_point.fraction_bits {
+                                "Integer"
+                            } else {
+                                "Fraction"
+                            };
+                            (bit, field)
+                        })
+                        .collect()
+                ),
+                table![
+                    tr![
+                        td![sentence(
+                            model,
+                            Sentence::FloatingPoint,
+                            &[
+                                &model.encoding.exponent_bits.len().to_string(),
+                                &model.encoding.significand_bits.len().to_string(),
+                            ]
+                        )],
+                        td![format_value(model.encoding.value())],
+                    ],
+                    tr![
+                        td![sentence(
+                            model,
+                            Sentence::FixedPoint,
+                            &[&fixed_point.name(width)]
+                        )],
+                        td![format_value(fixed_point.value(&model.encoding))],
+                    ],
+                ],
+            ]
+        }),
+    ]
+}
+
+/// Bits labelled with the fields of some other reading of them than the float's.
+fn view_field_bits(model: &Model, bits: Vec<(bool, &str)>) -> Node<Msg> {
+    div![
+        C!["field_bits", "binary"],
+        bits.into_iter().map(|(bit, field)| span![
+            C![format!("field_{}", field.to_lowercase().replace(' ', "_"))],
+            attrs! {At::Title => field_name(model, field)},
+            if bit { "1" } else { "0" },
+        ]),
+    ]
+}
+
+/// The name of a field of another reading of the bits in the chosen language.
+fn field_name<'a>(model: &Model, field: &'a str) -> &'a str {
+    let label = match field {
+        "Sign" => Label::Sign,
+        "Exponent" => Label::Exponent,
+        "Fraction" => Label::Fraction,
+        "Integer" => Label::Integer,
+        "Regime" => Label::Regime,
+        "Combination" => Label::Combination,
+        "Exponent continuation" => Label::ExponentContinuation,
+        "Trailing significand" => Label::TrailingSignificand,
+        "Coefficient" => Label::Coefficient,
+        _ => return field,
+    };
+    model.locale.text(label)
+}
+
+/// The same bits read as a posit, whose fields are marked out since they move with the value.
+fn view_posit(model: &Model) -> Node<Msg> {
+    let width = model.encoding.len();
+    div![
+        C!["posit"],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.posit.is_some().as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::TogglePosit),
+            ],
+            model.locale.text(Label::ComparePosit),
+        ],
+        model.posit.map(|posit| {
+            let fields = posit.fields(&model.encoding);
+            div![
+                label![
+                    format!("{} ({}): ", model.locale.text(Label::PositEs), posit.es),
+                    input![
+                        attrs! {
+                            At::Type => "range",
+                            At::Min => "0",
+                            At::Max => MAX_POSIT_ES.to_string(),
+                            At::Value => posit.es.to_string()
+                        },
+                        input_ev(Ev::Input, |i| Msg::SetPositEs(
+                            i.parse().expect("Slider must report number")
+                        )),
+                    ],
+                ],
+                view_field_bits(
+                    model,
+                    fields
+                        .iter()
+                        .map(|&(bit, field)| (bit, field.name()))
+                        .collect()
+                ),
+                IF!(model.encoding.sign_bit => p![
+                    C!["hint"],
+                    model.locale.text(Sentence::PositNegation)
+                ]),
+                table![
+                    tr![
+                        td![sentence(
+                            model,
+                            Sentence::FloatingPoint,
+                            &[
+                                &model.encoding.exponent_bits.len().to_string(),
+                                &model.encoding.significand_bits.len().to_string(),
+                            ]
+                        )],
+                        td![format_encoding(&model.encoding)],
+                    ],
+                    tr![
+                        td![format!("posit<{}, {}>", width, posit.es)],
+                        td![{
+                            let value = posit.value(&model.encoding);
+                            if value.is_nan() {
+                                "NaR".to_owned()
+                            } else {
+                                format_value(value)
+                            }
+                        }],
+                    ],
+                ],
+            ]
+        }),
+    ]
+}
+
+/// The same bits read as the IEEE 754 decimal format of the same width, if there is one.
+fn view_decimal(model: &Model) -> Node<Msg> {
+    let format = match DecimalFormat::with_width(model.encoding.len()) {
+        Some(format) => format,
+        None => return empty![],
+    };
+    let bits: Vec<bool> = model.encoding.bits().collect();
+    div![
+        C!["decimal"],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.decimal.is_some().as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::ToggleDecimal),
+            ],
+            sentence(
+                model,
+                Sentence::CompareDecimal,
+                &[&model.encoding.len().to_string()]
+            ),
+        ],
+        model.decimal.map(|encoding| {
+            let decoded = format.decode(&bits, encoding);
+            div![
+                div![DecimalEncoding::ALL.iter().map(|&e| label![
+                    input![
+                        attrs! {
+                            At::Type => "radio",
+                            At::Name => "decimal_encoding",
+                            At::Checked => (e == encoding).as_at_value()
+                        },
+                        ev(Ev::Change, move |_| Msg::SetDecimalEncoding(e)),
+                    ],
+                    model.locale.text(Text::DecimalEncoding(e)),
+                ])],
+                if encoding == DecimalEncoding::Dpd {
+                    // Each declet of the trailing significand with the digits it holds.
+                    let fields = format.fields(&bits, encoding);
+                    let declets = format.declets(&bits);
+                    let (head, trailing) = fields.split_at(fields.len() - 10 * declets.len());
+                    div![
+                        C!["declets"],
+                        view_field_bits(model, head.to_vec()),
+                        trailing
+                            .chunks(10)
+                            .zip(declets)
+                            .map(|(declet, digits)| div![
+                                C!["declet"],
+                                view_field_bits(model, declet.to_vec()),
+                                format!("{:03}", digits),
+                            ]),
+                    ]
+                } else {
+                    view_field_bits(model, format.fields(&bits, encoding))
+                },
+                table![
+                    tr![
+                        td![sentence(
+                            model,
+                            Sentence::FloatingPoint,
+                            &[
+                                &model.encoding.exponent_bits.len().to_string(),
+                                &model.encoding.significand_bits.len().to_string(),
+                            ]
+                        )],
+                        td![format_encoding(&model.encoding)],
+                    ],
+                    tr![
+                        td![sentence(
+                            model,
+                            Sentence::DecimalOf,
+                            &[
+                                &model.encoding.len().to_string(),
+                                &format.precision.to_string(),
+                            ]
+                        )],
+                        td![decoded.to_string()],
+                    ],
+                    if let Decoded::Finite {
+                        coefficient,
+                        exponent,
+                        ..
+                    } = decoded
+                    {
+                        vec![
+                            tr![
+                                td![model.locale.text(Label::Coefficient)],
+                                td![coefficient.to_string()]
+                            ],
+                            tr![
+                                td![model.locale.text(Label::Exponent)],
+                                td![exponent.to_string()]
+                            ],
+                        ]
+                    } else {
+                        Vec::new()
+                    },
+                ],
+            ]
+        }),
+    ]
+}
+
+/// The value encoded as a two's complement integer of the same width, to contrast with the
+/// float's encoding of it.
+fn view_twos_complement(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let width = encoding.len();
+    let float_bits = encoding
+        .bits()
+        .enumerate()
+        .map(|(i, bit)| {
+            let field = if i == 0 {
+                "Sign"
+            } else if i <= encoding.exponent_bits.len() {
+                "Exponent"
+            } else {
+                "Fraction"
+            };
+            (bit, field)
+        })
+        .collect();
+    div![
+        C!["twos_complement"],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.twos_complement.as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::ToggleTwosComplement),
+            ],
+            model.locale.text(Label::CompareTwosComplement),
+        ],
+        IF!(model.twos_complement => table![
+            tr![
+                td![sentence(
+                    model,
+                    Sentence::FloatingPoint,
+                    &[
+                        &encoding.exponent_bits.len().to_string(),
+                        &encoding.significand_bits.len().to_string(),
+                    ]
+                )],
+                td![view_field_bits(model, float_bits)],
+                td![format_encoding(encoding)],
+            ],
+            tr![
+                td![format!("i{}", width)],
+                match twos_complement(encoding) {
+                    Ok(bits) => {
+                        let (magnitude, _) = encoding.reduced_ratio();
+                        let minus = encoding.sign_bit && !magnitude.is_zero();
+                        nodes![
+                            td![view_field_bits(
+                                model,
+                                bits.iter()
+                                    .enumerate()
+                                    .map(|(i, &bit)| (bit, if i == 0 { "Sign" } else { "Integer" }))
+                                    .collect()
+                            )],
+                            td![format!("{}{}", if minus { "\u{2212}" } else { "" }, magnitude)],
+                        ]
+                    }
+                    Err(reason) => nodes![td![
+                        C!["error"],
+                        attrs! {At::ColSpan => 2},
+                        sentence(
+                            model,
+                            Sentence::NotRepresentable,
+                            &[model.locale.text(Text::Unrepresentable(reason))]
+                        ),
+                    ]],
+                },
+            ],
+        ]),
+    ]
+}
+
+/// The same bits read as integers of the encoding's width.
+fn view_integers(model: &Model) -> Node<Msg> {
+    let width = model.encoding.len();
+    // Only encodings which fit in a `u64` can be typed in as integers.
+    let (signed, unsigned) = if model.encoding.fits_u64() {
+        (
+            model.encoding.to_signed_bits().to_string(),
+            td![input![
+                attrs! {At::Value => model.encoding.to_bits().to_string()},
+                input_ev(Ev::Change, Msg::SetFromUnsigned),
+            ]],
+        )
+    } else {
+        let (negative, magnitude) = model.encoding.to_big_signed_bits();
+        (
+            format!("{}{}", if negative { "\u{2212}" } else { "" }, magnitude),
+            td![C!["binary"], model.encoding.to_big_bits().to_string()],
+        )
+    };
+    div![
+        C!["integers"],
+        table![
+            tr![
+                td![sentence(
+                    model,
+                    Sentence::TwosComplementInteger,
+                    &[&width.to_string()]
+                )],
+                td![signed],
+            ],
+            tr![td![format!("u{}", width)], unsigned],
+        ],
+        model
+            .integer_error
+            .as_ref()
+            .map(|e| div![C!["error"], e.as_str()]),
+    ]
+}
+
+/// The raw bits decoded as several other types at once.
+fn view_reinterpretations(model: &Model) -> Node<Msg> {
+    if !model.encoding.fits_u64() {
+        return empty![];
+    }
+    let bits = model.encoding.to_bits();
+    div![
+        C!["reinterpretations"],
+        h3![fill(
+            heading(model, Heading::Reinterpretations),
+            &[&format!("0x{:016X}", bits)]
+        )],
+        div![Reinterpretation::ALL.iter().map(|&r| label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.reinterpretations.contains(&r).as_at_value()
+                },
+                ev(Ev::Change, move |_| Msg::ToggleReinterpretation(r)),
+            ],
+            r.name(),
+        ])],
+        table![Reinterpretation::ALL
+            .iter()
+            .filter(|r| model.reinterpretations.contains(r))
+            .map(|&r| tr![
+                td![r.name()],
+                r.decode(bits).into_iter().map(|lane| td![lane]),
+            ])],
+    ]
+}
+
+/// Animates the raw bits from one saved value to another, to show how evenly spaced bit
+/// patterns are unevenly spaced values.
+fn view_interpolation(model: &Model) -> Node<Msg> {
+    if !model.encoding.fits_u64() {
+        return empty![];
+    }
+    let interpolation = &model.interpolation;
+    let endpoint = |endpoint: &Option<Encoding>| {
+        endpoint.as_ref().map_or_else(
+            || model.locale.text(Label::NotSet).to_owned(),
+            |e| format!("{} ({})", format_value(e.value()), e.hex()),
+        )
+    };
+    div![
+        C!["interpolation"],
+        h3![heading(model, Heading::Interpolation)],
+        p![
+            sentence(
+                model,
+                Sentence::InterpolateFrom,
+                &[&endpoint(&interpolation.start)]
+            ),
+            button![
+                model.locale.text(Button::UseCurrent),
+                ev(Ev::Click, |_| Msg::SaveInterpolationStart)
+            ],
+        ],
+        p![
+            sentence(
+                model,
+                Sentence::InterpolateTo,
+                &[&endpoint(&interpolation.end)]
+            ),
+            button![
+                model.locale.text(Button::UseCurrent),
+                ev(Ev::Click, |_| Msg::SaveInterpolationEnd)
+            ],
+        ],
+        if interpolation.timer.is_some() {
+            button![
+                model.locale.text(Button::Stop),
+                ev(Ev::Click, |_| Msg::StopInterpolation)
+            ]
+        } else {
+            button![
+                attrs! {
+                    At::Disabled => (interpolation.start.is_none() || interpolation.end.is_none())
+                        .as_at_value()
+                },
+                model.locale.text(Button::Play),
+                ev(Ev::Click, |_| Msg::PlayInterpolation)
+            ]
+        },
+        ol![
+            C!["interpolation_frames"],
+            interpolation
+                .frames
+                .iter()
+                .map(|&(bits, value)| li![format!("0x{:X}: {}", bits, format_value(value))]),
+        ],
+    ]
+}
+
+/// Plots interpolating between the saved endpoints linearly in value space against linearly
+/// in (order preserving) bit space.
+fn view_interpolation_comparison(model: &Model) -> Node<Msg> {
+    const SAMPLES: i32 = 32;
+    let (start, end) = match (&model.interpolation.start, &model.interpolation.end) {
+        (Some(start), Some(end))
+            if start.same_layout(end) && start.value().is_finite() && end.value().is_finite() =>
+        {
+            (start, end)
+        }
+        _ => return empty![],
+    };
+    let (a, b) = (start.value(), end.value());
+    let (from, to) = (start.to_ordinal(), end.to_ordinal());
+    // (t, value space, bit space)
+    let samples: Vec<(f64, f64, f64)> = (0..=SAMPLES)
+        .map(|i| {
+            let t = f64::from(i) / f64::from(SAMPLES);
+            let ordinal = i128::from(from)
+                + (i128::from(to) - i128::from(from)) * i128::from(i) / i128::from(SAMPLES);
+            #[allow(clippy::cast_possible_truncation)]
+            let bit_space = start.with_ordinal(ordinal as i64).value();
+            (t, a + (b - a) * t, bit_space)
+        })
+        .collect();
+    let (lo, hi) = (a.min(b), a.max(b));
+    let height = |v: f64| {
+        if hi > lo {
+            format!("{}%", 100.0 * (v - lo) / (hi - lo))
+        } else {
+            "50%".to_owned()
+        }
+    };
+    let point = |t: f64, v: f64, class: &str, color: &str| -> Node<Msg> {
+        div![
+            C!["comparison_point", class],
+            style! {
+                St::Left => format!("{}%", 100.0 * t),
+                St::Bottom => height(v),
+                St::BackgroundColor => color,
+            },
+            attrs! {At::Title => format_value(v)},
+        ]
+    };
+    div![
+        C!["interpolation_comparison"],
+        h3![heading(model, Heading::InterpolationComparison)],
+        div![
+            C!["comparison_plot"],
+            samples.iter().map(|&(t, v, _)| point(
+                t,
+                v,
+                "value_space",
+                model.colors.get(BitType::Exponent)
+            )),
+            samples.iter().map(|&(t, _, v)| point(
+                t,
+                v,
+                "bit_space",
+                model.colors.get(BitType::Significand)
+            )),
+        ],
+        p![span![
+            style! {St::Color => model.colors.get(BitType::Exponent)},
+            model.locale.text(Label::LinearInValue)
+        ]],
+        p![span![
+            style! {St::Color => model.colors.get(BitType::Significand)},
+            model.locale.text(Label::LinearInBits)
+        ]],
+        p![model.locale.text(Sentence::BitsAreLogarithms)],
+    ]
+}
+
+/// Shows the mapping from float bits to integers whose unsigned order matches float order.
+fn view_sortable_bits(model: &Model) -> Node<Msg> {
+    if !model.encoding.fits_u64() {
+        return empty![];
+    }
+    let encoding = &model.encoding;
+    let width = encoding.len();
+    let binary = |bits: u64| format!("{:01$b}", bits, width);
+    let one = encoding.power_of_two(0);
+    let mut examples: Vec<Encoding> = vec![
+        encoding.max_finite(true),
+        encoding.zero(true),
+        encoding.zero(false),
+        encoding.min_subnormal(),
+        encoding.max_finite(false),
+        encoding.clone(),
+    ];
+    examples.extend(encoding.infinity(true));
+    examples.extend(encoding.infinity(false));
+    examples.extend(one.iter().flat_map(|one| vec![one.toggled(0), one.clone()]));
+    examples.sort_by_key(Encoding::to_sortable_bits);
+
+    div![
+        C!["sortable_bits"],
+        h3![heading(model, Heading::SortableBits)],
+        p![model.locale.text(if encoding.sign_bit {
+            Label::NegativeInvert
+        } else {
+            Label::PositiveSetSign
+        })],
+        table![
+            tr![
+                td![model.locale.text(Label::Bits)],
+                td![C!["binary"], binary(encoding.to_bits())]
+            ],
+            tr![
+                td![model.locale.text(Label::Sortable)],
+                td![C!["binary"], binary(encoding.to_sortable_bits())]
+            ],
+        ],
+        p![model.locale.text(Sentence::SortableKeys)],
+        table![
+            tr![
+                th![model.locale.text(Label::Value)],
+                th![model.locale.text(Label::SortableKey)]
+            ],
+            examples.iter().map(|e| tr![
+                td![format_value(e.value())],
+                td![C!["binary"], binary(e.to_sortable_bits())],
+            ]),
+        ],
+    ]
+}
+
+/// The position of the value among all finite values of the format, counting +0 and
+/// \u{2212}0 as #0 and negative values with negative ordinals.
+fn view_ordinal(model: &Model) -> Node<Msg> {
+    if !model.encoding.fits_u64() {
+        return empty![];
+    }
+    let encoding = &model.encoding;
+    div![
+        C!["ordinal"],
+        if encoding.is_nan() || encoding.is_infinite() {
+            span![model.locale.text(Text::NotFinite)]
+        } else {
+            span![model.locale.text(Text::FiniteValue)]
+        },
+        input![
+            attrs! {At::Value => group_digits(encoding.to_ordinal())},
+            input_ev(Ev::Change, Msg::SetFromOrdinal),
+        ],
+        {
+            let max = group_digits(encoding.max_finite(false).to_ordinal());
+            sentence(
+                model,
+                Sentence::OrdinalOf,
+                &[
+                    &group_digits(2 * i128::from(encoding.max_finite(false).to_ordinal()) + 1),
+                    &max,
+                    &max,
+                ],
+            )
+        },
+        model
+            .ordinal_error
+            .as_ref()
+            .map(|e| div![C!["error"], e.as_str()]),
+    ]
+}
+
+/// Code which assembles the value from its fields, to paste into low level code.
+fn view_codegen(model: &Model) -> Node<Msg> {
+    if !model.encoding.fits_u64() {
+        return empty![];
+    }
+    div![
+        C!["codegen"],
+        h3![heading(model, Heading::Codegen)],
+        div![Language::ALL.iter().map(|&language| label![
+            input![
+                attrs! {
+                    At::Type => "radio",
+                    At::Name => "code_language",
+                    At::Checked => (language == model.code_language).as_at_value()
+                },
+                ev(Ev::Change, move |_| Msg::SetCodeLanguage(language)),
+            ],
+            language.name(),
+        ])],
+        pre![codegen::construction(&model.encoding, model.code_language)],
+        button![
+            model.locale.text(Button::CopyCode),
+            ev(Ev::Click, |_| Msg::CopyCode)
+        ],
+    ]
+}
+
+/// Stimulus and expected results for the operations shown above, for FPU testbenches.
+fn view_test_vectors(model: &Model) -> Node<Msg> {
+    div![
+        C!["codegen"],
+        h3![heading(model, Heading::TestVectors)],
+        pre![codegen::test_vectors(&model.encoding)],
+        button![
+            model.locale.text(Button::CopyTestVectors),
+            ev(Ev::Click, |_| Msg::CopyTestVectors)
+        ],
+    ]
+}
+
+/// Most vectors shown from a pasted TestFloat run, to keep rendering quick.
+const TEST_VECTORS_SHOWN: usize = 200;
+
+/// Vectors from `testfloat_gen`, checked against the app's own results, with buttons to load
+/// their operands and results.
+fn view_testfloat(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let operation = model.test_operation;
+    let load = |label: &str, encoding: &Encoding| -> Node<Msg> {
+        let encoding = encoding.clone();
+        button![label, ev(Ev::Click, move |_| Msg::Load(encoding))]
+    };
+    div![
+        C!["testfloat"],
+        h3![heading(model, Heading::TestFloat)],
+        p![sentence(
+            model,
+            Sentence::PasteTestFloat,
+            &[
+                &encoding.len().to_string(),
+                operation.name(),
+                &encoding.len().to_string(),
+            ]
+        )],
+        div![Operation::ALL.iter().map(|&o| label![
+            input![
+                attrs! {
+                    At::Type => "radio",
+                    At::Name => "test_operation",
+                    At::Checked => (o == operation).as_at_value()
+                },
+                ev(Ev::Change, move |_| Msg::SetTestOperation(o)),
+            ],
+            o.name(),
+        ])],
+        textarea![
+            attrs! {At::Rows => 6, At::Value => model.test_vectors.as_str()},
+            input_ev(Ev::Input, Msg::SetTestVectors),
+        ],
+        table![model
+            .test_vectors
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .take(TEST_VECTORS_SHOWN)
+            .map(
+                |(i, line)| match testfloat::parse_line(line, operation, encoding) {
+                    Ok(case) => {
+                        let verdict = match testfloat::check(operation, &case) {
+                            Verdict::Pass => span![model.locale.text(Label::Pass)],
+                            Verdict::Fail(result, flags) => span![
+                                C!["error"],
+                                sentence(
+                                    model,
+                                    Sentence::Mismatch,
+                                    &[&result.hex(), &format!("{:02X}", flags.to_bits())]
+                                )
+                            ],
+                            Verdict::Unchecked => span![model.locale.text(Label::NotChecked)],
+                        };
+                        tr![
+                            td![format!("{}", i + 1)],
+                            td![load(&case.a.hex(), &case.a)],
+                            td![case.b.as_ref().map(|b| load(&b.hex(), b))],
+                            td![load(&case.result.hex(), &case.result)],
+                            td![format!("{:02X}", case.flags.to_bits())],
+                            td![verdict],
+                        ]
+                    }
+                    Err(e) => tr![
+                        td![format!("{}", i + 1)],
+                        td![C!["error"], attrs! {At::ColSpan => 5}, e],
+                    ],
+                }
+            )],
+    ]
+}
+
+/// Most values summed by the summation demo, to keep rendering quick.
+const MAX_SUMMANDS: usize = 1000;
+
+/// Most steps of the summation demo shown.
+const SUMMATION_STEPS_SHOWN: usize = 100;
+
+/// Digits up to which exact sums and errors are shown in full.
+const SUMMATION_DIGITS: usize = 40;
+
+/// Typed values summed in the current format both naively and with Kahan's compensated
+/// summation, showing every running sum and how far each result is from the exact sum.
+fn view_summation(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let mut errors = Vec::new();
+    let mut rounded = Vec::new();
+    let inputs: Vec<Encoding> = model
+        .summands
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|text| !text.is_empty())
+        .take(MAX_SUMMANDS)
+        .filter_map(|text| match parse_decimal(text, encoding, model.rounding) {
+            Ok((value, flags)) => {
+                rounded.push(if flags.inexact { Some(text) } else { None });
+                Some(value)
+            }
+            Err(e) => {
+                errors.push(div![C!["error"], format!("{}: {}", text, e)]);
+                None
+            }
+        })
+        .collect();
+    let bits = |value: &Encoding| {
+        span![
+            format_encoding(value),
+            br![],
+            span![C!["hint"], value.hex()],
+        ]
+    };
+    let error = |sum: &Encoding, exact: &Exact| {
+        Exact::of(sum).map_or_else(
+            || model.locale.text(Label::NotFinite).to_owned(),
+            |sum| sum.sub(exact).to_decimal(SUMMATION_DIGITS),
+        )
+    };
+    let summation = Summation::new(&inputs, model.rounding);
+    div![
+        C!["summation"],
+        h3![heading(model, Heading::Summation)],
+        p![model.locale.text(Sentence::SummationIntro)],
+        textarea![
+            attrs! {At::Rows => 3, At::Value => model.summands.as_str()},
+            input_ev(Ev::Input, Msg::SetSummands),
+        ],
+        errors,
+        summation.map(|summation| {
+            let last = summation.steps.last();
+            table![
+                tr![
+                    th!["#"],
+                    th![model.locale.text(Label::Value)],
+                    th![model.locale.text(Label::NaiveSum)],
+                    th![model.locale.text(Label::KahanSum)],
+                    th![model.locale.text(Label::Compensation)],
+                ],
+                summation
+                    .steps
+                    .iter()
+                    .zip(rounded)
+                    .enumerate()
+                    .take(SUMMATION_STEPS_SHOWN)
+                    .map(|(i, (step, typed))| tr![
+                        td![format!("{}", i + 1)],
+                        td![span![
+                            C![IF!(typed.is_some() => "rounded")],
+                            typed.map(|typed| attrs! {
+                                At::Title => sentence(model, Sentence::RoundedFrom, &[typed])
+                            }),
+                            bits(&step.input)
+                        ]],
+                        td![bits(&step.naive)],
+                        td![bits(&step.kahan)],
+                        td![bits(&step.compensation)],
+                    ]),
+                summation.exact.as_ref().map(|exact| {
+                    let (best, _) = exact.rounded(encoding, model.rounding);
+                    nodes![
+                        tr![
+                            td![
+                                attrs! {At::ColSpan => 2},
+                                model.locale.text(Label::ExactSum)
+                            ],
+                            td![
+                                attrs! {At::ColSpan => 3},
+                                exact.to_decimal(SUMMATION_DIGITS)
+                            ],
+                        ],
+                        tr![
+                            td![
+                                attrs! {At::ColSpan => 2},
+                                model.locale.text(Label::CorrectlyRounded)
+                            ],
+                            td![attrs! {At::ColSpan => 3}, bits(&best)],
+                        ],
+                        last.map(|last| tr![
+                            td![attrs! {At::ColSpan => 2}, model.locale.text(Label::Error)],
+                            td![error(&last.naive, exact)],
+                            td![error(&last.kahan, exact)],
+                            td![],
+                        ]),
+                    ]
+                }),
+            ]
+        }),
+    ]
+}
+
+/// Bits of a hard case's result shown past the end of its run.
+const DILEMMA_TAIL_BITS: usize = 8;
+
+/// Inputs whose exp or ln is hard to round, with the result of the loaded one written out past
+/// the end of its run of alike bits.
+fn view_dilemma(model: &Model) -> Node<Msg> {
+    let binary =
+        |bits: &[bool]| -> String { bits.iter().map(|&b| if b { '1' } else { '0' }).collect() };
+    let current = HARD_CASES
+        .iter()
+        .find(|case| case.input() == model.encoding);
+    div![
+        C!["dilemma"],
+        h3![heading(model, Heading::Dilemma)],
+        p![model.locale.text(Sentence::DilemmaIntro)],
+        table![HARD_CASES.iter().map(|case| {
+            let input = case.input();
+            let run = case.result().run(input.precision() as usize);
+            tr![
+                C![IF!(input == model.encoding => "current")],
+                td![format!(
+                    "{}({})",
+                    case.function.name(),
+                    format_hex_float(&input)
+                )],
+                td![case.format.name()],
+                td![sentence(model, Sentence::BitsAlike, &[&run.to_string()])],
+                td![button![
+                    model.locale.text(Button::Load),
+                    ev(Ev::Click, move |_| Msg::Load(input))
+                ]],
+            ]
+        })],
+        current.map(|case| {
+            let result = case.result();
+            let precision = model.encoding.precision() as usize;
+            let run = result.run(precision);
+            let end = (precision + 1 + run + DILEMMA_TAIL_BITS).min(result.bits.len());
+            let alike = result.bits[precision + 1];
+            let explanation = if result.bits[precision] == alike {
+                Sentence::NearValue
+            } else {
+                Sentence::NearMidpoint
+            };
+            nodes![
+                p![
+                    C!["binary"],
+                    format!(
+                        "{}({}) = {}1.{}",
+                        case.function.name(),
+                        format_hex_float(&model.encoding),
+                        if result.negative { "\u{2212}" } else { "" },
+                        binary(&result.bits[1..precision])
+                    ),
+                    span![
+                        C!["rounding_bit"],
+                        binary(&result.bits[precision..=precision])
+                    ],
+                    span![
+                        C!["run"],
+                        binary(&result.bits[precision + 1..=precision + run])
+                    ],
+                    format!(
+                        "{}\u{2026} \u{d7} 2^{}",
+                        binary(&result.bits[precision + 1 + run..end]),
+                        result.exponent
+                    ),
+                ],
+                p![sentence(
+                    model,
+                    explanation,
+                    &[
+                        &run.to_string(),
+                        if alike { "1" } else { "0" },
+                        &(run + 1).to_string()
+                    ]
+                )],
+            ]
+        }),
+    ]
+}
+
+/// A file of floating point numbers, browsed by loading one element at a time.
+fn view_data_file(model: &Model) -> Node<Msg> {
+    let chooser = input![
+        attrs! {At::Type => "file", At::Accept => ".npy,.bin,.raw,.f16,.f32,.f64"},
+        ev(Ev::Change, |event| {
+            let input: web_sys::HtmlInputElement = event.target()?.unchecked_into();
+            Some(Msg::ChooseDataFile(input.files()?.get(0)?))
+        }),
+    ];
+    let error = model.data_error.as_ref().map(|e| div![C!["error"], e]);
+    let file = match &model.data_file {
+        None => {
+            return div![
+                C!["data_file"],
+                h3![heading(model, Heading::DataFile)],
+                p![model.locale.text(Sentence::OpenDataFile)],
+                chooser,
+                error,
+            ]
+        }
+        Some(file) => file,
+    };
+    let len = file.len();
+    let index = file.index;
+    div![
+        C!["data_file"],
+        h3![fill(
+            heading(model, Heading::BrowsingDataFile),
+            &[&file.name]
+        )],
+        chooser,
+        error,
+        IF!(!file.typed => div![
+            ElementType::ALL.iter().map(|&t| label![
+                input![
+                    attrs! {
+                        At::Type => "radio",
+                        At::Name => "data_element",
+                        At::Checked => (t == file.element).as_at_value()
+                    },
+                    ev(Ev::Change, move |_| Msg::SetDataElement(t)),
+                ],
+                t.name(),
+            ]),
+            label![
+                input![
+                    attrs! {
+                        At::Type => "checkbox",
+                        At::Checked => (!file.little_endian).as_at_value()
+                    },
+                    ev(Ev::Change, |_| Msg::ToggleDataEndianness),
+                ],
+                model.locale.text(Label::BigEndian),
+            ],
+        ]),
+        if file.is_empty() {
+            p![model.locale.text(Sentence::NoWholeElements)]
+        } else {
+            p![sentence(
+                model,
+                Sentence::ElementOf,
+                &[&index.to_string(), &len.to_string(), file.element.name()]
+            )]
+        },
+        div![
+            button![
+                model.locale.text(Button::Previous),
+                attrs! {At::Disabled => (index == 0).as_at_value()},
+                ev(Ev::Click, move |_| Msg::ShowElement(
+                    index.saturating_sub(1)
+                )),
+            ],
+            button![
+                model.locale.text(Button::Next),
+                attrs! {At::Disabled => (index + 1 >= len).as_at_value()},
+                ev(Ev::Click, move |_| Msg::ShowElement(index + 1)),
+            ],
+            button![
+                model.locale.text(Button::NextNan),
+                ev(Ev::Click, |_| Msg::NextNan)
+            ],
+            button![
+                model.locale.text(Button::Close),
+                ev(Ev::Click, |_| Msg::CloseDataFile)
+            ],
+        ],
+    ]
+}
+
+fn view_paste(model: &Model) -> Node<Msg> {
+    match &model.paste {
+        None => empty![],
+        Some(PasteState::Ambiguous(text, formats)) => div![
+            C!["paste"],
+            sentence(model, Sentence::PasteAs, &[text]),
+            formats.iter().map(|&f| button![
+                model.locale.text(Text::PasteFormat(f)),
+                ev(Ev::Click, move |_| Msg::PasteAs(f))
+            ]),
+            button![
+                model.locale.text(Button::Cancel),
+                ev(Ev::Click, |_| Msg::DismissPaste)
+            ],
+        ],
+        Some(PasteState::Error(e)) => div![
+            C!["paste", "error"],
+            e.as_str(),
+            button![
+                model.locale.text(Button::Dismiss),
+                ev(Ev::Click, |_| Msg::DismissPaste)
+            ],
+        ],
+    }
+}
+
+fn view_colors(model: &Model) -> Node<Msg> {
+    let text = |text| model.locale.text(text);
+    div![
+        C!["colors"],
+        BitType::ALL.iter().map(|&t| label![
+            C!["color_picker"],
+            format!("{}: ", text(Text::Field(t))),
+            input![
+                attrs! {
+                    At::Type => "color",
+                    At::Value => model.colors.get(t)
+                },
+                input_ev(Ev::Input, move |c| Msg::SetColor(t, c)),
+            ],
+        ]),
+        button![
+            C!["reset_colors"],
+            text(Text::ResetColours),
+            ev(Ev::Click, |_| Msg::ResetColors)
+        ],
+        div![
+            C!["themes"],
+            Theme::ALL.iter().map(|&theme| label![
+                input![
+                    attrs! {
+                        At::Type => "radio",
+                        At::Name => "theme",
+                        At::Checked => (theme == model.theme).as_at_value()
+                    },
+                    ev(Ev::Change, move |_| Msg::SetTheme(theme)),
+                ],
+                text(Text::Theme(theme)),
+            ]),
+        ],
+        div![
+            C!["locales"],
+            attrs! {At::Title => text(Text::Language)},
+            Locale::ALL.iter().map(|&locale| label![
+                attrs! {At::Lang => locale.tag()},
+                input![
+                    attrs! {
+                        At::Type => "radio",
+                        At::Name => "locale",
+                        At::Checked => (locale == model.locale).as_at_value()
+                    },
+                    ev(Ev::Change, move |_| Msg::SetLocale(locale)),
+                ],
+                locale.name(),
+            ]),
+        ],
+        button![
+            attrs! {At::Title => text(Text::Label(Label::ResetTitle))},
+            text(Text::ResetToDefaults),
+            ev(Ev::Click, |_| Msg::ResetToDefaults)
+        ],
+    ]
+}
+
+fn view_history(model: &Model) -> Node<Msg> {
+    let text = |text| model.locale.text(text);
+    div![
+        C!["history"],
+        div![
+            C!["undo"],
+            button![
+                text(Text::Undo),
+                attrs! {
+                    At::Title => "Ctrl+Z",
+                    At::Disabled => model.undo.is_empty().as_at_value(),
+                },
+                ev(Ev::Click, |_| Msg::Undo),
+            ],
+            button![
+                text(Text::Redo),
+                attrs! {
+                    At::Title => "Ctrl+Shift+Z",
+                    At::Disabled => model.redo.is_empty().as_at_value(),
+                },
+                ev(Ev::Click, |_| Msg::Redo),
+            ],
+        ],
+        model.history.iter().enumerate().rev().map(|(i, step)| {
+            let changes = i
+                .checked_sub(1)
+                .and_then(|prev| step.encoding.changes_from(&model.history[prev].encoding))
+                .unwrap_or_default();
+            div![
+                C!["history_step"],
+                span![C!["history_label"], step.label.as_str()],
+                span![
+                    C!["history_bits"],
+                    step.encoding.bits().enumerate().map(|(j, b)| span![
+                        C![IF!(changes.get(j) == Some(&true) => "changed")],
+                        if b { "1" } else { "0" }
+                    ]),
+                ],
+            ]
+        }),
+    ]
+}
+
+/// Shortcuts for building edge cases without clicking every bit.
+fn view_bit_tools(model: &Model) -> Node<Msg> {
+    let fill = |t: BitType, value: bool| {
+        button![
+            model.locale.text(if value {
+                Button::Fill(t)
+            } else {
+                Button::Clear(t)
+            }),
+            ev(Ev::Click, move |_| Msg::FillField(t, value)),
+        ]
+    };
+    div![
+        C!["bit_tools"],
+        button![
+            model.locale.text(Button::Randomize),
+            ev(Ev::Click, |_| Msg::Randomize(random_seed())),
+        ],
+        fill(BitType::Exponent, true),
+        fill(BitType::Exponent, false),
+        fill(BitType::Significand, true),
+        fill(BitType::Significand, false),
+        button![
+            model.locale.text(Button::ClearAll),
+            ev(Ev::Click, |_| Msg::Clear)
+        ],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.group_nibbles.as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::ToggleNibbleGroups),
+            ],
+            model.locale.text(Label::GroupInFours),
+        ],
+    ]
+}
+
+fn view_operations(model: &Model) -> Node<Msg> {
+    div![
+        C!["operations"],
+        UnaryOperator::ALL.iter().map(|&operator| {
+            button![
+                model.locale.text(Text::UnaryOperator(operator)),
+                ev(Ev::Click, move |_| Msg::ApplyUnary(operator)),
+            ]
+        }),
+    ]
+}
+
+fn view_special_values(model: &Model) -> Node<Msg> {
+    div![
+        C!["special_values"],
+        SpecialValue::ALL.iter().map(|&special| {
+            let missing = special.in_format(&model.encoding).is_none();
+            button![
+                model.locale.text(Text::SpecialValue(special)),
+                attrs! {At::Disabled => missing.as_at_value()},
+                ev(Ev::Click, move |_| Msg::SetSpecial(special)),
+            ]
+        }),
+    ]
+}
+
+fn view_copy(model: &Model) -> Node<Msg> {
+    div![
+        C!["copy_formats"],
+        PasteFormat::COPYABLE.iter().map(|&format| {
+            button![
+                model.locale.text(Button::CopyFormat(format)),
+                ev(Ev::Click, move |_| Msg::Copy(format)),
+            ]
+        }),
+        model.copied.map(|format| span![
+            C!["copied"],
+            sentence(
+                model,
+                Sentence::Copied,
+                &[model.locale.text(Text::PasteFormat(format))]
+            )
+        ]),
+    ]
+}
+
+fn view_bits(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let significand_start = 1 + encoding.exponent_bits.len();
+    div![
+        C!["bits"],
+        keyboard_ev(Ev::KeyDown, |event| {
+            let key = event.key();
+            if matches!(key.as_str(), "ArrowLeft" | "ArrowRight" | "Home" | "End") {
+                event.prevent_default();
+                Some(Msg::BitKey(key))
+            } else {
+                None
+            }
+        }),
+        view_bit_field(model, BitType::Sign, 0..1, None),
+        view_bit_field(model, BitType::Exponent, 1..significand_start, None),
+        view_bit_field(
+            model,
+            BitType::Significand,
+            significand_start..encoding.len(),
+            IF!(encoding.options.implicit_bit => span![
+                C!["bit_column", "field_start"],
+                span![
+                    C!["bit", "hidden_bit"],
+                    attrs! {
+                        At::Title => model.locale.text(Label::ImplicitBitTitle),
+                        At::from("aria-label") => sentence(
+                            model,
+                            Sentence::ImplicitBitAria,
+                            &[&u8::from(encoding.leading_bit()).to_string()]
+                        )
+                    },
+                    if encoding.leading_bit() { "1" } else { "0" },
+                ],
+                span![C!["bit_index"]],
+            ]),
+        ),
+        div![
+            C!["visually_hidden"],
+            attrs! {At::from("role") => "status", At::from("aria-live") => "polite"},
+            model.announcement.as_str(),
+        ],
+    ]
+}
+
+/// The bits at `indices`, which make up field `t`, grouped so that screen readers name the
+/// field. `leading` goes before them.
+fn view_bit_field(
+    model: &Model,
+    t: BitType,
+    indices: Range<usize>,
+    leading: Option<Node<Msg>>,
+) -> Node<Msg> {
+    span![
+        C!["bit_field"],
+        attrs! {
+            At::from("role") => "group",
+            At::from("aria-label") => {
+                let name = model.locale.text(Text::Field(t));
+                match indices.len() {
+                    1 => sentence(model, Sentence::FieldOfOneBit, &[name]),
+                    n => sentence(model, Sentence::FieldOfBits, &[name, &n.to_string()]),
+                }
+            }
+        },
+        leading,
+        indices
+            .filter_map(|i| model.encoding.bit(i))
+            .map(|bit| view_bit(model, bit)),
+    ]
+}
+
+fn view_bit(model: &Model, bit: Bit) -> Node<Msg> {
+    let i = bit.index;
+    let flip = match model.flipped.get(i) {
+        Some(true) if model.flip_parity => Some("flip_odd"),
+        Some(true) => Some("flip_even"),
+        _ => None,
+    };
+    // Only one bit is a tab stop, and the arrow keys move between them, so tabbing past the
+    // row doesn't take dozens of presses.
+    let focusable = i == model.bit_cursor.min(model.encoding.len() - 1);
+    // Positions count from the least significant bit, as in a register diagram.
+    let position = model.encoding.len() - 1 - i;
+    let field_start = i == 1
+        || (i == 1 + model.encoding.exponent_bits.len() && !model.encoding.options.implicit_bit);
+    let nibble_start = model.group_nibbles && i != 0 && (position + 1) % 4 == 0;
+    // Bits the walkthrough hasn't reached yet are faded, and those of its current step ringed.
+    let settled = match current_walkthrough(model).map(|walkthrough| walkthrough.settled(i)) {
+        Some(Ordering::Equal) => Some("settling"),
+        Some(Ordering::Greater) => Some("unsettled"),
+        _ => None,
+    };
+    let button = button![
+        C!["bit", flip, settled],
+        id![format!("bit_{}", i)],
+        attrs! {
+            At::TabIndex => if focusable { 0 } else { -1 },
+            At::from("aria-label") => sentence(
+                model,
+                Sentence::BitAria,
+                &[
+                    &model.locale.text(Text::Field(bit.field)).to_lowercase(),
+                    &bit.weight.to_string(),
+                    &u8::from(bit.value).to_string(),
+                ]
+            )
+        },
+        style! {St::BackgroundColor => model.colors.get(bit.field) },
+        if bit.value { "1" } else { "0" },
+        ev(Ev::Focus, move |_| Msg::BitFocused(i)),
+        if model.explain_mode {
+            vec![ev(Ev::Click, move |_| Msg::ExplainBit(Some(i)))]
+        } else {
+            vec![
+                mouse_ev(
+                    Ev::MouseDown,
+                    move |event| IF!(event.button() == 0 => Msg::StartDrag(i)),
+                ),
+                // The mouse toggles bits as its button goes down, so only clicks from the
+                // keyboard, which have no click count, are left to toggle them here.
+                mouse_ev(
+                    Ev::Click,
+                    move |event| IF!(event.detail() == 0 => Msg::ToggleBit(i)),
+                ),
+            ]
+        },
+        if model.drag.is_some() {
+            ev(Ev::MouseEnter, move |_| Msg::DragOver(i))
+        } else {
+            ev(Ev::MouseEnter, move |_| Msg::HoverBit(Some(i)))
+        },
+        ev(Ev::MouseLeave, |_| Msg::HoverBit(None)),
+    ];
+    span![
+        C![
+            "bit_column",
+            IF!(field_start => "field_start"),
+            IF!(nibble_start => "nibble_start")
+        ],
+        button,
+        span![C!["bit_index"], position.to_string()],
+    ]
+}
+
+/// The fields decoded one step at a time, from the stored integers to the factors of the value.
+fn view_fields(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    let stored_exponent = encoding.field(BitType::Exponent);
+    let reserved = encoding.is_infinite() || encoding.is_nan();
+    let subnormal = stored_exponent == 0;
+    let (significand, _) = encoding.scaled_significand();
+    #[allow(clippy::cast_sign_loss)]
+    let fraction_len = (encoding.precision() - 1) as usize;
+    let locale = model.locale;
+    let unbiased = if reserved {
+        locale.text(Label::NoUnbiasedExponent).to_owned()
+    } else if subnormal {
+        sentence(
+            model,
+            Sentence::SubnormalExponent,
+            &[&encoding.emin().to_string()],
+        )
+    } else {
+        format!(
+            "{} \u{2212} {} = {}",
+            stored_exponent,
+            encoding.bias(),
+            i128::from(stored_exponent) - i128::from(encoding.bias())
+        )
+    };
+    let leading = locale.text(
+        match (encoding.options.implicit_bit, encoding.leading_bit()) {
+            (true, true) => Label::LeadingImplicitOne,
+            (true, false) => Label::LeadingImplicitZero,
+            (false, true) => Label::LeadingStoredOne,
+            (false, false) => Label::LeadingStoredZero,
+        },
+    );
+    let row = |name: &str, value: String| tr![td![name], td![C!["binary"], value]];
+    div![
+        C!["fields"],
+        table![
+            row(
+                locale.text(Label::Sign),
+                if encoding.sign_bit { "\u{2212}1" } else { "+1" }.to_owned()
+            ),
+            row(
+                locale.text(Label::StoredExponent),
+                stored_exponent.to_string()
+            ),
+            row(locale.text(Text::Bias), encoding.bias().to_string()),
+            row(locale.text(Label::UnbiasedExponent), unbiased),
+            row(locale.text(Label::LeadingBit), leading.to_owned()),
+            row(
+                locale.text(Label::SignificandInteger),
+                significand.to_string()
+            ),
+            row(
+                locale.text(Label::SignificandFraction),
+                format!(
+                    "{} / 2^{} = {}",
+                    significand,
+                    fraction_len,
+                    binary_fraction(&significand, fraction_len)
+                )
+            ),
+        ],
+    ]
+}
+
+/// The formula for the value, first in general and then with the fields substituted in.
+fn view_formula(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    if encoding.is_infinite() || encoding.is_nan() {
+        return div![
+            C!["formula"],
+            p![model.locale.text(if encoding.is_nan() {
+                Sentence::NoFormulaNan
+            } else {
+                Sentence::NoFormulaInfinity
+            })],
+        ];
+    }
+    let stored_exponent = encoding.field(BitType::Exponent);
+    let subnormal = stored_exponent == 0;
+    let leading = if encoding.leading_bit() { "1" } else { "0" };
+    let fraction: String = encoding.significand_bits[usize::from(!encoding.options.implicit_bit)..]
+        .iter()
+        .map(|&b| if b { '1' } else { '0' })
+        .collect();
+    let fraction = if fraction.is_empty() { "0" } else { &fraction };
+    let sign = if encoding.sign_bit { "1" } else { "0" };
+    let exponent = if subnormal {
+        format!("1 \u{2212} {}", encoding.bias())
+    } else {
+        format!("{} \u{2212} {}", stored_exponent, encoding.bias())
+    };
+    div![
+        C!["formula"],
+        p![
+            "(\u{2212}1)",
+            sup!["s"],
+            format!(" \u{D7} {}.f \u{D7} 2", leading),
+            sup![if subnormal {
+                "1 \u{2212} bias"
+            } else {
+                "e \u{2212} bias"
+            }],
+        ],
+        p![
+            C!["binary"],
+            "(\u{2212}1)",
+            sup![sign],
+            format!(" \u{D7} {}.{}\u{2082} \u{D7} 2", leading, fraction),
+            sup![exponent],
+            format!(" = {}", format_encoding(encoding)),
+        ],
+    ]
+}
+
+/// Controls for playing through consecutive bit patterns, one step at a time or on a timer.
+fn view_stepping(model: &Model) -> Node<Msg> {
+    div![
+        C!["stepping"],
+        if model.stepping.is_some() {
+            button![
+                model.locale.text(Button::Pause),
+                ev(Ev::Click, |_| Msg::PauseSteps)
+            ]
+        } else {
+            button![
+                attrs! {At::Title => model.locale.text(Label::PlayStepsTitle)},
+                model.locale.text(Button::Play),
+                ev(Ev::Click, |_| Msg::PlaySteps)
+            ]
+        },
+        button![
+            attrs! {At::Title => model.locale.text(Label::StepTitle)},
+            model.locale.text(Button::Step),
+            ev(Ev::Click, |_| Msg::StepPattern)
+        ],
+        span![sentence(model, Sentence::RawBits, &[&model.encoding.hex()])],
+    ]
+}
+
+/// Representable values shown on each side of the current one on the number line.
+const NUMBER_LINE_NEIGHBOURS: usize = 4;
+
+/// The nearest finite values on either side of the current one, spaced to scale, or on a log
+/// scale of their distance when the spacing varies too much to show linearly.
+fn view_number_line(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    if encoding.is_nan() || encoding.is_infinite() {
+        return empty![];
+    }
+    let walk = |step: fn(&Encoding) -> Option<Encoding>| {
+        iter::successors(Some(encoding.clone()), move |e| {
+            step(e).filter(|e| !e.is_infinite())
+        })
+        .skip(1)
+        .take(NUMBER_LINE_NEIGHBOURS)
+        .collect::<Vec<_>>()
+    };
+    let mut values = walk(Encoding::next_down);
+    values.reverse();
+    values.push(encoding.clone());
+    values.extend(walk(Encoding::next_up));
+    // The difference between nearby values is exact, so its logarithm is accurate even where
+    // the values themselves are too close together or too small for `f64`.
+    let offsets: Vec<(Encoding, Option<(bool, f64)>)> = values
+        .into_iter()
+        .map(|value| {
+            let offset = calculate(
+                Operator::Subtract,
+                &value,
+                encoding,
+                RoundingMode::TiesToEven,
+            )
+            .filter(|(difference, _)| !difference.is_zero())
+            .map(|(difference, _)| (difference.sign_bit, difference.log10()));
+            (value, offset)
+        })
+        .collect();
+    let logs = offsets
+        .iter()
+        .filter_map(|(_, offset)| offset.map(|(_, log)| log));
+    let (min, max) = logs.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), log| {
+        (min.min(log), max.max(log))
+    });
+    let log_scale = max - min > 2.0;
+    let position = |offset: Option<(bool, f64)>| {
+        let fraction = offset.map_or(0.0, |(negative, log)| {
+            let magnitude = if log_scale {
+                (log - min + 1.0) / (max - min + 1.0)
+            } else {
+                10_f64.powf(log - max)
+            };
+            if negative {
+                -magnitude
+            } else {
+                magnitude
+            }
+        });
+        format!("{}%", 50.0 + 45.0 * fraction)
+    };
+    div![
+        C!["number_line"],
+        h3![heading(
+            model,
+            if log_scale {
+                Heading::NeighboursLogScale
+            } else {
+                Heading::NeighboursToScale
+            }
+        )],
+        div![
+            C!["number_line_track"],
+            offsets.into_iter().map(|(value, offset)| {
+                let text = format_encoding(&value);
+                div![
+                    C!["number_line_tick", IF!(offset.is_none() => "current")],
+                    style! {St::Left => position(offset)},
+                    attrs! {At::Title => text},
+                    ev(Ev::Click, move |_| Msg::Load(value)),
+                ]
+            }),
+        ],
+    ]
+}
+
+/// Buttons for the neighbouring values and the gap between consecutive values here.
+fn view_neighbours(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    if encoding.is_nan() {
+        return empty![];
+    }
+    let step = |label: &str, next: Option<Encoding>, msg: fn() -> Msg| {
+        button![
+            attrs! {
+                At::Disabled => next.is_none().as_at_value(),
+                At::Title => next.map_or_else(
+                    || model.locale.text(Label::NoValueBeyond).to_owned(),
+                    |next| format_encoding(&next)
+                )
+            },
+            label,
+            ev(Ev::Click, move |_| msg()),
+        ]
+    };
+    let scale = encoding.ulp_exponent();
+    let per_binade = encoding.binade_size_exponent();
+    let binade = if encoding.field(BitType::Exponent) == 0 {
+        sentence(
+            model,
+            Sentence::FromZeroTo,
+            &[&(scale + per_binade).to_string()],
+        )
+    } else {
+        sentence(
+            model,
+            Sentence::FromTo,
+            &[
+                &(scale + per_binade).to_string(),
+                &(scale + per_binade + 1).to_string(),
+            ],
+        )
+    };
+    let count = if per_binade < 64 {
+        format!(" = {}", group_digits(1_u64 << per_binade))
+    } else {
+        String::new()
+    };
+    div![
+        C!["neighbours"],
+        step(
+            model.locale.text(Button::PreviousValue),
+            encoding.next_down(),
+            || Msg::StepDown
+        ),
+        div![
+            C!["ulp"],
+            div![sentence(
+                model,
+                Sentence::OneUlp,
+                &[&scale.to_string(), &format_scaled(1.0, scale)]
+            )],
+            IF!(!encoding.is_infinite() => div![
+                C!["hint"],
+                sentence(
+                    model,
+                    Sentence::BinadeValues,
+                    &[&per_binade.to_string(), &count, &binade]
+                )
+            ]),
+        ],
+        step(
+            model.locale.text(Button::NextValue),
+            encoding.next_up(),
+            || Msg::StepUp
+        ),
+    ]
+}
+
+/// The current value `a` next to a second operand `b` in the same format, with how they
+/// compare.
+fn view_comparison(model: &Model) -> Node<Msg> {
+    let (a, b) = (&model.encoding, &model.second);
+    let bits = |x: &Encoding, toggle: Option<fn(usize) -> Msg>| -> Node<Msg> {
+        div![
+            C!["bits", "operand_bits"],
+            (0..x.len()).filter_map(|i| x.bit(i)).map(|bit| {
+                let i = bit.index;
+                button![
+                    C!["bit"],
+                    style! {St::BackgroundColor => model.colors.get(bit.field)},
+                    attrs! {At::Disabled => toggle.is_none().as_at_value()},
+                    if bit.value { "1" } else { "0" },
+                    toggle.map(|toggle| ev(Ev::Click, move |_| toggle(i))),
+                ]
+            }),
+        ]
+    };
+    let relation = match a.compare(b) {
+        Some(Ordering::Less) => "a < b",
+        Some(Ordering::Equal) if a.bits().eq(b.bits()) => "a == b",
+        Some(Ordering::Equal) => model.locale.text(Label::EqualEncodingsDiffer),
+        Some(Ordering::Greater) => "a > b",
+        None => model.locale.text(Label::Unordered),
+    };
+    let finite = |x: &Encoding| !x.is_nan() && !x.is_infinite();
+    let distance = if a.fits_u64() && finite(a) && finite(b) {
+        let steps = i128::from(a.to_ordinal()) - i128::from(b.to_ordinal());
+        Some(sentence(
+            model,
+            Sentence::StepsApart,
+            &[&group_digits(steps.abs())],
+        ))
+    } else {
+        None
+    };
+    div![
+        C!["comparison"],
+        h3![heading(model, Heading::Comparison)],
+        table![
+            tr![td!["a"], td![bits(a, None)], td![format_encoding(a)],],
+            tr![
+                td!["b"],
+                td![bits(b, Some(Msg::ToggleSecondBit))],
+                td![format_encoding(b)],
+            ],
+            model.operator.map(|operator| {
+                let name = format!("a {} b", operator.symbol());
+                match &model.result {
+                    Some((result, _)) => {
+                        tr![
+                            td![name],
+                            td![bits(result, None)],
+                            td![format_encoding(result)]
+                        ]
+                    }
+                    None => tr![
+                        td![name],
+                        td![],
+                        td![model.locale.text(Label::NoResultFormatNoNans)]
+                    ],
+                }
+            }),
+        ],
+        label![
+            format!("{}: ", model.locale.text(Label::SetBFromDecimal)),
+            input![
+                attrs! {At::Value => format_encoding(b)},
+                input_ev(Ev::Change, Msg::SetSecondFromDecimal),
+            ],
+        ],
+        button![
+            model.locale.text(Button::SwapOperands),
+            ev(Ev::Click, |_| Msg::SwapOperands)
+        ],
+        div![
+            C!["operators"],
+            Operator::ALL.iter().map(|&operator| {
+                button![
+                    C![IF!(model.operator == Some(operator) => "current")],
+                    format!("a {} b", operator.symbol()),
+                    ev(Ev::Click, move |_| Msg::Calculate(operator)),
+                ]
+            }),
+        ],
+        model.result.as_ref().map(|(_, flags)| {
+            p![
+                if flags.inexact {
+                    sentence(model, Sentence::RoundedWith, &[model.rounding.name()])
+                } else {
+                    model.locale.text(Sentence::ResultExact).to_owned()
+                },
+                button![
+                    model.locale.text(Button::UseAsA),
+                    ev(Ev::Click, |_| Msg::UseResult)
+                ],
+            ]
+        }),
+        model
+            .second_error
+            .as_ref()
+            .map(|e| div![C!["error"], e.as_str()]),
+        p![relation],
+        distance.map(|d| p![d]),
+    ]
+}
+
+/// The exception flags raised since they were last cleared. Like those of a real FPU they are
+/// sticky, so a flag stays raised however many exact operations follow.
+fn view_flags(model: &Model) -> Node<Msg> {
+    div![
+        C!["flags"],
+        h3![heading(model, Heading::Flags)],
+        ul![model.flags.named().iter().map(|&(raised, name)| li![
+            C![IF!(raised => "raised")],
+            name,
+            format!(
+                " {}",
+                model
+                    .locale
+                    .text(if raised { Label::Raised } else { Label::Clear })
+            ),
+        ])],
+        button![
+            model.locale.text(Button::ClearFlags),
+            attrs! {At::Disabled => (model.flags == Flags::default()).as_at_value()},
+            ev(Ev::Click, |_| Msg::ClearFlags),
+        ],
+    ]
+}
+
+/// The bytes of the encoding at increasing addresses, as a hex dump of memory holding it
+/// would show them.
+fn view_bytes(model: &Model) -> Node<Msg> {
+    let mut bytes = model.encoding.bytes();
+    if model.little_endian {
+        bytes.reverse();
+    }
+    div![
+        C!["bytes"],
+        table![
+            tr![bytes
+                .iter()
+                .enumerate()
+                .map(|(offset, _)| th![format!("+{}", offset)])],
+            tr![bytes
+                .iter()
+                .map(|byte| td![C!["binary"], format!("{:02X}", byte)])],
+        ],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => (!model.little_endian).as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::ToggleByteOrder),
+            ],
+            model.locale.text(Label::BigEndian),
+        ],
+        IF!(model.encoding.len() % 8 != 0 => span![
+            C!["hint"],
+            sentence(
+                model,
+                Sentence::PaddedWith,
+                &[&(8 - model.encoding.len() % 8).to_string()]
+            ),
+        ]),
+    ]
+}
+
+/// The raw bits in hex. Once a digit is selected, typing overwrites it and moves on to the
+/// next one, which is much quicker than clicking bits when copying a pattern.
+fn view_hex_entry(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    div![
+        C!["hex_entry"],
+        attrs! {At::TabIndex => 0},
+        keyboard_ev(Ev::KeyDown, |event| {
+            let key = event.key();
+            let handled = matches!(
+                key.as_str(),
+                "ArrowLeft" | "ArrowRight" | "Backspace" | "Escape"
+            ) || (key.len() == 1 && key.chars().all(|c| c.is_ascii_hexdigit()));
+            if handled {
+                event.prevent_default();
+                Some(Msg::HexKey(key))
+            } else {
+                None
+            }
+        }),
+        ev(Ev::Blur, |_| Msg::SelectNibble(None)),
+        "0x",
+        (0..encoding.nibble_count()).map(|i| span![
+            C!["nibble", IF!(model.nibble_cursor == Some(i) => "selected")],
+            format!("{:X}", encoding.nibble(i)),
+            ev(Ev::Click, move |_| Msg::SelectNibble(Some(i))),
+        ]),
+        span![
+            C!["hint"],
+            model.locale.text(if model.nibble_cursor.is_some() {
+                Label::TypingHexHint
+            } else {
+                Label::ClickHexHint
+            })
+        ],
+    ]
+}
+
+fn view_explanation(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    div![
+        C!["explain"],
+        label![
+            input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.explain_mode.as_at_value()
+                },
+                ev(Ev::Change, |_| Msg::ToggleExplainMode),
+            ],
+            model.locale.text(Label::ExplainBits),
+        ],
+        model
+            .explained_bit
+            .and_then(|i| encoding.bit(i))
+            .map(|bit| {
+                let role = match bit.field {
+                    BitType::Sign => model.locale.text(Sentence::SignBitRole).to_owned(),
+                    BitType::Exponent => sentence(
+                        model,
+                        Sentence::ExponentBitRole,
+                        &[
+                            &(1_u64 << bit.weight).to_string(),
+                            &encoding.bias().to_string(),
+                            model.locale.text(
+                                match (encoding.options.infinities, encoding.options.nans) {
+                                    (true, true) => Label::ReservedBoth,
+                                    (true, false) => Label::ReservedInfinity,
+                                    (false, true) => Label::ReservedNan,
+                                    (false, false) => Label::ReservedNone,
+                                },
+                            ),
+                        ],
+                    ),
+                    BitType::Significand
+                        if !encoding.options.implicit_bit
+                            && bit.weight + 1 == encoding.significand_bits.len() =>
+                    {
+                        model
+                            .locale
+                            .text(Sentence::ExplicitLeadingBitRole)
+                            .to_owned()
+                    }
+                    BitType::Significand => sentence(
+                        model,
+                        Sentence::SignificandBitRole,
+                        &[&(encoding.significand_bits.len()
+                            - usize::from(!encoding.options.implicit_bit)
+                            - bit.weight)
+                            .to_string()],
+                    ),
+                };
+                let current = encoding.value();
+                let flipped = encoding.toggled(bit.index).value();
+                div![
+                    C!["explanation"],
+                    h3![heading(model, Heading::FieldBit(bit.field))],
+                    p![role],
+                    p![sentence(
+                        model,
+                        Sentence::FlipWouldChange,
+                        &[
+                            &format_value(current),
+                            &format_value(flipped),
+                            &format_value(flipped - current),
+                        ]
+                    )],
+                    button![
+                        model.locale.text(Button::ClearField(bit.field)),
+                        ev(Ev::Click, move |_| Msg::SetField(bit.field, 0))
+                    ],
+                    button![
+                        model.locale.text(Button::Close),
+                        ev(Ev::Click, |_| Msg::ExplainBit(None))
+                    ],
+                ]
+            }),
+    ]
+}
+
+/// Offers to restore the value from the previous visit.
+fn view_restore(model: &Model) -> Node<Msg> {
+    model.restorable.as_ref().map_or(empty![], |encoding| {
+        div![
+            C!["restore"],
+            sentence(
+                model,
+                Sentence::RestorePrompt,
+                &[&format_value(encoding.value()), &encoding.hex()]
+            ),
+            button![
+                model.locale.text(Button::Restore),
+                ev(Ev::Click, |_| Msg::RestoreSession)
+            ],
+            button![
+                model.locale.text(Button::Discard),
+                ev(Ev::Click, |_| Msg::DiscardSession)
+            ],
+        ]
+    })
+}
+
+fn view_value(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    // NaNs differ in their quiet bit and payload, which "NaN" alone hides.
+    let (value, nan) = match encoding.nan_payload() {
+        Some(payload) => {
+            let sign = if encoding.sign_bit { "\u{2212}" } else { "" };
+            let (short, long) = if encoding.is_signaling_nan() {
+                ("sNaN", Label::SignalingNan)
+            } else {
+                ("qNaN", Label::QuietNan)
+            };
+            (
+                format!("{}{}", sign, short),
+                Some(div![
+                    C!["nan_payload"],
+                    sentence(
+                        model,
+                        Sentence::NanPayload,
+                        &[model.locale.text(long), &format!("{:#X}", payload)]
+                    )
+                ]),
+            )
+        }
+        None => (format_encoding(encoding), None),
+    };
+    let class = encoding.class();
+    let rounded_from = typed_decimal(model)
+        .filter(|(_, flags)| flags.inexact)
+        .map(|(text, _)| text);
+    div![
+        id!["result"],
+        C!["value"],
+        div![
+            C!["class_badge"],
+            attrs! {
+                At::Title => sentence(
+                    model,
+                    Sentence::ClassTitle,
+                    &[model.locale.text(Text::Class(class)), class.fpclassify()]
+                )
+            },
+            model.locale.text(Text::Class(class)),
+        ],
+        encoding.non_canonical().map(|kind| div![
+            C!["class_badge", "non_canonical"],
+            attrs! {At::Title => model.locale.text(Text::NonCanonical(kind))},
+            kind.name(),
+        ]),
+        span![
+            C![IF!(rounded_from.is_some() => "rounded")],
+            rounded_from.map(|text| attrs! {
+                At::Title => sentence(model, Sentence::RoundedFrom, &[text.trim()])
+            }),
+            value,
+        ],
+        nan,
+        view_ghost(model),
+        view_hover_preview(model),
+    ]
+}
+
+/// Digits beyond which the exact decimal value isn't shown, which is enough for any binary64
+/// value.
+const EXACT_DECIMAL_LIMIT: usize = 1500;
+
+/// The value in full, with every digit of its decimal expansion.
+fn view_exact_decimal(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    if encoding.is_nan() || encoding.is_infinite() {
+        return empty![];
+    }
+    div![
+        C!["exact_decimal"],
+        h3![heading(model, Heading::ExactDecimal)],
+        match exact_decimal(encoding, EXACT_DECIMAL_LIMIT) {
+            Some(digits) => p![C!["binary"], digits],
+            None => p![sentence(
+                model,
+                Sentence::TooManyDigits,
+                &[&EXACT_DECIMAL_LIMIT.to_string()]
+            )],
+        },
+    ]
+}
+
+/// The value as a fraction in lowest terms, whose denominator is always a power of two.
+fn view_exact_fraction(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    if encoding.is_nan() || encoding.is_infinite() {
+        return empty![];
+    }
+    let (numerator, power) = encoding.reduced_ratio();
+    let sign = if encoding.sign_bit { "\u{2212}" } else { "" };
+    // Each digit takes a little over three bits.
+    let digits = numerator.bit_len() * 3 / 10;
+    div![
+        C!["exact_fraction"],
+        h3![heading(model, Heading::ExactFraction)],
+        if digits > EXACT_DECIMAL_LIMIT {
+            p![sentence(
+                model,
+                Sentence::NumeratorTooLong,
+                &[&EXACT_DECIMAL_LIMIT.to_string()]
+            )]
+        } else if power == 0 {
+            p![C!["binary"], format!("{}{}", sign, numerator)]
+        } else {
+            p![
+                C!["binary"],
+                format!("{}{} / 2", sign, numerator),
+                sup![power.to_string()],
+            ]
+        },
+    ]
+}
+
+/// log2 and log10 of the magnitude, saying when each is an exact integer.
+fn view_logarithms(model: &Model) -> Node<Msg> {
+    let encoding = &model.encoding;
+    if encoding.is_nan() {
+        return empty![];
+    }
+    let (log2, log10) = if encoding.is_infinite() {
+        ("\u{221E}".to_owned(), "\u{221E}".to_owned())
+    } else if encoding.is_zero() {
+        ("\u{2212}\u{221E}".to_owned(), "\u{2212}\u{221E}".to_owned())
+    } else {
+        (
+            encoding
+                .exact_log2()
+                .map_or_else(|| format!("{:.6}", encoding.log2()), |log| log.to_string()),
+            encoding
+                .exact_log10()
+                .map_or_else(|| format!("{:.6}", encoding.log10()), |log| log.to_string()),
+        )
+    };
+    let special = encoding.is_zero() || encoding.is_infinite();
+    let subnormal = encoding.field(BitType::Exponent) == 0;
+    let note = |label| model.locale.text(label);
+    let log2_note = match encoding.exact_log2() {
+        Some(_) if subnormal => note(Label::ExactSingleOne),
+        Some(_) => note(Label::ExactFractionZeros),
+        None if special => "",
+        None => note(Label::RoundedNotPowerOfTwo),
+    };
+    let log10_note = match encoding.exact_log10() {
+        Some(_) => note(Label::ExactPowerOfTen),
+        None if special => "",
+        None => note(Label::RoundedNotPowerOfTen),
+    };
+    div![
+        C!["logarithms"],
+        table![
+            tr![
+                td!["log", sub!["2"], " |x|"],
+                td![C!["binary"], log2],
+                td![C!["hint"], log2_note],
+            ],
+            tr![
+                td!["log", sub!["10"], " |x|"],
+                td![C!["binary"], log10],
+                td![C!["hint"], log10_note],
+            ],
+        ],
+    ]
+}
+
+/// Fields for typing a decimal number, which is rounded to the nearest value of the format, or
+/// a hex pattern for the raw bits.
+fn view_decimal_entry(model: &Model) -> Node<Msg> {
+    div![
+        C!["decimal_entry"],
+        label![
+            format!("{}: ", model.locale.text(Label::SetFromDecimal)),
+            input![
+                attrs! {
+                    At::Value => format_encoding(&model.encoding),
+                    At::Placeholder => sentence(model, Sentence::ForExample, &["0.1"])
+                },
+                input_ev(Ev::Change, Msg::SetFromDecimal),
+            ],
+        ],
+        label![
+            format!("{}: ", model.locale.text(Label::Hex)),
+            input![
+                C!["binary"],
+                attrs! {
+                    At::Value => model.encoding.hex(),
+                    At::Size => model.encoding.nibble_count() + 3
+                },
+                input_ev(Ev::Change, Msg::SetFromHex),
+            ],
+        ],
+        label![
+            format!("{}: ", model.locale.text(Label::Binary)),
+            input![
+                C!["binary"],
+                attrs! {
+                    At::Value => model.encoding.field_string(),
+                    At::Size => model.encoding.len() + 6
+                },
+                input_ev(Ev::Change, Msg::SetFromBinary),
+            ],
+        ],
+        label![
+            format!("{}: ", model.locale.text(Label::HexFloat)),
+            input![
+                C!["binary"],
+                attrs! {
+                    At::Value => format_hex_float(&model.encoding),
+                    At::Placeholder => sentence(model, Sentence::ForExample, &["0x1.8p+1"])
+                },
+                input_ev(Ev::Change, Msg::SetFromHexFloat),
+            ],
+        ],
+        model
+            .decimal_error
+            .iter()
+            .chain(&model.hex_error)
+            .chain(&model.binary_error)
+            .chain(&model.hex_float_error)
+            .map(|e| div![C!["error"], e.as_str()]),
+        view_rounding_error(model),
+        model.decimal_input.as_ref().map(|text| {
+            let text = text.clone();
+            button![
+                model.locale.text(Button::WalkThrough),
+                ev(Ev::Click, move |_| Msg::StartWalkthrough(text)),
+            ]
+        }),
+        model
+            .decimal_input
+            .as_ref()
+            .map(|text| view_roundings(model, text)),
+    ]
+}
+
+/// A seed of 53 random bits, as many as `Math.random` gives.
+fn random_seed() -> u64 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let seed = (js_sys::Math::random() * 2_f64.powi(53)) as u64;
+    seed
+}
+
+/// Practice at encoding values and reading bit patterns, with the score so far.
+fn view_quiz(model: &Model) -> Node<Msg> {
+    let quiz = match &model.quiz {
+        Some(quiz) => quiz,
+        None => {
+            return div![
+                C!["quiz"],
+                button![
+                    model.locale.text(Button::Practise),
+                    ev(Ev::Click, |_| Msg::StartQuiz(random_seed())),
+                ],
+            ]
+        }
+    };
+    let answer = &quiz.answer;
+    let question = match quiz.kind {
+        Kind::BuildBits => div![
+            p![sentence(
+                model,
+                Sentence::AskBuildBits,
+                &[&format_encoding(answer)]
+            )],
+            IF!(!model.encoding.same_layout(answer) => p![
+                C!["hint"],
+                model.locale.text(Label::FormatChanged)
+            ]),
+            button![
+                model.locale.text(Button::Check),
+                attrs! {At::Disabled => quiz.is_over().as_at_value()},
+                ev(Ev::Click, |_| Msg::CheckQuizBits),
+            ],
+        ],
+        Kind::ReadBits => div![
+            p![model.locale.text(Label::WhatValue)],
+            view_field_bits(
+                model,
+                answer
+                    .bits()
+                    .enumerate()
+                    .map(|(i, bit)| {
+                        let field = if i == 0 {
+                            "Sign"
+                        } else if i <= answer.exponent_bits.len() {
+                            "Exponent"
+                        } else {
+                            "Fraction"
+                        };
+                        (bit, field)
+                    })
+                    .collect()
+            ),
+            label![
+                format!("{}: ", model.locale.text(Label::Value)),
+                input![
+                    attrs! {
+                        At::Placeholder => sentence(model, Sentence::ForExample, &["-2.5"]),
+                        At::Disabled => quiz.is_over().as_at_value()
+                    },
+                    input_ev(Ev::Change, Msg::AnswerQuiz),
+                ],
+            ],
+        ],
+    };
+    div![
+        C!["quiz"],
+        h3![heading(model, Heading::Practice)],
+        p![sentence(
+            model,
+            Sentence::QuizProgress,
+            &[
+                &quiz.asked.to_string(),
+                &quiz.solved.to_string(),
+                &(quiz.asked - u32::from(!quiz.is_over())).to_string(),
+            ]
+        )],
+        question,
+        match quiz.status {
+            Status::Unanswered => empty![],
+            Status::Wrong => p![
+                C!["error"],
+                if quiz.attempts == 1 {
+                    sentence(model, Sentence::WrongOnce, &[])
+                } else {
+                    sentence(model, Sentence::Wrong, &[&quiz.attempts.to_string()])
+                }
+            ],
+            Status::Right => p![
+                C!["right"],
+                if quiz.attempts == 1 {
+                    sentence(model, Sentence::RightFirstTime, &[])
+                } else {
+                    sentence(model, Sentence::Right, &[&quiz.attempts.to_string()])
+                }
+            ],
+            Status::Revealed => div![
+                p![sentence(
+                    model,
+                    Sentence::TheAnswer,
+                    &[&answer.field_string(), &format_encoding(answer)]
+                )],
+                quiz.explanation()
+                    .iter()
+                    .map(|line| p![line.text(model.locale)]),
+                {
+                    let answer = answer.clone();
+                    button![
+                        model.locale.text(Button::LoadAnswer),
+                        ev(Ev::Click, move |_| Msg::Load(answer))
+                    ]
+                },
+            ],
+        },
+        div![
+            C!["quiz_controls"],
+            button![
+                model.locale.text(Button::RevealAnswer),
+                attrs! {At::Disabled => quiz.is_over().as_at_value()},
+                ev(Ev::Click, |_| Msg::RevealAnswer),
+            ],
+            button![
+                model.locale.text(Button::NextQuestion),
+                ev(Ev::Click, |_| Msg::NextQuestion(random_seed())),
+            ],
+            button![
+                model.locale.text(Button::StopPractising),
+                ev(Ev::Click, |_| Msg::StopQuiz)
+            ],
+        ],
+    ]
+}
+
+/// The walkthrough of the current value's conversion from decimal, if it is the value shown.
+fn current_walkthrough(model: &Model) -> Option<&Walkthrough> {
+    model
+        .walkthrough
+        .as_ref()
+        .filter(|walkthrough| walkthrough.result == model.encoding)
+}
+
+/// The conversion steps so far, each settling some bits of the bit row.
+fn view_walkthrough(model: &Model) -> Node<Msg> {
+    let walkthrough = match current_walkthrough(model) {
+        Some(walkthrough) => walkthrough,
+        None => return empty![],
+    };
+    let step = walkthrough.step;
+    let last = walkthrough.steps.len() - 1;
+    div![
+        C!["walkthrough"],
+        h3![heading(model, Heading::Walkthrough)],
+        ol![walkthrough.steps[..=step]
+            .iter()
+            .enumerate()
+            .map(|(i, shown)| li![
+                C![IF!(i == step => "current")],
+                h4![model.locale.text(shown.title)],
+                shown.lines.iter().map(|line| p![line.text(model.locale)]),
+            ])],
+        div![
+            C!["walkthrough_controls"],
+            button![
+                model.locale.text(Button::PreviousStep),
+                attrs! {At::Disabled => (step == 0).as_at_value()},
+                ev(Ev::Click, move |_| Msg::ShowWalkthroughStep(
+                    step.saturating_sub(1)
+                )),
+            ],
+            sentence(
+                model,
+                Sentence::StepOf,
+                &[&(step + 1).to_string(), &(last + 1).to_string()]
+            ),
+            button![
+                model.locale.text(Button::NextStep),
+                attrs! {At::Disabled => (step == last).as_at_value()},
+                ev(Ev::Click, move |_| Msg::ShowWalkthroughStep(step + 1)),
+            ],
+            button![
+                model.locale.text(Button::Close),
+                ev(Ev::Click, |_| Msg::CloseWalkthrough)
+            ],
+        ],
+    ]
+}
+
+/// The last typed decimal and the flags from rounding it, if the current value is still the
+/// one it rounded to.
+fn typed_decimal(model: &Model) -> Option<(&str, Flags)> {
+    let text = model.decimal_input.as_deref()?;
+    let (rounded, flags) = parse_decimal(text, &model.encoding, model.rounding).ok()?;
+    Some((text, flags)).filter(|_| rounded == model.encoding)
+}
+
+/// How far the stored value is from the typed decimal, exactly.
+fn view_rounding_error(model: &Model) -> Node<Msg> {
+    let (text, flags) = match typed_decimal(model) {
+        Some(typed) => typed,
+        None => return empty![],
+    };
+    let message = if !flags.inexact {
+        sentence(model, Sentence::StoredExactly, &[text.trim()])
+    } else {
+        match decimal_rounding_error(text, &model.encoding, EXACT_DECIMAL_LIMIT) {
+            Some(error) => sentence(model, Sentence::OffBy, &[text.trim(), &error]),
+            None => sentence(
+                model,
+                Sentence::ErrorTooLong,
+                &[text.trim(), &EXACT_DECIMAL_LIMIT.to_string()],
+            ),
+        }
+    };
+    p![
+        C!["rounding_error", IF!(flags.inexact => "rounded")],
+        message
+    ]
+}
+
+/// How the last typed decimal rounds in each direction, in the current format.
+fn view_roundings(model: &Model, text: &str) -> Node<Msg> {
+    table![
+        C!["roundings"],
+        RoundingMode::ALL.iter().filter_map(|&rounding| {
+            let (rounded, flags) = parse_decimal(text, &model.encoding, rounding).ok()?;
+            Some(tr![
+                C![IF!(rounding == model.rounding => "current")],
+                td![rounding.name()],
+                td![C!["binary"], rounded.hex()],
+                td![format_encoding(&rounded)],
+                td![model.locale.text(if flags.inexact {
+                    Label::Rounded
+                } else {
+                    Label::Exact
+                })],
+            ])
+        }),
+    ]
+}
+
+/// The value before the last edit and the signed change, fading out after each edit.
+fn view_ghost(model: &Model) -> Node<Msg> {
+    model.ghost.map_or(empty![], |previous| {
+        let change = model.encoding.value() - previous;
+        span![
+            C![
+                "ghost",
+                if model.ghost_parity {
+                    "ghost_odd"
+                } else {
+                    "ghost_even"
+                }
+            ],
+            format!(
+                "{} ({}{})",
+                format_value(previous),
+                if change >= 0.0 { "+" } else { "" },
+                format_value(change)
+            )
+        ]
+    })
+}
+
+/// The value the number would have if the hovered bit were toggled.
+fn view_hover_preview(model: &Model) -> Node<Msg> {
+    let current = model.encoding.value();
+    match model.hovered_bit {
+        Some(i) if i < model.encoding.len() => {
+            let flipped = model.encoding.toggled(i).value();
+            div![
+                C!["hover_preview"],
+                format!(
+                    "{} (\u{394} {})",
+                    format_value(flipped),
+                    format_value(flipped - current)
+                )
+            ]
+        }
+        _ => div![C!["hover_preview"]],
+    }
+}
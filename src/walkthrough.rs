@@ -0,0 +1,502 @@
+//! The conversion of a typed decimal into the current format, worked through as it is done by
+//! hand: the integer part by repeated halving, the fraction part by repeated doubling, then
+//! normalizing, biasing the exponent and rounding.
+
+use crate::{
+    bignum::BigUint,
+    encoding::{Encoding, Flags, RoundingMode},
+    i18n::{Label, Line, Sentence},
+    text::{decimal_ratio, format_encoding, parse_decimal, Decimal},
+};
+use std::cmp::Ordering;
+
+/// Halvings or doublings written out before the rest are summarized.
+const SHOWN_LINES: usize = 12;
+
+/// Decimal places beyond which the fraction part is too long to double by hand.
+const MAX_FRACTION_DIGITS: u32 = 1000;
+
+/// Doublings beyond which the walkthrough gives up. binary128 needs up to 16,495 to reach its
+/// smallest subnormal, and wider formats more.
+const MAX_DOUBLINGS: usize = 20_000;
+
+/// Binary digits written out before the middle of the rest is elided.
+const SHOWN_DIGITS: usize = 80;
+
+/// One stage of the conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub title: Label,
+    pub lines: Vec<Line>,
+    /// Positions in the bit row of the bits of the result this step settles.
+    pub bits: Vec<usize>,
+}
+
+/// The steps of converting a decimal, and how far through them the reader is.
+#[derive(Clone, PartialEq)]
+pub struct Walkthrough {
+    pub steps: Vec<Step>,
+    pub step: usize,
+    /// The encoding the decimal converts to.
+    pub result: Encoding,
+}
+
+impl Walkthrough {
+    /// Works through converting `text` into the format of `current`, rounding in the
+    /// direction of `rounding`.
+    pub fn new(text: &str, current: &Encoding, rounding: RoundingMode) -> Result<Self, String> {
+        let (result, flags) = parse_decimal(text, current, rounding)?;
+        let (sign_bit, numerator, fraction_len) = match decimal_ratio(text)? {
+            Decimal::Finite(sign_bit, numerator, fraction_len) => {
+                (sign_bit, numerator, fraction_len)
+            }
+            _ => return Err("Only finite numbers are converted digit by digit".to_owned()),
+        };
+        if fraction_len > MAX_FRACTION_DIGITS {
+            return Err(format!(
+                "Numbers with more than {} decimal places are too long to work through",
+                MAX_FRACTION_DIGITS
+            ));
+        }
+        let one = BigUint::pow10(fraction_len);
+        let (integer, fraction) = numerator.div_rem(&one);
+        let expansion = Expansion::new(&integer, fraction.clone(), fraction_len, &result)?;
+        let typed = Typed {
+            numerator,
+            fraction_len,
+        };
+        let steps = vec![
+            Step {
+                title: Label::Sign,
+                lines: vec![Line::new(
+                    if sign_bit {
+                        Sentence::TypedNegative
+                    } else {
+                        Sentence::TypedPositive
+                    },
+                    vec![text.trim().to_owned()],
+                )],
+                bits: vec![0],
+            },
+            Step {
+                title: Label::SplitParts,
+                lines: vec![
+                    Line::new(Sentence::IntegerPartIs, vec![integer.to_string()]),
+                    Line::new(
+                        Sentence::FractionPartIs,
+                        vec![decimal_fraction(&fraction, fraction_len)],
+                    ),
+                    Line::new(Sentence::PartsSeparately, Vec::new()),
+                ],
+                bits: Vec::new(),
+            },
+            integer_step(&integer, &expansion.integer_bits),
+            expansion.fraction_step(),
+            expansion.normalize_step(&typed, &result),
+            expansion.bias_step(&result, sign_bit),
+            expansion.round_step(&typed, &result, rounding, flags),
+        ];
+        Ok(Self {
+            steps,
+            step: 0,
+            result,
+        })
+    }
+
+    /// When bit `i` of the result is settled compared to the current step: `Less` if it
+    /// already has been and `Greater` if it is yet to be.
+    pub fn settled(&self, i: usize) -> Ordering {
+        self.steps
+            .iter()
+            .position(|step| step.bits.contains(&i))
+            .map_or(Ordering::Less, |settled| settled.cmp(&self.step))
+    }
+}
+
+/// The magnitude of the typed decimal, `numerator / 10^fraction_len`.
+struct Typed {
+    numerator: BigUint,
+    fraction_len: u32,
+}
+
+/// The binary digits of the typed value, as far as the rounding bit of the result.
+struct Expansion {
+    /// Most significant first.
+    integer_bits: Vec<bool>,
+    /// From the one worth 1/2 down.
+    fraction_bits: Vec<bool>,
+    /// The first few doublings which produced the fraction bits.
+    doublings: Vec<Line>,
+    /// Whether any of the fraction is left after the last doubling.
+    fraction_left: bool,
+    /// The exponent of the leading one bit, if there is one above the rounding bit.
+    leading: Option<i32>,
+    /// The exponent of the first kept bit: the leading one's, or that of the smallest normal
+    /// binade if it is lower.
+    top: i32,
+    precision: i32,
+}
+
+impl Expansion {
+    fn new(
+        integer: &BigUint,
+        mut fraction: BigUint,
+        fraction_len: u32,
+        result: &Encoding,
+    ) -> Result<Self, String> {
+        let (emin, precision) = (result.emin(), result.precision());
+        let one = BigUint::pow10(fraction_len);
+        let integer_bits: Vec<bool> = (0..integer.bit_len())
+            .rev()
+            .map(|i| integer.bit(i))
+            .collect();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let mut leading = integer_bits.len().checked_sub(1).map(|e| e as i32);
+        let mut fraction_bits = Vec::new();
+        let mut doublings = Vec::new();
+        // Doubling stops after the rounding bit, one below the last kept bit.
+        while !fraction.is_zero()
+            && fraction_weight(fraction_bits.len()) >= leading.unwrap_or(emin).max(emin) - precision
+        {
+            if fraction_bits.len() == MAX_DOUBLINGS {
+                return Err("The value is too small to work through by hand".to_owned());
+            }
+            let before = if doublings.len() < SHOWN_LINES {
+                Some(decimal_fraction(&fraction, fraction_len))
+            } else {
+                None
+            };
+            fraction = fraction.shl(1);
+            let bit = fraction >= one;
+            if bit {
+                fraction = fraction.sub(&one);
+                leading = leading.or_else(|| Some(fraction_weight(fraction_bits.len())));
+            }
+            if let Some(before) = before {
+                doublings.push(Line::new(
+                    Sentence::Doubling,
+                    vec![
+                        before,
+                        format!(
+                            "{}{}",
+                            u8::from(bit),
+                            decimal_fraction(&fraction, fraction_len).trim_start_matches('0')
+                        ),
+                        u8::from(bit).to_string(),
+                    ],
+                ));
+            }
+            fraction_bits.push(bit);
+        }
+        Ok(Self {
+            integer_bits,
+            fraction_bits,
+            doublings,
+            fraction_left: !fraction.is_zero(),
+            leading,
+            top: leading.unwrap_or(emin).max(emin),
+            precision,
+        })
+    }
+
+    /// The bit worth `2^weight`.
+    #[allow(clippy::cast_sign_loss)]
+    fn bit(&self, weight: i32) -> bool {
+        if weight >= 0 {
+            let len = self.integer_bits.len();
+            (weight as usize) < len && self.integer_bits[len - 1 - weight as usize]
+        } else {
+            self.fraction_bits
+                .get((-weight - 1) as usize)
+                .copied()
+                .unwrap_or(false)
+        }
+    }
+
+    /// The bits of the significand before rounding, from the first kept one.
+    fn kept(&self) -> Vec<bool> {
+        (self.top - self.precision + 1..=self.top)
+            .rev()
+            .map(|weight| self.bit(weight))
+            .collect()
+    }
+
+    /// Whether any bit below the rounding bit is set.
+    fn sticky(&self) -> bool {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let lowest = -(self.fraction_bits.len() as i32);
+        self.fraction_left || (lowest..self.top - self.precision).any(|weight| self.bit(weight))
+    }
+
+    /// The significand before rounding in binary, e.g. `1.0110`.
+    fn significand(&self) -> String {
+        let kept = self.kept();
+        format!("{}.{}", u8::from(kept[0]), binary(&kept[1..]))
+    }
+
+    fn fraction_step(&self) -> Step {
+        let mut lines = self.doublings.clone();
+        if self.fraction_bits.len() > SHOWN_LINES {
+            lines.push(Line::new(
+                Sentence::MoreDoublings,
+                vec![(self.fraction_bits.len() - SHOWN_LINES).to_string()],
+            ));
+        }
+        lines.push(if self.fraction_bits.is_empty() && !self.fraction_left {
+            Line::new(Sentence::FractionZero, Vec::new())
+        } else if self.fraction_bits.is_empty() {
+            Line::new(Sentence::IntegerHoldsAll, Vec::new())
+        } else if !self.fraction_left {
+            Line::new(Sentence::FractionExact, vec![binary(&self.fraction_bits)])
+        } else {
+            Line::new(Sentence::FractionForever, vec![binary(&self.fraction_bits)])
+        });
+        Step {
+            title: Label::FractionPart,
+            lines,
+            bits: Vec::new(),
+        }
+    }
+
+    fn normalize_step(&self, typed: &Typed, result: &Encoding) -> Step {
+        let (emin, emax) = (result.emin(), result.emax());
+        let digits = |bits: &[bool]| {
+            if bits.is_empty() {
+                "0".to_owned()
+            } else {
+                binary(bits)
+            }
+        };
+        let mut lines = vec![Line::new(
+            Sentence::Together,
+            vec![
+                digits(&self.integer_bits),
+                digits(&self.fraction_bits),
+                if self.fraction_left { "\u{2026}" } else { "" }.to_owned(),
+            ],
+        )];
+        lines.push(match self.leading {
+            Some(e) if e > emax => Line::new(
+                Sentence::LeadingTooLarge,
+                vec![e.to_string(), emax.to_string()],
+            ),
+            Some(e) if e >= emin => {
+                let (sentence, places) = match e {
+                    0 => (Sentence::MoveNowhere, None),
+                    1 => (Sentence::MoveOneLeft, None),
+                    -1 => (Sentence::MoveOneRight, None),
+                    e if e > 0 => (Sentence::MoveLeft, Some(e)),
+                    e => (Sentence::MoveRight, Some(-e)),
+                };
+                Line::new(
+                    sentence,
+                    places
+                        .map(|places| places.to_string())
+                        .into_iter()
+                        .chain(vec![self.significand(), e.to_string()])
+                        .collect(),
+                )
+            }
+            Some(e) => Line::new(
+                Sentence::LeadingSubnormal,
+                vec![
+                    e.to_string(),
+                    emin.to_string(),
+                    self.significand(),
+                    emin.to_string(),
+                ],
+            ),
+            None if typed.numerator.is_zero() => Line::new(Sentence::ZeroHasNoLeading, Vec::new()),
+            None => Line::new(
+                Sentence::NoOneBit,
+                vec![(emin - self.precision).to_string()],
+            ),
+        });
+        let explicit = !result.options.implicit_bit;
+        lines.push(Line::new(
+            if explicit {
+                Sentence::LeadingStored
+            } else {
+                Sentence::LeadingImplied
+            },
+            Vec::new(),
+        ));
+        Step {
+            title: Label::Normalize,
+            lines,
+            bits: if explicit {
+                vec![1 + result.exponent_bits.len()]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    fn bias_step(&self, result: &Encoding, sign_bit: bool) -> Step {
+        let (emin, emax) = (result.emin(), result.emax());
+        let exponent_len = result.exponent_bits.len();
+        #[allow(clippy::cast_possible_wrap)]
+        let bias = result.bias() as i64;
+        let lines = match self.leading {
+            Some(e) if e > emax => vec![
+                Line::new(
+                    Sentence::BiasOverflows,
+                    vec![
+                        e.to_string(),
+                        bias.to_string(),
+                        (i64::from(e) + bias).to_string(),
+                    ],
+                ),
+                Line::new(
+                    Sentence::OverflowsTo,
+                    vec![format_encoding(&result.overflowed(sign_bit))],
+                ),
+            ],
+            Some(e) if e >= emin => {
+                let stored = i64::from(e) + bias;
+                vec![
+                    Line::new(
+                        Sentence::ExponentStores,
+                        vec![e.to_string(), bias.to_string(), stored.to_string()],
+                    ),
+                    Line::new(
+                        Sentence::InBits,
+                        vec![
+                            format!("{:01$b}", stored, exponent_len),
+                            exponent_len.to_string(),
+                        ],
+                    ),
+                ]
+            }
+            _ => vec![Line::new(
+                Sentence::ExponentZeros,
+                vec![emin.to_string(), emin.to_string()],
+            )],
+        };
+        Step {
+            title: Label::BiasTheExponent,
+            lines,
+            bits: (1..=exponent_len).collect(),
+        }
+    }
+
+    fn round_step(
+        &self,
+        typed: &Typed,
+        result: &Encoding,
+        rounding: RoundingMode,
+        flags: Flags,
+    ) -> Step {
+        let kept = self.kept();
+        let mut lines = vec![Line::new(
+            Sentence::Keeping,
+            vec![
+                self.precision.to_string(),
+                self.significand(),
+                u8::from(self.bit(self.top - self.precision)).to_string(),
+                u8::from(self.sticky()).to_string(),
+            ],
+        )];
+        if flags.inexact {
+            let rounded_up = result.is_nan() || result.is_infinite() || {
+                let (stored_numerator, stored_denominator) = result.exact_ratio();
+                let mut stored = stored_numerator;
+                stored.mul_pow10(typed.fraction_len);
+                stored > typed.numerator.mul(&stored_denominator)
+            };
+            lines.push(Line::new(
+                if rounded_up {
+                    Sentence::RoundsUp
+                } else {
+                    Sentence::RoundsDown
+                },
+                vec![rounding.name().to_owned()],
+            ));
+            let overflowed_already = self.leading.map_or(false, |e| e > result.emax());
+            if rounded_up && kept[1..].iter().all(|&b| b) && !overflowed_already {
+                lines.push(Line::new(
+                    if flags.overflow {
+                        Sentence::CarryOverflows
+                    } else {
+                        Sentence::CarryIntoExponent
+                    },
+                    Vec::new(),
+                ));
+            }
+        } else {
+            lines.push(Line::new(Sentence::NothingLost, Vec::new()));
+        }
+        lines.push(Line::new(
+            Sentence::Result,
+            vec![result.field_string(), format_encoding(result)],
+        ));
+        let significand_start = 1 + result.exponent_bits.len();
+        Step {
+            title: Label::Round,
+            lines,
+            bits: (significand_start + usize::from(!result.options.implicit_bit)..result.len())
+                .collect(),
+        }
+    }
+}
+
+/// The worked division of the integer part by two, until nothing is left.
+fn integer_step(integer: &BigUint, bits: &[bool]) -> Step {
+    let mut lines = Vec::new();
+    let mut quotient = integer.clone();
+    while !quotient.is_zero() && lines.len() < SHOWN_LINES {
+        let dividend = quotient.to_string();
+        let remainder = quotient.div_small(2);
+        lines.push(Line::new(
+            Sentence::Halving,
+            vec![dividend, quotient.to_string(), remainder.to_string()],
+        ));
+    }
+    if bits.len() > SHOWN_LINES {
+        lines.push(Line::new(
+            Sentence::MoreHalvings,
+            vec![(bits.len() - SHOWN_LINES).to_string()],
+        ));
+    }
+    lines.push(if integer.is_zero() {
+        Line::new(Sentence::IntegerZero, Vec::new())
+    } else {
+        Line::new(Sentence::Remainders, vec![binary(bits)])
+    });
+    Step {
+        title: Label::IntegerPart,
+        lines,
+        bits: Vec::new(),
+    }
+}
+
+/// The weight of the fraction bit at `index`, counting from the one worth 1/2.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn fraction_weight(index: usize) -> i32 {
+    -(index as i32) - 1
+}
+
+/// `numerator / 10^fraction_len`, which is less than one, in decimal.
+fn decimal_fraction(numerator: &BigUint, fraction_len: u32) -> String {
+    if numerator.is_zero() {
+        return "0".to_owned();
+    }
+    let digits = format!("{:01$}", numerator, fraction_len as usize);
+    format!("0.{}", digits.trim_end_matches('0'))
+}
+
+/// Binary digits, with the middle of very long runs elided.
+fn binary(bits: &[bool]) -> String {
+    let digits =
+        |bits: &[bool]| -> String { bits.iter().map(|&b| if b { '1' } else { '0' }).collect() };
+    if bits.len() <= SHOWN_DIGITS {
+        digits(bits)
+    } else {
+        format!(
+            "{}\u{2026}{} ({} digits)",
+            digits(&bits[..SHOWN_DIGITS / 2]),
+            digits(&bits[bits.len() - SHOWN_DIGITS / 2..]),
+            bits.len()
+        )
+    }
+}
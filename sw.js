@@ -0,0 +1,40 @@
+// Caches the application shell so the visualiser works offline. Bump `CACHE` whenever the
+// cached files change so that old versions are evicted.
+const CACHE = 'ieee754-v1';
+const FILES = [
+    '/',
+    '/index.html',
+    '/css/index.css',
+    '/icon.svg',
+    '/manifest.webmanifest',
+    '/pkg/package.js',
+    '/pkg/package_bg.wasm',
+];
+
+self.addEventListener('install', event => {
+    event.waitUntil(caches.open(CACHE).then(cache => cache.addAll(FILES)));
+});
+
+self.addEventListener('activate', event => {
+    event.waitUntil(
+        caches.keys().then(keys =>
+            Promise.all(keys.filter(key => key !== CACHE).map(key => caches.delete(key)))
+        )
+    );
+});
+
+// Prefer the network so development builds are picked up, falling back to the cache offline.
+self.addEventListener('fetch', event => {
+    if (event.request.method !== 'GET') {
+        return;
+    }
+    event.respondWith(
+        fetch(event.request)
+            .then(response => {
+                const copy = response.clone();
+                caches.open(CACHE).then(cache => cache.put(event.request, copy));
+                return response;
+            })
+            .catch(() => caches.match(event.request))
+    );
+});